@@ -0,0 +1,37 @@
+#include <stdio.h>
+#include "tokenizers.h"
+
+int main(int argc, char **argv) {
+  if (argc < 3) {
+    fprintf(stderr, "Usage: %s <tokenizer.json> <sequence>\n", argv[0]);
+    return 1;
+  }
+
+  TkTokenizer *tokenizer = tk_tokenizer_from_file(argv[1]);
+  if (tokenizer == NULL) {
+    fprintf(stderr, "Error: %s\n", tk_last_error());
+    return 1;
+  }
+
+  TkEncoding *encoding = tk_tokenizer_encode(tokenizer, argv[2], NULL, true);
+  if (encoding == NULL) {
+    fprintf(stderr, "Error: %s\n", tk_last_error());
+    tk_tokenizer_free(tokenizer);
+    return 1;
+  }
+
+  for (size_t i = 0; i < encoding->len; i++) {
+    printf("%u\t%s\t(%zu, %zu)\n", encoding->ids[i], encoding->tokens[i],
+           encoding->offsets[i].start, encoding->offsets[i].end);
+  }
+
+  char *decoded = tk_tokenizer_decode(tokenizer, encoding->ids, encoding->len, true);
+  if (decoded != NULL) {
+    printf("%s\n", decoded);
+  }
+
+  tk_string_free(decoded);
+  tk_encoding_free(encoding);
+  tk_tokenizer_free(tokenizer);
+  return 0;
+}
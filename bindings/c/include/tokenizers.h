@@ -0,0 +1,226 @@
+/* Generated with cbindgen from the tokenizers-c crate. Do not edit manually, use `make header`. */
+
+#ifndef TOKENIZERS_H
+#define TOKENIZERS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum TkPaddingDirection {
+  TK_PADDING_DIRECTION_RIGHT,
+  TK_PADDING_DIRECTION_LEFT,
+} TkPaddingDirection;
+
+typedef enum TkTruncationStrategy {
+  TK_TRUNCATION_STRATEGY_LONGEST_FIRST,
+  TK_TRUNCATION_STRATEGY_ONLY_FIRST,
+  TK_TRUNCATION_STRATEGY_ONLY_SECOND,
+} TkTruncationStrategy;
+
+typedef enum TkTruncationDirection {
+  // Keep the start of each sequence
+  TK_TRUNCATION_DIRECTION_RIGHT,
+  // Keep the end of each sequence
+  TK_TRUNCATION_DIRECTION_LEFT,
+  // Keep `head_length` tokens at the start of each sequence, and the end for the rest
+  TK_TRUNCATION_DIRECTION_HEAD_TAIL,
+} TkTruncationDirection;
+
+// A Tokenizer, loaded from its JSON representation. Free it with `tk_tokenizer_free`.
+typedef struct TkTokenizer TkTokenizer;
+
+// The offsets of a token, in bytes, relative to the original input
+typedef struct TkOffsets {
+  size_t start;
+  size_t end;
+} TkOffsets;
+
+// An encoded sequence. Each array contains exactly `len` elements.
+//
+// It is owned by the caller, free it with `tk_encoding_free`, and all its content stays valid
+// until then. If it is part of a `TkEncodingBatch` it belongs to the batch, and gets freed
+// with `tk_encoding_batch_free` instead.
+typedef struct TkEncoding {
+  // The number of tokens
+  size_t len;
+  // The token ids
+  const uint32_t *ids;
+  // The type ids, representing the sequence to which each token belongs
+  const uint32_t *type_ids;
+  // The attention mask: 1 for each real token, 0 for the padding
+  const uint32_t *attention_mask;
+  // The special tokens mask: 1 for each special token, 0 for the others
+  const uint32_t *special_tokens_mask;
+  // The offsets of each token
+  const struct TkOffsets *offsets;
+  // Each token, as a NUL-terminated UTF-8 string
+  const char *const *tokens;
+} TkEncoding;
+
+// A batch of encodings, in the same order as the inputs.
+//
+// It is owned by the caller, free it with `tk_encoding_batch_free`. The encodings it contains
+// belong to the batch, and must not be freed individually.
+typedef struct TkEncodingBatch {
+  // The number of encodings
+  size_t len;
+  // The encodings, as a contiguous array
+  const struct TkEncoding *encodings;
+} TkEncodingBatch;
+
+// The padding parameters
+typedef struct TkPaddingParams {
+  // Pad to this fixed length, or to the longest encoding of the batch if 0
+  size_t length;
+  // If not 0, pad to a multiple of this value
+  size_t pad_to_multiple_of;
+  // When `length` is 0, and this array is not empty, pad to the smallest of these lengths that
+  // fits the longest encoding of the batch
+  const size_t *bucket_lengths;
+  // The number of lengths in `bucket_lengths`
+  size_t n_bucket_lengths;
+  // If not 0, pad to at least this length
+  size_t min_pad_length;
+  // If not 0, never pad beyond this length
+  size_t max_pad_length;
+  uint32_t pad_id;
+  uint32_t pad_type_id;
+  // The NUL-terminated padding token. `"[PAD]"` is used if `NULL`
+  const char *pad_token;
+  enum TkPaddingDirection direction;
+} TkPaddingParams;
+
+// The truncation parameters
+typedef struct TkTruncationParams {
+  size_t max_length;
+  // The number of tokens repeated from one overflowing part to the next one
+  size_t stride;
+  enum TkTruncationStrategy strategy;
+  enum TkTruncationDirection direction;
+  // The number of tokens kept at the start of each sequence with `HeadTail`
+  size_t head_length;
+  // Whether to cut only between words
+  bool word_boundaries;
+  // If not 0, the maximum length of the first sequence, which is repeated as is in each
+  // overflowing part of a pair
+  size_t max_first_length;
+  // If not 0, the maximum length of the second sequence
+  size_t max_second_length;
+} TkTruncationParams;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Free an encoding returned by `tk_tokenizer_encode`. Does nothing if `encoding` is `NULL`.
+//
+// # Safety
+// `encoding` must have been returned by `tk_tokenizer_encode`, and not freed already.
+void tk_encoding_free(struct TkEncoding *encoding);
+
+// Free a batch returned by `tk_tokenizer_encode_batch`, with all the encodings it contains.
+// Does nothing if `batch` is `NULL`.
+//
+// # Safety
+// `batch` must have been returned by `tk_tokenizer_encode_batch`, and not freed already.
+void tk_encoding_batch_free(struct TkEncodingBatch *batch);
+
+// Returns the message describing the last error that happened on the calling thread, or `NULL`
+// if the last call to a function that can fail succeeded.
+//
+// The returned string is owned by the library, and stays valid until the next call to any other
+// function of this library on the same thread. It must not be freed.
+const char *tk_last_error(void);
+
+// Load a Tokenizer from the given JSON file.
+//
+// Returns `NULL` on error.
+//
+// # Safety
+// `path` must be a valid NUL-terminated string.
+struct TkTokenizer *tk_tokenizer_from_file(const char *path);
+
+// Load a Tokenizer from a buffer containing its JSON representation. The buffer doesn't need to
+// be NUL-terminated, and can be released as soon as this function returns.
+//
+// Returns `NULL` on error.
+//
+// # Safety
+// `json` must point to at least `len` bytes.
+struct TkTokenizer *tk_tokenizer_from_buffer(const uint8_t *json, size_t len);
+
+// Free a Tokenizer. Does nothing if `tokenizer` is `NULL`.
+//
+// # Safety
+// `tokenizer` must have been returned by one of the `tk_tokenizer_from_*` functions, and not
+// freed already.
+void tk_tokenizer_free(struct TkTokenizer *tokenizer);
+
+// Encode the given sequence, and optionally its pair (can be `NULL`).
+//
+// Returns `NULL` on error. The returned encoding must be freed with `tk_encoding_free`.
+//
+// # Safety
+// `tokenizer` must be a valid Tokenizer, `sequence` and `pair` (if not `NULL`) must be valid
+// NUL-terminated strings.
+struct TkEncoding *tk_tokenizer_encode(const struct TkTokenizer *tokenizer,
+                                       const char *sequence,
+                                       const char *pair,
+                                       bool add_special_tokens);
+
+// Encode a batch of `len` sequences, and optionally their pairs. If not `NULL`, `pairs` must
+// contain `len` elements too.
+//
+// Returns `NULL` on error. The returned batch must be freed with `tk_encoding_batch_free`.
+//
+// # Safety
+// `tokenizer` must be a valid Tokenizer, `sequences` and `pairs` (if not `NULL`) must point to
+// `len` valid NUL-terminated strings.
+struct TkEncodingBatch *tk_tokenizer_encode_batch(const struct TkTokenizer *tokenizer,
+                                                  const char *const *sequences,
+                                                  const char *const *pairs,
+                                                  size_t len,
+                                                  bool add_special_tokens);
+
+// Decode the `len` given ids back to a string.
+//
+// Returns `NULL` on error. The returned string must be freed with `tk_string_free`.
+//
+// # Safety
+// `tokenizer` must be a valid Tokenizer, and `ids` must point to `len` ids.
+char *tk_tokenizer_decode(const struct TkTokenizer *tokenizer,
+                          const uint32_t *ids,
+                          size_t len,
+                          bool skip_special_tokens);
+
+// Free a string returned by this library. Does nothing if `s` is `NULL`.
+//
+// # Safety
+// `s` must have been returned by `tk_tokenizer_decode`, and not freed already.
+void tk_string_free(char *s);
+
+// Set the padding parameters of the Tokenizer, or disable padding if `params` is `NULL`.
+//
+// Returns `false` on error.
+//
+// # Safety
+// `tokenizer` must be a valid Tokenizer, and `params` either `NULL` or valid.
+bool tk_tokenizer_set_padding(struct TkTokenizer *tokenizer, const struct TkPaddingParams *params);
+
+// Set the truncation parameters of the Tokenizer, or disable truncation if `params` is `NULL`.
+//
+// Returns `false` on error.
+//
+// # Safety
+// `tokenizer` must be a valid Tokenizer, and `params` either `NULL` or valid.
+bool tk_tokenizer_set_truncation(struct TkTokenizer *tokenizer,
+                                 const struct TkTruncationParams *params);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TOKENIZERS_H */
@@ -0,0 +1,166 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+use tokenizers::tokenizer::{Encoding, Error, Result};
+
+/// The offsets of a token, in bytes, relative to the original input
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TkOffsets {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// An encoded sequence. Each array contains exactly `len` elements.
+///
+/// It is owned by the caller, free it with `tk_encoding_free`, and all its content stays valid
+/// until then. If it is part of a `TkEncodingBatch` it belongs to the batch, and gets freed
+/// with `tk_encoding_batch_free` instead.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TkEncoding {
+    /// The number of tokens
+    pub len: usize,
+    /// The token ids
+    pub ids: *const u32,
+    /// The type ids, representing the sequence to which each token belongs
+    pub type_ids: *const u32,
+    /// The attention mask: 1 for each real token, 0 for the padding
+    pub attention_mask: *const u32,
+    /// The special tokens mask: 1 for each special token, 0 for the others
+    pub special_tokens_mask: *const u32,
+    /// The offsets of each token
+    pub offsets: *const TkOffsets,
+    /// Each token, as a NUL-terminated UTF-8 string
+    pub tokens: *const *const c_char,
+}
+
+/// A batch of encodings, in the same order as the inputs.
+///
+/// It is owned by the caller, free it with `tk_encoding_batch_free`. The encodings it contains
+/// belong to the batch, and must not be freed individually.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TkEncodingBatch {
+    /// The number of encodings
+    pub len: usize,
+    /// The encodings, as a contiguous array
+    pub encodings: *const TkEncoding,
+}
+
+/// Keeps alive the buffers a `TkEncoding` points to.
+struct EncodingData {
+    ids: Vec<u32>,
+    type_ids: Vec<u32>,
+    attention_mask: Vec<u32>,
+    special_tokens_mask: Vec<u32>,
+    offsets: Vec<TkOffsets>,
+    // The pointers point into `tokens`, which must then be kept too
+    #[allow(dead_code)]
+    tokens: Vec<CString>,
+    token_ptrs: Vec<*const c_char>,
+}
+
+impl EncodingData {
+    fn new(encoding: Encoding) -> Result<Self> {
+        let tokens = encoding
+            .get_tokens()
+            .iter()
+            .map(|t| CString::new(t.as_str()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(Error::other)?;
+        let token_ptrs = tokens.iter().map(|t| t.as_ptr()).collect();
+
+        Ok(Self {
+            ids: encoding.get_ids().to_vec(),
+            type_ids: encoding.get_type_ids().to_vec(),
+            attention_mask: encoding.get_attention_mask().to_vec(),
+            special_tokens_mask: encoding.get_special_tokens_mask().to_vec(),
+            offsets: encoding
+                .get_offsets()
+                .iter()
+                .map(|(start, end)| TkOffsets {
+                    start: *start,
+                    end: *end,
+                })
+                .collect(),
+            tokens,
+            token_ptrs,
+        })
+    }
+
+    fn view(&self) -> TkEncoding {
+        TkEncoding {
+            len: self.ids.len(),
+            ids: self.ids.as_ptr(),
+            type_ids: self.type_ids.as_ptr(),
+            attention_mask: self.attention_mask.as_ptr(),
+            special_tokens_mask: self.special_tokens_mask.as_ptr(),
+            offsets: self.offsets.as_ptr(),
+            tokens: self.token_ptrs.as_ptr(),
+        }
+    }
+}
+
+/// What we actually allocate for a `TkEncoding`. Since the public part comes first, a pointer to
+/// it is also a pointer to the whole.
+#[repr(C)]
+struct OwnedEncoding {
+    public: TkEncoding,
+    data: EncodingData,
+}
+
+#[repr(C)]
+struct OwnedEncodingBatch {
+    public: TkEncodingBatch,
+    encodings: Vec<TkEncoding>,
+    data: Vec<EncodingData>,
+}
+
+pub(crate) fn into_raw(encoding: Encoding) -> Result<*mut TkEncoding> {
+    let data = EncodingData::new(encoding)?;
+    let owned = Box::new(OwnedEncoding {
+        public: data.view(),
+        data,
+    });
+    Ok(Box::into_raw(owned) as *mut TkEncoding)
+}
+
+pub(crate) fn batch_into_raw(encodings: Vec<Encoding>) -> Result<*mut TkEncodingBatch> {
+    let data = encodings
+        .into_iter()
+        .map(EncodingData::new)
+        .collect::<Result<Vec<_>>>()?;
+    let encodings = data.iter().map(|d| d.view()).collect::<Vec<_>>();
+    let owned = Box::new(OwnedEncodingBatch {
+        public: TkEncodingBatch {
+            len: encodings.len(),
+            encodings: encodings.as_ptr(),
+        },
+        encodings,
+        data,
+    });
+    Ok(Box::into_raw(owned) as *mut TkEncodingBatch)
+}
+
+/// Free an encoding returned by `tk_tokenizer_encode`. Does nothing if `encoding` is `NULL`.
+///
+/// # Safety
+/// `encoding` must have been returned by `tk_tokenizer_encode`, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn tk_encoding_free(encoding: *mut TkEncoding) {
+    if !encoding.is_null() {
+        drop(Box::from_raw(encoding as *mut OwnedEncoding));
+    }
+}
+
+/// Free a batch returned by `tk_tokenizer_encode_batch`, with all the encodings it contains.
+/// Does nothing if `batch` is `NULL`.
+///
+/// # Safety
+/// `batch` must have been returned by `tk_tokenizer_encode_batch`, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn tk_encoding_batch_free(batch: *mut TkEncodingBatch) {
+    if !batch.is_null() {
+        drop(Box::from_raw(batch as *mut OwnedEncodingBatch));
+    }
+}
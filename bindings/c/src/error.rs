@@ -0,0 +1,89 @@
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use tokenizers::tokenizer::Result;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    // A CString can't contain any NUL byte
+    let message = CString::new(message.replace('\0', "")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, making sure that no error nor panic escapes through the FFI boundary. Any of them
+/// is saved as the last error of the current thread, and `None` is returned.
+pub(crate) fn wrap<T, F>(f: F) -> Option<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(v)) => Some(v),
+        Ok(Err(e)) => {
+            set_last_error(e.to_string());
+            None
+        }
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Unknown error".into());
+            set_last_error(format!("Panic: {}", message));
+            None
+        }
+    }
+}
+
+/// Borrow a NUL-terminated UTF-8 string given by the caller.
+pub(crate) unsafe fn to_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str> {
+    if ptr.is_null() {
+        return Err(format!("`{}` must not be NULL", name).into());
+    }
+    Ok(CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|e| format!("`{}` is not valid UTF-8: {}", name, e))?)
+}
+
+/// Borrow an object given by the caller.
+pub(crate) unsafe fn to_ref<'a, T>(ptr: *const T, name: &str) -> Result<&'a T> {
+    ptr.as_ref()
+        .ok_or_else(|| format!("`{}` must not be NULL", name).into())
+}
+
+/// Mutably borrow an object given by the caller.
+pub(crate) unsafe fn to_mut<'a, T>(ptr: *mut T, name: &str) -> Result<&'a mut T> {
+    ptr.as_mut()
+        .ok_or_else(|| format!("`{}` must not be NULL", name).into())
+}
+
+/// Borrow an array of `len` elements given by the caller. It can be `NULL` if `len` is 0.
+pub(crate) unsafe fn to_slice<'a, T>(ptr: *const T, len: usize, name: &str) -> Result<&'a [T]> {
+    if len == 0 {
+        Ok(&[])
+    } else if ptr.is_null() {
+        Err(format!("`{}` must not be NULL", name).into())
+    } else {
+        Ok(std::slice::from_raw_parts(ptr, len))
+    }
+}
+
+/// Returns the message describing the last error that happened on the calling thread, or `NULL`
+/// if the last call to a function that can fail succeeded.
+///
+/// The returned string is owned by the library, and stays valid until the next call to any other
+/// function of this library on the same thread. It must not be freed.
+#[no_mangle]
+pub extern "C" fn tk_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
@@ -0,0 +1,27 @@
+//! C bindings over the Rust implementation of tokenizers.
+//!
+//! The header `include/tokenizers.h` is generated from this crate using
+//! [cbindgen](https://github.com/eqrion/cbindgen), with `make header`.
+//!
+//! # Ownership rules
+//! - Every object returned by a `tk_*` function is owned by the caller, and must be freed with
+//!   the matching `tk_*_free` function. Objects are never freed by the library itself.
+//! - Everything the caller provides is only borrowed for the duration of the call.
+//! - A `TkEncoding` contained in a `TkEncodingBatch` belongs to the batch, and gets freed with it.
+//!
+//! # Errors
+//! Functions that can fail return `NULL` (or `false`) on error. The message describing the error
+//! can then be retrieved with `tk_last_error`, and belongs to the library. No panic ever crosses
+//! the FFI boundary.
+//!
+//! # Thread safety
+//! A `TkTokenizer` can be used to encode and decode from multiple threads at once. Setting its
+//! padding or truncation requires exclusive access though.
+
+mod encoding;
+mod error;
+mod tokenizer;
+
+pub use encoding::*;
+pub use error::tk_last_error;
+pub use tokenizer::*;
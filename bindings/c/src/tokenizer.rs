@@ -0,0 +1,483 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+use tokenizers::tokenizer::{
+    EncodeInput, Error, PaddingDirection, PaddingParams, PaddingStrategy, Tokenizer,
+    TruncationDirection, TruncationParams, TruncationStrategy,
+};
+
+use crate::encoding::{self, TkEncoding, TkEncodingBatch};
+use crate::error::{to_mut, to_ref, to_slice, to_str, wrap};
+
+/// A Tokenizer, loaded from its JSON representation. Free it with `tk_tokenizer_free`.
+pub struct TkTokenizer {
+    tokenizer: Tokenizer,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum TkPaddingDirection {
+    Right,
+    Left,
+}
+
+/// The padding parameters
+#[repr(C)]
+#[derive(Debug)]
+pub struct TkPaddingParams {
+    /// Pad to this fixed length, or to the longest encoding of the batch if 0
+    pub length: usize,
+    /// If not 0, pad to a multiple of this value
+    pub pad_to_multiple_of: usize,
+    /// When `length` is 0, and this array is not empty, pad to the smallest of these lengths that
+    /// fits the longest encoding of the batch
+    pub bucket_lengths: *const usize,
+    /// The number of lengths in `bucket_lengths`
+    pub n_bucket_lengths: usize,
+    /// If not 0, pad to at least this length
+    pub min_pad_length: usize,
+    /// If not 0, never pad beyond this length
+    pub max_pad_length: usize,
+    pub pad_id: u32,
+    pub pad_type_id: u32,
+    /// The NUL-terminated padding token. `"[PAD]"` is used if `NULL`
+    pub pad_token: *const c_char,
+    pub direction: TkPaddingDirection,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum TkTruncationStrategy {
+    LongestFirst,
+    OnlyFirst,
+    OnlySecond,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum TkTruncationDirection {
+    /// Keep the start of each sequence
+    Right,
+    /// Keep the end of each sequence
+    Left,
+    /// Keep `head_length` tokens at the start of each sequence, and the end for the rest
+    HeadTail,
+}
+
+/// The truncation parameters
+#[repr(C)]
+#[derive(Debug)]
+pub struct TkTruncationParams {
+    pub max_length: usize,
+    /// The number of tokens repeated from one overflowing part to the next one
+    pub stride: usize,
+    pub strategy: TkTruncationStrategy,
+    pub direction: TkTruncationDirection,
+    /// The number of tokens kept at the start of each sequence with `HeadTail`
+    pub head_length: usize,
+    /// Whether to cut only between words
+    pub word_boundaries: bool,
+    /// If not 0, the maximum length of the first sequence, which is repeated as is in each
+    /// overflowing part of a pair
+    pub max_first_length: usize,
+    /// If not 0, the maximum length of the second sequence
+    pub max_second_length: usize,
+}
+
+/// Load a Tokenizer from the given JSON file.
+///
+/// Returns `NULL` on error.
+///
+/// # Safety
+/// `path` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_from_file(path: *const c_char) -> *mut TkTokenizer {
+    wrap(|| {
+        let tokenizer = Tokenizer::from_file(to_str(path, "path")?)?;
+        Ok(Box::into_raw(Box::new(TkTokenizer { tokenizer })))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Load a Tokenizer from a buffer containing its JSON representation. The buffer doesn't need to
+/// be NUL-terminated, and can be released as soon as this function returns.
+///
+/// Returns `NULL` on error.
+///
+/// # Safety
+/// `json` must point to at least `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_from_buffer(json: *const u8, len: usize) -> *mut TkTokenizer {
+    wrap(|| {
+        let json = std::str::from_utf8(to_slice(json, len, "json")?).map_err(Error::other)?;
+        let tokenizer = json.parse::<Tokenizer>()?;
+        Ok(Box::into_raw(Box::new(TkTokenizer { tokenizer })))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Free a Tokenizer. Does nothing if `tokenizer` is `NULL`.
+///
+/// # Safety
+/// `tokenizer` must have been returned by one of the `tk_tokenizer_from_*` functions, and not
+/// freed already.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_free(tokenizer: *mut TkTokenizer) {
+    if !tokenizer.is_null() {
+        drop(Box::from_raw(tokenizer));
+    }
+}
+
+/// Encode the given sequence, and optionally its pair (can be `NULL`).
+///
+/// Returns `NULL` on error. The returned encoding must be freed with `tk_encoding_free`.
+///
+/// # Safety
+/// `tokenizer` must be a valid Tokenizer, `sequence` and `pair` (if not `NULL`) must be valid
+/// NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_encode(
+    tokenizer: *const TkTokenizer,
+    sequence: *const c_char,
+    pair: *const c_char,
+    add_special_tokens: bool,
+) -> *mut TkEncoding {
+    wrap(|| {
+        let tokenizer = &to_ref(tokenizer, "tokenizer")?.tokenizer;
+        let input = to_input(sequence, pair)?;
+        encoding::into_raw(tokenizer.encode(input, add_special_tokens)?)
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Encode a batch of `len` sequences, and optionally their pairs. If not `NULL`, `pairs` must
+/// contain `len` elements too.
+///
+/// Returns `NULL` on error. The returned batch must be freed with `tk_encoding_batch_free`.
+///
+/// # Safety
+/// `tokenizer` must be a valid Tokenizer, `sequences` and `pairs` (if not `NULL`) must point to
+/// `len` valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_encode_batch(
+    tokenizer: *const TkTokenizer,
+    sequences: *const *const c_char,
+    pairs: *const *const c_char,
+    len: usize,
+    add_special_tokens: bool,
+) -> *mut TkEncodingBatch {
+    wrap(|| {
+        let tokenizer = &to_ref(tokenizer, "tokenizer")?.tokenizer;
+        let sequences = to_slice(sequences, len, "sequences")?;
+        let pairs = if pairs.is_null() {
+            vec![ptr::null(); len]
+        } else {
+            to_slice(pairs, len, "pairs")?.to_vec()
+        };
+        let inputs = sequences
+            .iter()
+            .zip(pairs)
+            .map(|(sequence, pair)| to_input(*sequence, pair))
+            .collect::<tokenizers::Result<Vec<_>>>()?;
+        encoding::batch_into_raw(tokenizer.encode_batch(inputs, add_special_tokens)?)
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+unsafe fn to_input<'s>(
+    sequence: *const c_char,
+    pair: *const c_char,
+) -> tokenizers::Result<EncodeInput<'s>> {
+    let sequence = to_str(sequence, "sequence")?;
+    Ok(if pair.is_null() {
+        sequence.into()
+    } else {
+        (sequence, to_str(pair, "pair")?).into()
+    })
+}
+
+/// Decode the `len` given ids back to a string.
+///
+/// Returns `NULL` on error. The returned string must be freed with `tk_string_free`.
+///
+/// # Safety
+/// `tokenizer` must be a valid Tokenizer, and `ids` must point to `len` ids.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_decode(
+    tokenizer: *const TkTokenizer,
+    ids: *const u32,
+    len: usize,
+    skip_special_tokens: bool,
+) -> *mut c_char {
+    wrap(|| {
+        let tokenizer = &to_ref(tokenizer, "tokenizer")?.tokenizer;
+        let ids = to_slice(ids, len, "ids")?.to_vec();
+        let decoded = tokenizer.decode(ids, skip_special_tokens)?;
+        Ok(CString::new(decoded).map_err(Error::other)?.into_raw())
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Free a string returned by this library. Does nothing if `s` is `NULL`.
+///
+/// # Safety
+/// `s` must have been returned by `tk_tokenizer_decode`, and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn tk_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Set the padding parameters of the Tokenizer, or disable padding if `params` is `NULL`.
+///
+/// Returns `false` on error.
+///
+/// # Safety
+/// `tokenizer` must be a valid Tokenizer, and `params` either `NULL` or valid.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_set_padding(
+    tokenizer: *mut TkTokenizer,
+    params: *const TkPaddingParams,
+) -> bool {
+    wrap(|| {
+        let tokenizer = &mut to_mut(tokenizer, "tokenizer")?.tokenizer;
+        let padding = match params.as_ref() {
+            None => None,
+            Some(params) => Some(PaddingParams {
+                strategy: match (params.length, params.n_bucket_lengths) {
+                    (0, 0) => PaddingStrategy::BatchLongest,
+                    (0, n) => PaddingStrategy::Bucketed(
+                        to_slice(params.bucket_lengths, n, "bucket_lengths")?.to_vec(),
+                    ),
+                    (length, _) => PaddingStrategy::Fixed(length),
+                },
+                direction: match params.direction {
+                    TkPaddingDirection::Right => PaddingDirection::Right,
+                    TkPaddingDirection::Left => PaddingDirection::Left,
+                },
+                pad_to_multiple_of: match params.pad_to_multiple_of {
+                    0 => None,
+                    multiple => Some(multiple),
+                },
+                min_pad_length: Some(params.min_pad_length).filter(|l| *l > 0),
+                max_pad_length: Some(params.max_pad_length).filter(|l| *l > 0),
+                pad_id: params.pad_id,
+                pad_type_id: params.pad_type_id,
+                pad_token: if params.pad_token.is_null() {
+                    PaddingParams::default().pad_token
+                } else {
+                    to_str(params.pad_token, "pad_token")?.to_owned()
+                },
+            }),
+        };
+        tokenizer.with_padding(padding);
+        Ok(())
+    })
+    .is_some()
+}
+
+/// Set the truncation parameters of the Tokenizer, or disable truncation if `params` is `NULL`.
+///
+/// Returns `false` on error.
+///
+/// # Safety
+/// `tokenizer` must be a valid Tokenizer, and `params` either `NULL` or valid.
+#[no_mangle]
+pub unsafe extern "C" fn tk_tokenizer_set_truncation(
+    tokenizer: *mut TkTokenizer,
+    params: *const TkTruncationParams,
+) -> bool {
+    wrap(|| {
+        let tokenizer = &mut to_mut(tokenizer, "tokenizer")?.tokenizer;
+        let truncation = params.as_ref().map(|params| TruncationParams {
+            max_length: params.max_length,
+            stride: params.stride,
+            strategy: match params.strategy {
+                TkTruncationStrategy::LongestFirst => TruncationStrategy::LongestFirst,
+                TkTruncationStrategy::OnlyFirst => TruncationStrategy::OnlyFirst,
+                TkTruncationStrategy::OnlySecond => TruncationStrategy::OnlySecond,
+            },
+            direction: match params.direction {
+                TkTruncationDirection::Right => TruncationDirection::Right,
+                TkTruncationDirection::Left => TruncationDirection::Left,
+                TkTruncationDirection::HeadTail => {
+                    TruncationDirection::HeadTail(params.head_length)
+                }
+            },
+            word_boundaries: params.word_boundaries,
+            max_first_length: Some(params.max_first_length).filter(|l| *l > 0),
+            max_second_length: Some(params.max_second_length).filter(|l| *l > 0),
+        });
+        tokenizer.with_truncation(truncation);
+        Ok(())
+    })
+    .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{tk_encoding_batch_free, tk_encoding_free, TkOffsets};
+    use crate::error::tk_last_error;
+    use std::ffi::CStr;
+    use std::slice;
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::WhitespaceSplit;
+
+    fn get_tokenizer() -> *mut TkTokenizer {
+        let vocab = [
+            ("[UNK]", 0),
+            ("[PAD]", 1),
+            ("hello", 2),
+            ("my", 3),
+            ("friend", 4),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(WhitespaceSplit);
+        let json = tokenizer.to_string(false).unwrap();
+
+        let tokenizer = unsafe { tk_tokenizer_from_buffer(json.as_ptr(), json.len()) };
+        assert!(!tokenizer.is_null());
+        tokenizer
+    }
+
+    fn last_error() -> Option<String> {
+        let error = tk_last_error();
+        if error.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(error) }
+                    .to_str()
+                    .unwrap()
+                    .to_owned(),
+            )
+        }
+    }
+
+    #[test]
+    fn encode_decode() {
+        let tokenizer = get_tokenizer();
+        unsafe {
+            let encoding = tk_tokenizer_encode(
+                tokenizer,
+                "hello my friend\0".as_ptr() as *const c_char,
+                "hey\0".as_ptr() as *const c_char,
+                true,
+            );
+            assert!(!encoding.is_null());
+            assert_eq!(last_error(), None);
+
+            let e = &*encoding;
+            assert_eq!(e.len, 4);
+            assert_eq!(slice::from_raw_parts(e.ids, e.len), &[2, 3, 4, 0]);
+            assert_eq!(slice::from_raw_parts(e.type_ids, e.len), &[0, 0, 0, 1]);
+            assert_eq!(
+                slice::from_raw_parts(e.offsets, e.len)[2],
+                TkOffsets { start: 9, end: 15 }
+            );
+            let tokens = slice::from_raw_parts(e.tokens, e.len)
+                .iter()
+                .map(|t| CStr::from_ptr(*t).to_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(tokens, vec!["hello", "my", "friend", "hey"]);
+
+            let decoded = tk_tokenizer_decode(tokenizer, e.ids, e.len, false);
+            assert_eq!(
+                CStr::from_ptr(decoded).to_str().unwrap(),
+                "hello my friend [UNK]"
+            );
+
+            tk_string_free(decoded);
+            tk_encoding_free(encoding);
+            tk_tokenizer_free(tokenizer);
+        }
+    }
+
+    #[test]
+    fn encode_batch_with_padding_and_truncation() {
+        let tokenizer = get_tokenizer();
+        unsafe {
+            let padding = TkPaddingParams {
+                length: 0,
+                pad_to_multiple_of: 0,
+                bucket_lengths: ptr::null(),
+                n_bucket_lengths: 0,
+                min_pad_length: 0,
+                max_pad_length: 0,
+                pad_id: 1,
+                pad_type_id: 0,
+                pad_token: ptr::null(),
+                direction: TkPaddingDirection::Right,
+            };
+            assert!(tk_tokenizer_set_padding(tokenizer, &padding));
+            let truncation = TkTruncationParams {
+                max_length: 2,
+                stride: 0,
+                strategy: TkTruncationStrategy::LongestFirst,
+                direction: TkTruncationDirection::Right,
+                head_length: 0,
+                word_boundaries: false,
+                max_first_length: 0,
+                max_second_length: 0,
+            };
+            assert!(tk_tokenizer_set_truncation(tokenizer, &truncation));
+
+            let sequences = [
+                "hello\0".as_ptr() as *const c_char,
+                "hello my friend\0".as_ptr() as *const c_char,
+            ];
+            let batch = tk_tokenizer_encode_batch(
+                tokenizer,
+                sequences.as_ptr(),
+                ptr::null(),
+                sequences.len(),
+                true,
+            );
+            assert!(!batch.is_null());
+
+            let encodings = slice::from_raw_parts((*batch).encodings, (*batch).len);
+            let ids = encodings
+                .iter()
+                .map(|e| slice::from_raw_parts(e.ids, e.len))
+                .collect::<Vec<_>>();
+            assert_eq!(ids, vec![&[2, 1], &[2, 3]]);
+            let mask = slice::from_raw_parts(encodings[0].attention_mask, 2);
+            assert_eq!(mask, &[1, 0]);
+
+            tk_encoding_batch_free(batch);
+            tk_tokenizer_free(tokenizer);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        unsafe {
+            assert!(tk_tokenizer_from_buffer("{}".as_ptr(), 2).is_null());
+            assert!(last_error().is_some());
+
+            assert!(tk_tokenizer_encode(ptr::null(), ptr::null(), ptr::null(), true).is_null());
+            assert_eq!(
+                last_error(),
+                Some("`tokenizer` must not be NULL".to_string())
+            );
+
+            let tokenizer = get_tokenizer();
+            assert!(tk_tokenizer_encode(tokenizer, ptr::null(), ptr::null(), true).is_null());
+            assert_eq!(
+                last_error(),
+                Some("`sequence` must not be NULL".to_string())
+            );
+            tk_tokenizer_free(tokenizer);
+        }
+    }
+}
@@ -71,6 +71,26 @@ export function sequenceNormalizer(normalizers: Normalizer[]): Normalizer;
  */
 export function lowercaseNormalizer(): Normalizer;
 
+/**
+ * Returns a new LocaleLowercase Normalizer, which lowercases while applying the conditional
+ * rules of the Unicode SpecialCasing for the language of the given locale: the dotted and
+ * dotless i in Turkish and Azerbaijani, the accents in Lithuanian, and the final sigma.
+ * @param locale The locale of the text, like `"tr"` or `"lt-LT"`
+ */
+export function localeLowercaseNormalizer(locale: string): Normalizer;
+
+/**
+ * Returns a new CaseFold Normalizer, which applies the full Unicode case folding.
+ * For example, both "ß" and "ẞ" give "ss".
+ */
+export function caseFoldNormalizer(): Normalizer;
+
+/**
+ * Returns a new NfkcCasefold Normalizer, which removes the default ignorable code points,
+ * and then applies NFKC, the case folding, and NFKC again.
+ */
+export function nfkcCasefoldNormalizer(): Normalizer;
+
 /**
  *  Returns a new Strip Normalizer
  * @param [left=true] Whether or not to strip on the left (defaults to `true`)
@@ -88,6 +108,26 @@ export function stripAccentsNormalizer(): Normalizer;
  */
 export function nmtNormalizer(): Normalizer;
 
+/**
+ * Returns a new Transliterate Normalizer, which transliterates the characters of the given
+ * scripts to their closest ASCII forms, using built-in tables
+ * @param [scripts] The scripts to transliterate, all of them by default
+ */
+export function transliterateNormalizer(
+  scripts?: ("latin" | "greek" | "cyrillic")[]
+): Normalizer;
+
+/**
+ * Returns a new HtmlStrip Normalizer, which removes the HTML tags and comments, and decodes
+ * the character references like `&amp;`, while the offsets still point into the raw markup
+ * @param [decodeEntities=true] Whether to decode the character references
+ * @param [blockSeparator="space"] What replaces the tags of the block elements, like `<p>`
+ */
+export function htmlStripNormalizer(
+  decodeEntities?: boolean,
+  blockSeparator?: "none" | "space" | "newline"
+): Normalizer;
+
 /**
  * Returns a new Precompiled Normalizer
  */
@@ -97,3 +137,42 @@ export function precompiledNormalizer(): Normalizer;
  * Returns a new Replace Normalizer
  */
 export function replaceNormalizer(): Normalizer;
+
+/**
+ * Returns a new ReplaceMap Normalizer, which replaces the literal patterns of a table of
+ * rules with their content in a single pass, using the longest pattern at each position
+ * @param rules The content to use for each pattern. The patterns can't be empty.
+ */
+export function replaceMapNormalizer(rules: { [pattern: string]: string }): Normalizer;
+
+export interface CleanWhitespaceOptions {
+  /**
+   * Whether to collapse each run of whitespace into its first character.
+   * @default true
+   */
+  collapse?: boolean;
+  /**
+   * Whether to convert every kind of whitespace, like tabs or no-break spaces, to a space.
+   * @default true
+   */
+  normalizeSpaces?: boolean;
+  /**
+   * Whether to remove the control and format characters, like the zero-width spaces and
+   * joiners, or the bidi controls.
+   * @default true
+   */
+  removeControl?: boolean;
+  /**
+   * Whether to keep the newlines apart from the other whitespace. They are then converted to
+   * `\n` instead of a space, and a collapsed run that contains one becomes this newline.
+   * @default false
+   */
+  keepNewlines?: boolean;
+}
+
+/**
+ * Returns a new CleanWhitespace Normalizer, which cleans the whitespace of the input while
+ * keeping the alignments
+ * @param [options] The options of the normalizer
+ */
+export function cleanWhitespaceNormalizer(options?: CleanWhitespaceOptions): Normalizer;
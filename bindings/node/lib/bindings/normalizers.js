@@ -8,9 +8,16 @@ module.exports = {
   nfkdNormalizer: native.normalizers_NFKD,
   sequenceNormalizer: native.normalizers_Sequence,
   lowercaseNormalizer: native.normalizers_Lowercase,
+  localeLowercaseNormalizer: native.normalizers_LocaleLowercase,
+  caseFoldNormalizer: native.normalizers_CaseFold,
+  nfkcCasefoldNormalizer: native.normalizers_NfkcCasefold,
   stripNormalizer: native.normalizers_Strip,
   stripAccentsNormalizer: native.normalizers_StripAccents,
   nmtNormalizer: native.normalizers_Nmt,
+  transliterateNormalizer: native.normalizers_Transliterate,
+  htmlStripNormalizer: native.normalizers_HtmlStrip,
   precompiledNormalizer: native.normalizers_Precompiled,
   replaceNormalizer: native.normalizers_Replace,
+  replaceMapNormalizer: native.normalizers_ReplaceMap,
+  cleanWhitespaceNormalizer: native.normalizers_CleanWhitespace,
 };
@@ -1,4 +1,14 @@
-import { stripAccentsNormalizer, stripNormalizer } from "./normalizers";
+import {
+  caseFoldNormalizer,
+  cleanWhitespaceNormalizer,
+  htmlStripNormalizer,
+  localeLowercaseNormalizer,
+  nfkcCasefoldNormalizer,
+  replaceMapNormalizer,
+  stripAccentsNormalizer,
+  stripNormalizer,
+  transliterateNormalizer,
+} from "./normalizers";
 
 describe("stripNormalizer", () => {
   it("instantiates with no parameters", () => {
@@ -36,3 +46,83 @@ describe("stripAccentsNormalizer", () => {
     expect(normalizer.constructor.name).toEqual("Normalizer");
   });
 });
+
+describe("localeLowercaseNormalizer", () => {
+  it("lowercases for the given locale", () => {
+    expect(localeLowercaseNormalizer("tr").normalizeString("KIZ İSTANBUL")).toEqual(
+      "kız istanbul"
+    );
+    expect(localeLowercaseNormalizer("en").normalizeString("KIZ")).toEqual("kiz");
+  });
+});
+
+describe("caseFoldNormalizer", () => {
+  it("folds the case", () => {
+    const normalizer = caseFoldNormalizer();
+    expect(normalizer.constructor.name).toEqual("Normalizer");
+    expect(normalizer.normalizeString("Straße ẞ ΣΑΣ ①")).toEqual("strasse ss σασ ①");
+  });
+});
+
+describe("nfkcCasefoldNormalizer", () => {
+  it("applies NFKC_Casefold", () => {
+    const normalizer = nfkcCasefoldNormalizer();
+    expect(normalizer.constructor.name).toEqual("Normalizer");
+    expect(normalizer.normalizeString("Straße ẞ ΣΑΣ ①\u200b")).toEqual("strasse ss σασ 1");
+  });
+});
+
+describe("transliterateNormalizer", () => {
+  it("transliterates all the scripts by default", () => {
+    const normalizer = transliterateNormalizer();
+    expect(normalizer.normalizeString("Жуков Ἀθῆναι Straße")).toEqual(
+      "Zhukov Athinai Strasse"
+    );
+  });
+
+  it("transliterates only the given scripts", () => {
+    const normalizer = transliterateNormalizer(["cyrillic"]);
+    expect(normalizer.normalizeString("Жуков Straße")).toEqual("Zhukov Straße");
+  });
+});
+
+describe("htmlStripNormalizer", () => {
+  it("strips the tags and decodes the entities", () => {
+    const normalizer = htmlStripNormalizer();
+    expect(normalizer.normalizeString("<p>Fish &amp; <b>chips</b></p>")).toEqual(
+      " Fish & chips "
+    );
+  });
+
+  it("accepts a block separator", () => {
+    const normalizer = htmlStripNormalizer(false, "newline");
+    expect(normalizer.normalizeString("<p>Fish &amp; chips</p>")).toEqual(
+      "\nFish &amp; chips\n"
+    );
+  });
+});
+
+describe("replaceMapNormalizer", () => {
+  it("replaces the longest patterns", () => {
+    const normalizer = replaceMapNormalizer({ ":)": "🙂", ":))": "😀", a: "b", b: "c" });
+    expect(normalizer.normalizeString("ab :)) :)")).toEqual("bc 😀 🙂");
+  });
+
+  it("throws with an empty pattern", () => {
+    expect(() => replaceMapNormalizer({ "": "a" })).toThrow();
+  });
+});
+
+describe("cleanWhitespaceNormalizer", () => {
+  it("collapses the whitespace and removes the control characters", () => {
+    const normalizer = cleanWhitespaceNormalizer();
+    expect(normalizer.normalizeString("Hello\u00a0\t\u200b world\r\n!")).toEqual(
+      "Hello world !"
+    );
+  });
+
+  it("accepts options", () => {
+    const normalizer = cleanWhitespaceNormalizer({ keepNewlines: true, collapse: false });
+    expect(normalizer.normalizeString("a\t\r\nb")).toEqual("a \n\nb");
+  });
+});
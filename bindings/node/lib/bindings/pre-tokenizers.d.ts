@@ -109,3 +109,59 @@ export function sequencePreTokenizer(pretokenizers: PreTokenizer[]): PreTokenize
  * @param [individualDigits=false] Whether to split on individual digits.
  */
 export function digitsPreTokenizer(individualDigits?: boolean): PreTokenizer;
+
+/**
+ * Returns a new UnicodeWords PreTokenizer.
+ * This pre-tokenizer splits on the word boundaries of the Unicode Standard Annex #29. The words
+ * keep their apostrophes and the separators of their numbers, like `can't` or `3.14`.
+ *
+ * @param [removeWhitespace=true] Whether to remove the segments that only contain whitespace.
+ */
+export function unicodeWordsPreTokenizer(removeWhitespace?: boolean): PreTokenizer;
+
+/**
+ * Returns a new DictionarySplit PreTokenizer.
+ * This pre-tokenizer segments the runs of a script written without spaces into the words of
+ * a dictionary. The chars that are not part of any word are split on their own, and the text
+ * of the other scripts is left as is.
+ *
+ * @param words The frequency of each word of the dictionary.
+ * @param [script="han"] The script to segment. `han` includes Hiragana and Katakana.
+ * @param [segmentation="maxProbability"] How to choose between the possible segmentations:
+ * the one with the highest probability, or the longest word at each position.
+ */
+export function dictionarySplitPreTokenizer(
+  words: { [word: string]: number },
+  script?: "han" | "thai",
+  segmentation?: "maxProbability" | "maxMatch"
+): PreTokenizer;
+
+/**
+ * Returns a new DictionarySplit PreTokenizer, with the dictionary of the given file. The file
+ * has a word on each line, optionally followed by its frequency after a space or a tab.
+ *
+ * @param path The path to the dictionary file.
+ * @param [script="han"] The script to segment. `han` includes Hiragana and Katakana.
+ * @param [segmentation="maxProbability"] How to choose between the possible segmentations.
+ */
+export function dictionarySplitPreTokenizerFromFile(
+  path: string,
+  script?: "han" | "thai",
+  segmentation?: "maxProbability" | "maxMatch"
+): PreTokenizer;
+
+/**
+ * Returns a new CodeIdentifiers PreTokenizer.
+ * This pre-tokenizer splits source code for code search. The identifiers are split on their
+ * case changes, underscores and digits, and the operators like `->`, `::` or `!=` are kept
+ * together.
+ *
+ * @param [splitIdentifiers=true] Whether to split the camelCase, PascalCase, snake_case and
+ * kebab-case identifiers.
+ * @param [keepIndentation=false] Whether to keep each run of spaces and tabs at the start of
+ * a line as a pre-token.
+ */
+export function codeIdentifiersPreTokenizer(
+  splitIdentifiers?: boolean,
+  keepIndentation?: boolean
+): PreTokenizer;
@@ -11,5 +11,9 @@ module.exports = {
   punctuationPreTokenizer: native.pre_tokenizers_Punctuation,
   sequencePreTokenizer: native.pre_tokenizers_Sequence,
   digitsPreTokenizer: native.pre_tokenizers_Digits,
+  unicodeWordsPreTokenizer: native.pre_tokenizers_UnicodeWords,
+  dictionarySplitPreTokenizer: native.pre_tokenizers_DictionarySplit,
+  dictionarySplitPreTokenizerFromFile: native.pre_tokenizers_DictionarySplit_FromFile,
+  codeIdentifiersPreTokenizer: native.pre_tokenizers_CodeIdentifiers,
   splitPreTokenizer: native.pre_tokenizers_Split,
 };
@@ -1,9 +1,12 @@
 import {
   byteLevelPreTokenizer,
+  codeIdentifiersPreTokenizer,
+  dictionarySplitPreTokenizer,
   metaspacePreTokenizer,
   punctuationPreTokenizer,
   sequencePreTokenizer,
   splitPreTokenizer,
+  unicodeWordsPreTokenizer,
   whitespaceSplitPreTokenizer,
 } from "./pre-tokenizers";
 
@@ -62,3 +65,80 @@ describe("sequencePreTokenizer", () => {
     expect(sequence3.constructor.name).toEqual("PreTokenizer");
   });
 });
+
+describe("unicodeWordsPreTokenizer", () => {
+  it("splits on the word boundaries", () => {
+    const pretok = unicodeWordsPreTokenizer();
+    expect(pretok.preTokenizeString("I can't pay $3.14!")).toEqual([
+      ["I", [0, 1]],
+      ["can't", [2, 7]],
+      ["pay", [8, 11]],
+      ["$", [12, 13]],
+      ["3.14", [13, 17]],
+      ["!", [17, 18]],
+    ]);
+  });
+
+  it("can keep the whitespace", () => {
+    const pretok = unicodeWordsPreTokenizer(false);
+    expect(pretok.preTokenizeString("a b")).toEqual([
+      ["a", [0, 1]],
+      [" ", [1, 2]],
+      ["b", [2, 3]],
+    ]);
+  });
+});
+
+describe("dictionarySplitPreTokenizer", () => {
+  const words = { 研究: 100, 研究生: 20, 生命: 80 };
+
+  it("segments with the highest probability", () => {
+    const pretok = dictionarySplitPreTokenizer(words);
+    expect(pretok.preTokenizeString("研究生命 ok")).toEqual([
+      ["研究", [0, 2]],
+      ["生命", [2, 4]],
+      [" ok", [4, 7]],
+    ]);
+  });
+
+  it("segments with the longest words", () => {
+    const pretok = dictionarySplitPreTokenizer(words, "han", "maxMatch");
+    expect(pretok.preTokenizeString("研究生命")).toEqual([
+      ["研究生", [0, 3]],
+      ["命", [3, 4]],
+    ]);
+  });
+
+  it("throws with an unknown script", () => {
+    expect(() => dictionarySplitPreTokenizer(words, "latin" as "han")).toThrow();
+  });
+});
+
+describe("codeIdentifiersPreTokenizer", () => {
+  it("splits the identifiers and keeps the operators", () => {
+    const pretok = codeIdentifiersPreTokenizer();
+    expect(pretok.preTokenizeString("getX() -> max_len")).toEqual([
+      ["get", [0, 3]],
+      ["X", [3, 4]],
+      ["(", [4, 5]],
+      [")", [5, 6]],
+      ["->", [7, 9]],
+      ["max", [10, 13]],
+      ["_", [13, 14]],
+      ["len", [14, 17]],
+    ]);
+  });
+
+  it("can keep the indentation", () => {
+    const pretok = codeIdentifiersPreTokenizer(false, true);
+    expect(pretok.preTokenizeString("if x:\n  getX()")).toEqual([
+      ["if", [0, 2]],
+      ["x", [3, 4]],
+      [":", [4, 5]],
+      ["  ", [6, 8]],
+      ["getX", [8, 12]],
+      ["(", [12, 13]],
+      [")", [13, 14]],
+    ]);
+  });
+});
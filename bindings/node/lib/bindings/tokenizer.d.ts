@@ -1,5 +1,5 @@
 import { Decoder } from "./decoders";
-import { PaddingDirection, TruncationStrategy } from "./enums";
+import { PaddingDirection, TruncationDirection, TruncationStrategy } from "./enums";
 import { Model } from "./models";
 import { Normalizer } from "./normalizers";
 import { PostProcessor } from "./post-processors";
@@ -22,19 +22,53 @@ export interface TruncationOptions {
    * @default TruncationStrategy.LongestFirst
    */
   strategy?: TruncationStrategy;
-}
-
-export interface TruncationConfiguration extends Required<TruncationOptions> {
   /**
-   * The maximum length at which to truncate
+   * The tokens to keep in each truncated sequence:
+   * - `TruncationDirection.Right` Keep the start of the sequence.
+   * - `TruncationDirection.Left` Keep the end of the sequence.
+   * - `{ headTail: n }` Keep `n` tokens at the start of the sequence, and its end for the rest.
+   * @default TruncationDirection.Right
+   */
+  direction?: TruncationDirection | { headTail: number };
+  /**
+   * Whether to cut only between words, in the truncated sequences and their overflowing parts.
+   * A word longer than the whole length still gets cut.
+   * @default false
+   */
+  wordBoundaries?: boolean;
+  /**
+   * The maximum length of the first sequence. The rest of it gets dropped, so that it is
+   * repeated as is in each overflowing part of a pair.
    */
-  maxLength: number;
+  maxFirstLength?: number;
+  /**
+   * The maximum length of the second sequence, whose overflowing parts use the stride.
+   * With `TruncationStrategy.OnlySecond`, it gets the rest of the max length after the first
+   * sequence, up to this length.
+   */
+  maxSecondLength?: number;
 }
 
+export type TruncationConfiguration = Required<
+  Omit<TruncationOptions, "maxFirstLength" | "maxSecondLength">
+> &
+  Pick<TruncationOptions, "maxFirstLength" | "maxSecondLength"> & {
+    /**
+     * The maximum length at which to truncate
+     */
+    maxLength: number;
+  };
+
 export type PaddingConfiguration = Required<
-  Omit<PaddingOptions, "maxLength" | "padToMultipleOf">
+  Omit<
+    PaddingOptions,
+    "maxLength" | "padToMultipleOf" | "bucketLengths" | "minPadLength" | "maxPadLength"
+  >
 > &
-  Pick<PaddingOptions, "maxLength" | "padToMultipleOf">;
+  Pick<
+    PaddingOptions,
+    "maxLength" | "padToMultipleOf" | "bucketLengths" | "minPadLength" | "maxPadLength"
+  >;
 
 export interface PaddingOptions {
   /**
@@ -52,6 +86,22 @@ export interface PaddingOptions {
    * @default undefined
    */
   padToMultipleOf?: number;
+  /**
+   * If specified, and `maxLength` is not, pad to the smallest of these lengths that fits
+   * the longest sequence of the batch, or to the longest sequence if none does.
+   * @default undefined
+   */
+  bucketLengths?: number[];
+  /**
+   * If specified, pad to at least this length.
+   * @default undefined
+   */
+  minPadLength?: number;
+  /**
+   * If specified, never pad beyond this length. The longer sequences are left as is.
+   * @default undefined
+   */
+  maxPadLength?: number;
   /**
    * The index to be used when padding
    * @default 0
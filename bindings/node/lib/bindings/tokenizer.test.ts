@@ -3,7 +3,7 @@
 
 import { promisify } from "util";
 
-import { PaddingDirection, TruncationStrategy } from "./enums";
+import { PaddingDirection, TruncationDirection, TruncationStrategy } from "./enums";
 import { BPE } from "./models";
 import { RawEncoding } from "./raw-encoding";
 import {
@@ -94,6 +94,12 @@ describe("Tokenizer", () => {
     expect(typeof tokenizer.train).toBe("function");
   });
 
+  it("throws errors matching their category", () => {
+    expect(() => Tokenizer.fromString("{")).toThrow(RangeError);
+    expect(() => Tokenizer.fromFile("missing.json")).toThrow(Error);
+    expect(() => Tokenizer.fromFile("missing.json")).not.toThrow(RangeError);
+  });
+
   describe("addTokens", () => {
     it("accepts a list of string as new tokens when initial model is empty", () => {
       const model = BPE.empty();
@@ -215,13 +221,27 @@ describe("Tokenizer", () => {
         expect(pairEncoding.getTokens()).toEqual(["my", "pair"]);
       });
 
+      it("keeps the end of the sequence with direction `left`", async () => {
+        tokenizer.setTruncation(2, { direction: TruncationDirection.Left });
+
+        const singleEncoding = await encode("my name is john", null);
+        expect(singleEncoding.getTokens()).toEqual(["is", "john"]);
+      });
+
       it("throws an error with strategy `only_second` and no pair is encoded", async () => {
         tokenizer.setTruncation(2, { strategy: TruncationStrategy.OnlySecond });
-        await expect(encode("my name is john", null)).rejects.toThrow();
+        await expect(encode("my name is john", null)).rejects.toThrow(RangeError);
       });
     });
 
     describe("when padding is enabled", () => {
+      it("pads to the smallest bucket length that fits", async () => {
+        tokenizer.setPadding({ bucketLengths: [4, 8] });
+
+        const singleEncoding = await encode("my name", null);
+        expect(singleEncoding.getTokens()).toEqual(["my", "name", "[PAD]", "[PAD]"]);
+      });
+
       it("does not pad anything with default options", async () => {
         tokenizer.setPadding();
 
@@ -38,8 +38,9 @@ declare_types! {
             let guard = cx.lock();
             let output = this.borrow(&guard)
                 .decoder.as_ref().unwrap()
-                .decode(tokens)
-                .map_err(|e| Error(format!("{}", e)))?;
+                .decode(tokens);
+            drop(guard);
+            let output = output.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(cx.string(output).upcast())
         }
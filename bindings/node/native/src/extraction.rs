@@ -24,6 +24,23 @@ impl From<Error> for neon::result::Throw {
 
 pub type LibResult<T> = std::result::Result<T, Error>;
 
+/// Throw the JS error matching the category of the given `tk::Error`: a `RangeError` for the
+/// invalid values, like a malformed JSON, a missing token or invalid parameters, and an `Error`
+/// for everything else, including the IO errors.
+pub fn throw_tk_error<'a, C: Context<'a>, T>(cx: &mut C, error: tk::Error) -> NeonResult<T> {
+    match error {
+        tk::Error::Serialization(_)
+        | tk::Error::Regex(_)
+        | tk::Error::Bpe(_)
+        | tk::Error::WordPiece(_)
+        | tk::Error::WordLevel(_)
+        | tk::Error::Unigram(_)
+        | tk::Error::Truncation(_)
+        | tk::Error::Configuration(_) => cx.throw_range_error(error.to_string()),
+        _ => cx.throw_error(error.to_string()),
+    }
+}
+
 /// This trait is to be implemented for any type that we want to extract from
 /// a JsValue.
 pub trait FromJsValue: Sized {
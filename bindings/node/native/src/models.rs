@@ -108,15 +108,15 @@ declare_types! {
 
             let this = cx.this();
             let guard = cx.lock();
-
             let files = this.borrow(&guard)
                 .model.as_ref().expect("Uninitialized Model")
                 .read().unwrap()
                 .save(
                     Path::new(&folder),
                     name.as_deref()
-                )
-                .map_err(|e| Error(format!("{}", e)))?;
+                );
+            drop(guard);
+            let files = files.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(neon_serde::to_value(&mut cx, &files)?)
         }
@@ -174,7 +174,7 @@ fn bpe_init(mut cx: FunctionContext) -> JsResult<JsModel> {
 
     let mut builder = tk::models::bpe::BPE::builder().vocab_and_merges(vocab, merges);
     builder = options.apply_to_bpe_builder(builder);
-    let model = builder.build().map_err(|e| Error(e.to_string()))?;
+    let model = builder.build().or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let guard = cx.lock();
@@ -257,7 +257,7 @@ fn wordpiece_init(mut cx: FunctionContext) -> JsResult<JsModel> {
 
     let mut builder = tk::models::wordpiece::WordPiece::builder().vocab(vocab);
     builder = options.apply_to_wordpiece_builder(builder);
-    let model = builder.build().map_err(|e| Error(e.to_string()))?;
+    let model = builder.build().or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let guard = cx.lock();
@@ -326,7 +326,7 @@ fn wordlevel_init(mut cx: FunctionContext) -> JsResult<JsModel> {
 
     let mut builder = tk::models::wordlevel::WordLevel::builder().vocab(vocab);
     builder = options.apply_to_wordlevel_builder(builder);
-    let model = builder.build().map_err(|e| Error(e.to_string()))?;
+    let model = builder.build().or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let guard = cx.lock();
@@ -383,7 +383,7 @@ fn unigram_init(mut cx: FunctionContext) -> JsResult<JsModel> {
         .unwrap_or_else(UnigramOptions::default);
 
     let unigram = tk::models::unigram::Unigram::from(vocab, options.unk_id)
-        .map_err(|e| Error(e.to_string()))?;
+        .or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let guard = cx.lock();
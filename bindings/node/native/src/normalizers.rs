@@ -3,6 +3,7 @@ extern crate tokenizers as tk;
 use crate::extraction::*;
 use neon::prelude::*;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use tk::normalizers::NormalizerWrapper;
@@ -78,9 +79,9 @@ declare_types! {
 
             let this = cx.this();
             let guard = cx.lock();
-            this.borrow(&guard)
-                .normalize(&mut normalized)
-                .map_err(|e| Error(format!("{}", e)))?;
+            let result = this.borrow(&guard).normalize(&mut normalized);
+            drop(guard);
+            result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(cx.string(normalized.get()).upcast())
         }
@@ -131,6 +132,50 @@ fn bert_normalizer(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
     Ok(normalizer)
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct CleanWhitespaceOptions {
+    collapse: bool,
+    normalize_spaces: bool,
+    remove_control: bool,
+    keep_newlines: bool,
+}
+impl Default for CleanWhitespaceOptions {
+    fn default() -> Self {
+        Self {
+            collapse: true,
+            normalize_spaces: true,
+            remove_control: true,
+            keep_newlines: false,
+        }
+    }
+}
+
+/// clean_whitespace(options?: {
+///   collapse?: bool = true,
+///   normalizeSpaces?: bool = true,
+///   removeControl?: bool = true,
+///   keepNewlines?: bool = false
+/// })
+fn clean_whitespace(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    let options = cx
+        .extract_opt::<CleanWhitespaceOptions>(0)?
+        .unwrap_or_else(CleanWhitespaceOptions::default);
+
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer = Some(
+        tk::normalizers::CleanWhitespace::new(
+            options.collapse,
+            options.normalize_spaces,
+            options.remove_control,
+            options.keep_newlines,
+        )
+        .into(),
+    );
+    Ok(normalizer)
+}
+
 /// nfd()
 fn nfd(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
     let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
@@ -222,17 +267,96 @@ fn lowercase(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
     Ok(normalizer)
 }
 
+/// localeLowercase(locale: string)
+fn locale_lowercase(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    let locale = cx.extract::<String>(0)?;
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer =
+        Some(tk::normalizers::utils::LocaleLowercase::new(locale).into());
+    Ok(normalizer)
+}
+
+/// transliterate(scripts?: string[])
+fn transliterate(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    use tk::normalizers::transliterate::{Script, Transliterate};
+
+    let transliterate = match cx.extract_opt::<Vec<String>>(0)? {
+        Some(scripts) => Transliterate::new(
+            scripts
+                .iter()
+                .map(|script| match script.as_str() {
+                    "latin" => Ok(Script::Latin),
+                    "greek" => Ok(Script::Greek),
+                    "cyrillic" => Ok(Script::Cyrillic),
+                    _ => Err(Error(format!("Unknown script: {}", script))),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => Transliterate::default(),
+    };
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer = Some(transliterate.into());
+    Ok(normalizer)
+}
+
+/// htmlStrip(decodeEntities?: boolean, blockSeparator?: "none" | "space" | "newline")
+fn html_strip(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    use tk::normalizers::html::{BlockSeparator, HtmlStrip};
+
+    let decode_entities = cx.extract_opt::<bool>(0)?.unwrap_or(true);
+    let block_separator = match cx.extract_opt::<String>(1)?.as_deref() {
+        None | Some("space") => BlockSeparator::Space,
+        Some("none") => BlockSeparator::None,
+        Some("newline") => BlockSeparator::Newline,
+        Some(separator) => {
+            return cx.throw_error(format!("Unknown block separator: {}", separator))
+        }
+    };
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer =
+        Some(HtmlStrip::new(decode_entities, block_separator).into());
+    Ok(normalizer)
+}
+
+/// caseFold()
+fn case_fold(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer = Some(tk::normalizers::unicode::CaseFold.into());
+    Ok(normalizer)
+}
+
+/// nfkcCasefold()
+fn nfkc_casefold(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer = Some(tk::normalizers::unicode::NfkcCasefold.into());
+    Ok(normalizer)
+}
+
 /// replace()
 fn replace(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
     let pattern: String = cx.extract::<String>(0)?;
     let content: String = cx.extract::<String>(1)?;
+    let replace = tk::normalizers::replace::Replace::new(pattern, content)
+        .or_else(|e| throw_tk_error(&mut cx, e))?;
     let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
     let guard = cx.lock();
-    normalizer.borrow_mut(&guard).normalizer = Some(
-        tk::normalizers::replace::Replace::new(pattern, content)
-            .map_err(|e| Error(e.to_string()))?
-            .into(),
-    );
+    normalizer.borrow_mut(&guard).normalizer = Some(replace.into());
+    Ok(normalizer)
+}
+
+/// replaceMap(rules: { [pattern: string]: string })
+fn replace_map(mut cx: FunctionContext) -> JsResult<JsNormalizer> {
+    let rules = cx.extract::<HashMap<String, String>>(0)?;
+    let replace_map =
+        tk::normalizers::ReplaceMap::new(rules).or_else(|e| throw_tk_error(&mut cx, e))?;
+    let mut normalizer = JsNormalizer::new::<_, JsNormalizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    normalizer.borrow_mut(&guard).normalizer = Some(replace_map.into());
     Ok(normalizer)
 }
 
@@ -266,11 +390,18 @@ pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
     m.export_function(&format!("{}_NFKC", prefix), nfkc)?;
     m.export_function(&format!("{}_Sequence", prefix), sequence)?;
     m.export_function(&format!("{}_Lowercase", prefix), lowercase)?;
+    m.export_function(&format!("{}_LocaleLowercase", prefix), locale_lowercase)?;
+    m.export_function(&format!("{}_CaseFold", prefix), case_fold)?;
+    m.export_function(&format!("{}_NfkcCasefold", prefix), nfkc_casefold)?;
     m.export_function(&format!("{}_Strip", prefix), strip)?;
     m.export_function(&format!("{}_StripAccents", prefix), strip_accents)?;
     m.export_function(&format!("{}_Nmt", prefix), nmt)?;
+    m.export_function(&format!("{}_Transliterate", prefix), transliterate)?;
+    m.export_function(&format!("{}_HtmlStrip", prefix), html_strip)?;
     m.export_function(&format!("{}_Precompiled", prefix), precompiled)?;
     m.export_function(&format!("{}_Replace", prefix), replace)?;
+    m.export_function(&format!("{}_ReplaceMap", prefix), replace_map)?;
+    m.export_function(&format!("{}_CleanWhitespace", prefix), clean_whitespace)?;
     Ok(())
 }
 
@@ -2,10 +2,12 @@ extern crate tokenizers as tk;
 
 use crate::extraction::*;
 use neon::prelude::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use tk::normalizer::SplitDelimiterBehavior;
+use tk::pre_tokenizers::dictionary;
 use tk::pre_tokenizers::PreTokenizerWrapper;
 use tk::PreTokenizedString;
 
@@ -109,10 +111,9 @@ declare_types! {
 
             let this = cx.this();
             let guard = cx.lock();
-
-            this.borrow(&guard)
-                .pre_tokenize(&mut pretokenized)
-                .map_err(|e| Error(format!("{}", e)))?;
+            let result = this.borrow(&guard).pre_tokenize(&mut pretokenized);
+            drop(guard);
+            result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             let splits = pretokenized
                 .get_splits(tk::OffsetReferential::Original, tk::OffsetType::Char)
@@ -191,13 +192,11 @@ fn split(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
     let behavior: JsSplitDelimiterBehavior = cx.extract::<JsSplitDelimiterBehavior>(1)?;
     let invert: bool = cx.extract_opt::<bool>(2)?.unwrap_or(false);
 
+    let split = tk::pre_tokenizers::split::Split::new(pattern, behavior.into(), invert)
+        .or_else(|e| throw_tk_error(&mut cx, e))?;
     let mut pretok = JsPreTokenizer::new::<_, JsPreTokenizer, _>(&mut cx, vec![])?;
     let guard = cx.lock();
-    pretok.borrow_mut(&guard).pretok = Some(
-        tk::pre_tokenizers::split::Split::new(pattern, behavior.into(), invert)
-            .map_err(|e| Error(e.to_string()))?
-            .into(),
-    );
+    pretok.borrow_mut(&guard).pretok = Some(split.into());
     Ok(pretok)
 }
 
@@ -263,6 +262,90 @@ fn digits(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
     Ok(pretok)
 }
 
+/// unicode_words(removeWhitespace?: bool)
+fn unicode_words(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
+    let remove_whitespace = cx.extract_opt::<bool>(0)?.unwrap_or(true);
+
+    let mut pretok = JsPreTokenizer::new::<_, JsPreTokenizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    pretok.borrow_mut(&guard).pretok =
+        Some(tk::pre_tokenizers::unicode_words::UnicodeWords::new(remove_whitespace).into());
+
+    Ok(pretok)
+}
+
+fn dictionary_options(
+    cx: &mut FunctionContext,
+    index: i32,
+) -> NeonResult<(dictionary::Script, dictionary::Segmentation)> {
+    let script = match cx.extract_opt::<String>(index)?.as_deref() {
+        None | Some("han") => dictionary::Script::Han,
+        Some("thai") => dictionary::Script::Thai,
+        Some(script) => return cx.throw_error(format!("Unknown script: {}", script)),
+    };
+    let segmentation = match cx.extract_opt::<String>(index + 1)?.as_deref() {
+        None | Some("maxProbability") => dictionary::Segmentation::MaxProbability,
+        Some("maxMatch") => dictionary::Segmentation::MaxMatch,
+        Some(segmentation) => {
+            return cx.throw_error(format!("Unknown segmentation: {}", segmentation))
+        }
+    };
+    Ok((script, segmentation))
+}
+
+/// dictionary_split(
+///   words: { [word: string]: number },
+///   script?: "han" | "thai",
+///   segmentation?: "maxProbability" | "maxMatch"
+/// )
+fn dictionary_split(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
+    let words = cx.extract::<HashMap<String, u64>>(0)?;
+    let (script, segmentation) = dictionary_options(&mut cx, 1)?;
+
+    let mut pretok = JsPreTokenizer::new::<_, JsPreTokenizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    pretok.borrow_mut(&guard).pretok =
+        Some(dictionary::DictionarySplit::new(words, script, segmentation).into());
+
+    Ok(pretok)
+}
+
+/// dictionary_split_from_file(
+///   path: string,
+///   script?: "han" | "thai",
+///   segmentation?: "maxProbability" | "maxMatch"
+/// )
+fn dictionary_split_from_file(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
+    let path = cx.extract::<String>(0)?;
+    let (script, segmentation) = dictionary_options(&mut cx, 1)?;
+    let dictionary_split = dictionary::DictionarySplit::from_file(path, script, segmentation)
+        .or_else(|e| throw_tk_error(&mut cx, e))?;
+
+    let mut pretok = JsPreTokenizer::new::<_, JsPreTokenizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    pretok.borrow_mut(&guard).pretok = Some(dictionary_split.into());
+
+    Ok(pretok)
+}
+
+/// code_identifiers(splitIdentifiers?: bool, keepIndentation?: bool)
+fn code_identifiers(mut cx: FunctionContext) -> JsResult<JsPreTokenizer> {
+    let split_identifiers = cx.extract_opt::<bool>(0)?.unwrap_or(true);
+    let keep_indentation = cx.extract_opt::<bool>(1)?.unwrap_or(false);
+
+    let mut pretok = JsPreTokenizer::new::<_, JsPreTokenizer, _>(&mut cx, vec![])?;
+    let guard = cx.lock();
+    pretok.borrow_mut(&guard).pretok = Some(
+        tk::pre_tokenizers::code_identifiers::CodeIdentifiers::new(
+            split_identifiers,
+            keep_indentation,
+        )
+        .into(),
+    );
+
+    Ok(pretok)
+}
+
 /// Register everything here
 pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
     m.export_function(&format!("{}_ByteLevel", prefix), byte_level)?;
@@ -282,6 +365,13 @@ pub fn register(m: &mut ModuleContext, prefix: &str) -> NeonResult<()> {
     m.export_function(&format!("{}_Punctuation", prefix), punctuation)?;
     m.export_function(&format!("{}_Sequence", prefix), sequence)?;
     m.export_function(&format!("{}_Digits", prefix), digits)?;
+    m.export_function(&format!("{}_UnicodeWords", prefix), unicode_words)?;
+    m.export_function(&format!("{}_DictionarySplit", prefix), dictionary_split)?;
+    m.export_function(
+        &format!("{}_DictionarySplit_FromFile", prefix),
+        dictionary_split_from_file,
+    )?;
+    m.export_function(&format!("{}_CodeIdentifiers", prefix), code_identifiers)?;
     Ok(())
 }
 
@@ -1,5 +1,6 @@
 extern crate tokenizers as tk;
 
+use crate::extraction::throw_tk_error;
 use crate::models::*;
 use neon::prelude::*;
 use std::sync::{Arc, RwLock};
@@ -16,13 +17,13 @@ impl WordPieceFromFilesTask {
 
 impl Task for WordPieceFromFilesTask {
     type Output = WordPiece;
-    type Error = String;
+    type Error = tk::Error;
     type JsEvent = JsValue;
 
     fn perform(&self) -> Result<Self::Output, Self::Error> {
         let builder: Option<WordPieceBuilder> =
             unsafe { std::ptr::replace(&self.0 as *const _ as *mut _, None) };
-        builder.unwrap().build().map_err(|e| format!("{}", e))
+        builder.unwrap().build()
     }
 
     fn complete(
@@ -30,7 +31,7 @@ impl Task for WordPieceFromFilesTask {
         mut cx: TaskContext,
         result: Result<Self::Output, Self::Error>,
     ) -> JsResult<Self::JsEvent> {
-        let wordpiece = result.map_err(|e| cx.throw_error::<_, ()>(e).unwrap_err())?;
+        let wordpiece = result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
         let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
         let guard = cx.lock();
@@ -49,13 +50,13 @@ impl WordLevelFromFilesTask {
 
 impl Task for WordLevelFromFilesTask {
     type Output = WordLevel;
-    type Error = String;
+    type Error = tk::Error;
     type JsEvent = JsValue;
 
     fn perform(&self) -> Result<Self::Output, Self::Error> {
         let builder: Option<WordLevelBuilder> =
             unsafe { std::ptr::replace(&self.0 as *const _ as *mut _, None) };
-        builder.unwrap().build().map_err(|e| format!("{}", e))
+        builder.unwrap().build()
     }
 
     fn complete(
@@ -63,7 +64,7 @@ impl Task for WordLevelFromFilesTask {
         mut cx: TaskContext,
         result: Result<Self::Output, Self::Error>,
     ) -> JsResult<Self::JsEvent> {
-        let wordlevel = result.map_err(|e| cx.throw_error::<_, ()>(e).unwrap_err())?;
+        let wordlevel = result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
         let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
         let guard = cx.lock();
@@ -82,13 +83,13 @@ impl BPEFromFilesTask {
 
 impl Task for BPEFromFilesTask {
     type Output = BPE;
-    type Error = String;
+    type Error = tk::Error;
     type JsEvent = JsValue;
 
     fn perform(&self) -> Result<Self::Output, Self::Error> {
         let builder: Option<BpeBuilder> =
             unsafe { std::ptr::replace(&self.0 as *const _ as *mut _, None) };
-        builder.unwrap().build().map_err(|e| format!("{}", e))
+        builder.unwrap().build()
     }
 
     fn complete(
@@ -96,7 +97,7 @@ impl Task for BPEFromFilesTask {
         mut cx: TaskContext,
         result: Result<Self::Output, Self::Error>,
     ) -> JsResult<Self::JsEvent> {
-        let bpe = result.map_err(|e| cx.throw_error::<_, ()>(e).unwrap_err())?;
+        let bpe = result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
         let mut js_model = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
         let guard = cx.lock();
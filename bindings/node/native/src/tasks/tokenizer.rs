@@ -1,6 +1,7 @@
 extern crate tokenizers as tk;
 
 use crate::encoding::*;
+use crate::extraction::throw_tk_error;
 use crate::tokenizer::Tokenizer;
 use neon::prelude::*;
 use tk::tokenizer::{EncodeInput, Encoding};
@@ -17,7 +18,7 @@ pub enum EncodeOutput {
 
 impl Task for EncodeTask<'static> {
     type Output = EncodeOutput;
-    type Error = String;
+    type Error = tk::Error;
     type JsEvent = JsValue;
 
     fn perform(&self) -> Result<Self::Output, Self::Error> {
@@ -31,10 +32,11 @@ impl Task for EncodeTask<'static> {
                     .read()
                     .unwrap()
                     .encode_char_offsets(
-                        input.take().ok_or("No provided input")?,
+                        input
+                            .take()
+                            .ok_or_else(|| tk::Error::other("No provided input"))?,
                         *add_special_tokens,
                     )
-                    .map_err(|e| format!("{}", e))
                     .map(EncodeOutput::Single)
             }
             EncodeTask::Batch(worker, input, add_special_tokens) => {
@@ -46,10 +48,11 @@ impl Task for EncodeTask<'static> {
                     .read()
                     .unwrap()
                     .encode_batch_char_offsets(
-                        input.take().ok_or("No provided input")?,
+                        input
+                            .take()
+                            .ok_or_else(|| tk::Error::other("No provided input"))?,
                         *add_special_tokens,
                     )
-                    .map_err(|e| format!("{}", e))
                     .map(EncodeOutput::Batch)
             }
         }
@@ -60,7 +63,7 @@ impl Task for EncodeTask<'static> {
         mut cx: TaskContext,
         result: Result<Self::Output, Self::Error>,
     ) -> JsResult<Self::JsEvent> {
-        match result.map_err(|e| cx.throw_error::<_, ()>(e).unwrap_err())? {
+        match result.or_else(|e| throw_tk_error(&mut cx, e))? {
             EncodeOutput::Single(encoding) => {
                 let mut js_encoding = JsEncoding::new::<_, JsEncoding, _>(&mut cx, vec![])?;
                 // Set the actual encoding
@@ -97,7 +100,7 @@ pub enum DecodeOutput {
 
 impl Task for DecodeTask {
     type Output = DecodeOutput;
-    type Error = String;
+    type Error = tk::Error;
     type JsEvent = JsValue;
 
     fn perform(&self) -> Result<Self::Output, Self::Error> {
@@ -107,14 +110,12 @@ impl Task for DecodeTask {
                 .read()
                 .unwrap()
                 .decode(ids.to_vec(), *skip_special_tokens)
-                .map_err(|e| format!("{}", e))
                 .map(DecodeOutput::Single),
             DecodeTask::Batch(worker, ids, skip_special_tokens) => worker
                 .tokenizer
                 .read()
                 .unwrap()
                 .decode_batch(ids.to_vec(), *skip_special_tokens)
-                .map_err(|e| format!("{}", e))
                 .map(DecodeOutput::Batch),
         }
     }
@@ -124,7 +125,7 @@ impl Task for DecodeTask {
         mut cx: TaskContext,
         result: Result<Self::Output, Self::Error>,
     ) -> JsResult<Self::JsEvent> {
-        match result.map_err(|e| cx.throw_error::<_, ()>(e).unwrap_err())? {
+        match result.or_else(|e| throw_tk_error(&mut cx, e))? {
             DecodeOutput::Single(string) => Ok(cx.string(string).upcast()),
             DecodeOutput::Batch(strings) => {
                 let result = JsArray::new(&mut cx, strings.len() as u32);
@@ -259,6 +259,14 @@ pub enum TruncationStrategyDef {
     OnlySecond,
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "tk::TruncationDirection", rename_all = "camelCase")]
+pub enum TruncationDirectionDef {
+    Right,
+    Left,
+    HeadTail(usize),
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(
     remote = "tk::TruncationParams",
@@ -270,6 +278,13 @@ pub struct TruncationParamsDef {
     #[serde(with = "TruncationStrategyDef")]
     strategy: tk::TruncationStrategy,
     stride: usize,
+    #[serde(with = "TruncationDirectionDef")]
+    direction: tk::TruncationDirection,
+    word_boundaries: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_first_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_second_length: Option<usize>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -289,6 +304,7 @@ pub enum PaddingDirectionDef {
 // we want it to actually be very different from the classic representation.
 // In Rust, we use an enum to define the strategy, but in JS, we just want to have a optional
 // length number => If defined we use the Fixed(n) strategy and otherwise the BatchLongest.
+// The optional bucket lengths give the Bucketed strategy, when there is no fixed length.
 pub mod padding_strategy_serde {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -297,6 +313,8 @@ pub mod padding_strategy_serde {
     struct Strategy {
         #[serde(skip_serializing_if = "Option::is_none")]
         max_length: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        bucket_lengths: Option<Vec<usize>>,
     }
 
     pub fn serialize<S>(value: &tk::PaddingStrategy, serializer: S) -> Result<S::Ok, S::Error>
@@ -305,8 +323,12 @@ pub mod padding_strategy_serde {
     {
         let s = Strategy {
             max_length: match value {
-                tk::PaddingStrategy::BatchLongest => None,
                 tk::PaddingStrategy::Fixed(s) => Some(*s),
+                _ => None,
+            },
+            bucket_lengths: match value {
+                tk::PaddingStrategy::Bucketed(lengths) => Some(lengths.clone()),
+                _ => None,
             },
         };
         s.serialize(serializer)
@@ -319,6 +341,8 @@ pub mod padding_strategy_serde {
         let v = Strategy::deserialize(deserializer)?;
         if let Some(length) = v.max_length {
             Ok(tk::PaddingStrategy::Fixed(length))
+        } else if let Some(lengths) = v.bucket_lengths {
+            Ok(tk::PaddingStrategy::Bucketed(lengths))
         } else {
             Ok(tk::PaddingStrategy::BatchLongest)
         }
@@ -338,6 +362,10 @@ pub struct PaddingParamsDef {
     direction: tk::PaddingDirection,
     #[serde(skip_serializing_if = "Option::is_none")]
     pad_to_multiple_of: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_pad_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_pad_length: Option<usize>,
     pad_id: u32,
     pad_type_id: u32,
     pad_token: String,
@@ -375,8 +403,9 @@ declare_types! {
             let guard = cx.lock();
             let s = this.borrow(&guard)
                 .tokenizer.read().unwrap()
-                .to_string(pretty)
-                .map_err(|e| Error(format!("{}", e)))?;
+                .to_string(pretty);
+            drop(guard);
+            let s = s.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(cx.string(s).upcast())
         }
@@ -388,10 +417,11 @@ declare_types! {
 
             let this = cx.this();
             let guard = cx.lock();
-            this.borrow(&guard)
+            let result = this.borrow(&guard)
                 .tokenizer.read().unwrap()
-                .save(&path, pretty)
-                .map_err(|e| Error(format!("{}", e)))?;
+                .save(&path, pretty);
+            drop(guard);
+            result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(cx.undefined().upcast())
         }
@@ -765,11 +795,12 @@ declare_types! {
 
             let mut this = cx.this();
             let guard = cx.lock();
-
-            this.borrow_mut(&guard)
+            let result = this.borrow_mut(&guard)
                 .tokenizer.write().unwrap()
                 .train_from_files(&mut trainer, files)
-                .map_err(|e| Error(format!("{}", e)))?;
+                .map(|_| ());
+            drop(guard);
+            result.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             Ok(cx.undefined().upcast())
         }
@@ -789,8 +820,9 @@ declare_types! {
             let guard = cx.lock();
             let encoding = this.borrow(&guard)
                 .tokenizer.read().unwrap()
-                .post_process(encoding.into(), pair.map(|p| p.into()), add_special_tokens)
-                .map_err(|e| Error(format!("{}", e)))?;
+                .post_process(encoding.into(), pair.map(|p| p.into()), add_special_tokens);
+            drop(guard);
+            let encoding = encoding.or_else(|e| throw_tk_error(&mut cx, e))?;
 
             let mut js_encoding = JsEncoding::new::<_, JsEncoding, _>(&mut cx, vec![])?;
             let guard = cx.lock();
@@ -984,7 +1016,7 @@ pub fn tokenizer_from_string(mut cx: FunctionContext) -> JsResult<JsTokenizer> {
         PreTokenizer,
         Processor,
         Decoder,
-    > = s.parse().map_err(|e| Error(format!("{}", e)))?;
+    > = s.parse().or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let js_model: Handle<JsModel> = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let mut js_tokenizer = JsTokenizer::new(&mut cx, vec![js_model])?;
@@ -997,8 +1029,8 @@ pub fn tokenizer_from_string(mut cx: FunctionContext) -> JsResult<JsTokenizer> {
 pub fn tokenizer_from_file(mut cx: FunctionContext) -> JsResult<JsTokenizer> {
     let s = cx.extract::<String>(0)?;
 
-    let tokenizer = tk::tokenizer::TokenizerImpl::from_file(s)
-        .map_err(|e| Error(format!("Error loading from file{}", e)))?;
+    let tokenizer =
+        tk::tokenizer::TokenizerImpl::from_file(s).or_else(|e| throw_tk_error(&mut cx, e))?;
 
     let js_model: Handle<JsModel> = JsModel::new::<_, JsModel, _>(&mut cx, vec![])?;
     let mut js_tokenizer = JsTokenizer::new(&mut cx, vec![js_model])?;
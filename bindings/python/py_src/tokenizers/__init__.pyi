@@ -713,6 +713,9 @@ class Tokenizer:
         pad_token="[PAD]",
         length=None,
         pad_to_multiple_of=None,
+        bucket_lengths=None,
+        min_pad_length=None,
+        max_pad_length=None,
     ):
         """
         Enable the padding
@@ -738,9 +741,29 @@ class Tokenizer:
             length (:obj:`int`, `optional`):
                 If specified, the length at which to pad. If not specified we pad using the size of
                 the longest sequence in a batch.
+
+            bucket_lengths (:obj:`List[int]`, `optional`):
+                If specified, and ``length`` is not, we pad to the smallest of these lengths that
+                fits the longest sequence in a batch, or to this longest sequence if none does.
+
+            min_pad_length (:obj:`int`, `optional`):
+                If specified, we pad to at least this length.
+
+            max_pad_length (:obj:`int`, `optional`):
+                If specified, we never pad beyond this length. The longer sequences are left as is.
         """
         pass
-    def enable_truncation(self, max_length, stride=0, strategy="longest_first"):
+    def enable_truncation(
+        self,
+        max_length,
+        stride=0,
+        strategy="longest_first",
+        direction="right",
+        head_length=0,
+        word_boundaries=False,
+        max_first_length=None,
+        max_second_length=None,
+    ):
         """
         Enable truncation
 
@@ -755,6 +778,27 @@ class Tokenizer:
             strategy (:obj:`str`, `optional`, defaults to :obj:`longest_first`):
                 The strategy used to truncation. Can be one of ``longest_first``, ``only_first`` or
                 ``only_second``.
+
+            direction (:obj:`str`, `optional`, defaults to :obj:`right`):
+                The tokens to keep in each truncated sequence. Can be one of ``right`` to keep its
+                start, ``left`` to keep its end, or ``head_tail`` to keep ``head_length`` tokens
+                at its start and its end for the rest.
+
+            head_length (:obj:`int`, `optional`, defaults to :obj:`0`):
+                The number of tokens kept at the start of each sequence with ``head_tail``
+
+            word_boundaries (:obj:`bool`, `optional`, defaults to :obj:`False`):
+                Whether to cut only between words, in the truncated sequences and their
+                overflowing parts. A word longer than ``max_length`` still gets cut.
+
+            max_first_length (:obj:`int`, `optional`):
+                The maximum length of the first sequence. The rest of it gets dropped, so that it
+                is repeated as is in each overflowing part of a pair.
+
+            max_second_length (:obj:`int`, `optional`):
+                The maximum length of the second sequence, whose overflowing parts use ``stride``.
+                With ``only_second``, it gets the rest of ``max_length`` after the first sequence,
+                up to this length.
         """
         pass
     def encode(self, sequence, pair=None, is_pretokenized=False, add_special_tokens=True):
@@ -61,6 +61,9 @@ class BaseTokenizer:
         pad_type_id: Optional[int] = 0,
         pad_token: Optional[str] = "[PAD]",
         length: Optional[int] = None,
+        bucket_lengths: Optional[List[int]] = None,
+        min_pad_length: Optional[int] = None,
+        max_pad_length: Optional[int] = None,
     ):
         """Change the padding strategy
 
@@ -85,6 +88,16 @@ class BaseTokenizer:
             length: (`optional`) unsigned int:
                 If specified, the length at which to pad. If not specified
                 we pad using the size of the longest sequence in a batch
+
+            bucket_lengths: (`optional`) List[int]:
+                If specified, and `length` is not, we pad to the smallest of these lengths
+                that fits the longest sequence in a batch
+
+            min_pad_length: (`optional`) unsigned int:
+                If specified, we pad to at least this length
+
+            max_pad_length: (`optional`) unsigned int:
+                If specified, we never pad beyond this length
         """
         return self._tokenizer.enable_padding(
             direction=direction,
@@ -93,6 +106,9 @@ class BaseTokenizer:
             pad_type_id=pad_type_id,
             pad_token=pad_token,
             length=length,
+            bucket_lengths=bucket_lengths,
+            min_pad_length=min_pad_length,
+            max_pad_length=max_pad_length,
         )
 
     def no_padding(self):
@@ -110,7 +126,15 @@ class BaseTokenizer:
         return self._tokenizer.padding
 
     def enable_truncation(
-        self, max_length: int, stride: Optional[int] = 0, strategy: Optional[str] = "longest_first"
+        self,
+        max_length: int,
+        stride: Optional[int] = 0,
+        strategy: Optional[str] = "longest_first",
+        direction: Optional[str] = "right",
+        head_length: Optional[int] = 0,
+        word_boundaries: Optional[bool] = False,
+        max_first_length: Optional[int] = None,
+        max_second_length: Optional[int] = None,
     ):
         """Change the truncation options
 
@@ -124,8 +148,32 @@ class BaseTokenizer:
 
             strategy: (`optional`) str:
                 Can be one of `longest_first`, `only_first` or `only_second`
+
+            direction: (`optional`) str:
+                Can be one of `right`, `left` or `head_tail`
+
+            head_length: (`optional`) unsigned int:
+                The number of tokens kept at the start of each sequence with `head_tail`
+
+            word_boundaries: (`optional`) bool:
+                Whether to cut only between words
+
+            max_first_length: (`optional`) unsigned int:
+                The maximum length of the first sequence, repeated in each overflowing part
+
+            max_second_length: (`optional`) unsigned int:
+                The maximum length of the second sequence
         """
-        return self._tokenizer.enable_truncation(max_length, stride=stride, strategy=strategy)
+        return self._tokenizer.enable_truncation(
+            max_length,
+            stride=stride,
+            strategy=strategy,
+            direction=direction,
+            head_length=head_length,
+            word_boundaries=word_boundaries,
+            max_first_length=max_first_length,
+            max_second_length=max_second_length,
+        )
 
     def no_truncation(self):
         """ Disable truncation """
@@ -8,11 +8,18 @@ NFC = normalizers.NFC
 NFKC = normalizers.NFKC
 Sequence = normalizers.Sequence
 Lowercase = normalizers.Lowercase
+LocaleLowercase = normalizers.LocaleLowercase
+CaseFold = normalizers.CaseFold
+NfkcCasefold = normalizers.NfkcCasefold
 Strip = normalizers.Strip
 StripAccents = normalizers.StripAccents
 Nmt = normalizers.Nmt
+Transliterate = normalizers.Transliterate
+HtmlStrip = normalizers.HtmlStrip
 Precompiled = normalizers.Precompiled
 Replace = normalizers.Replace
+ReplaceMap = normalizers.ReplaceMap
+CleanWhitespace = normalizers.CleanWhitespace
 
 
 NORMALIZERS = {"nfc": NFC, "nfd": NFD, "nfkc": NFKC, "nfkd": NFKD}
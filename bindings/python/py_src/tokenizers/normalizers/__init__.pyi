@@ -100,6 +100,210 @@ class BertNormalizer(Normalizer):
         """
         pass
 
+class CaseFold(Normalizer):
+    """
+    CaseFold Normalizer
+
+    Applies the full Unicode case folding, which is more thorough than :class:`Lowercase`
+    to compare strings regardless of their case. For example, both "ß" and "ẞ" give "ss".
+    """
+
+    def __init__(self):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
+class CleanWhitespace(Normalizer):
+    """
+    CleanWhitespace normalizer
+
+    Cleans the whitespace of the input, while keeping the alignments.
+
+    Args:
+        collapse (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to collapse each run of whitespace into its first character
+
+        normalize_spaces (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to convert every kind of whitespace, like tabs or no-break spaces,
+            to a space
+
+        remove_control (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to remove the control and format characters, like the zero-width
+            spaces and joiners, or the bidi controls
+
+        keep_newlines (:obj:`bool`, `optional`, defaults to :obj:`False`):
+            Whether to keep the newlines apart from the other whitespace. They are then
+            converted to a line feed instead of a space, and a collapsed run that contains
+            one becomes this newline.
+    """
+
+    def __init__(
+        self, collapse=True, normalize_spaces=True, remove_control=True, keep_newlines=False
+    ):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
+class LocaleLowercase(Normalizer):
+    """
+    LocaleLowercase Normalizer
+
+    Lowercases like :class:`Lowercase`, but also applies the conditional rules of the
+    Unicode SpecialCasing for the language of the given locale: the dotted and dotless i
+    in Turkish and Azerbaijani, the accents in Lithuanian, and the final sigma.
+
+    Args:
+        locale (:obj:`str`):
+            The locale of the text, like :obj:`"tr"` or :obj:`"lt-LT"`
+    """
+
+    def __init__(self, locale):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
+class HtmlStrip(Normalizer):
+    """
+    HtmlStrip normalizer
+
+    Removes the HTML tags and comments, along with the content of the script and style
+    elements, and decodes the character references like :obj:`&amp;`. The offsets still
+    point into the raw markup.
+
+    Args:
+        decode_entities (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to decode the character references
+
+        block_separator (:obj:`str`, `optional`, defaults to :obj:`space`):
+            What replaces the tags of the block elements, like :obj:`<p>` or :obj:`<br>`.
+            Either :obj:`none`, :obj:`space` or :obj:`newline`.
+    """
+
+    def __init__(self, decode_entities=True, block_separator="space"):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
 class Lowercase(Normalizer):
     """
     Lowercase Normalizer
@@ -300,6 +504,49 @@ class NFKD(Normalizer):
         """
         pass
 
+class NfkcCasefold(Normalizer):
+    """
+    NfkcCasefold Normalizer
+
+    Applies the NFKC_Casefold normalization of Unicode: removes the default ignorable
+    code points, and then applies NFKC, the case folding, and NFKC again.
+    """
+
+    def __init__(self):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
 class Nmt(Normalizer):
     """
     Nmt normalizer
@@ -421,6 +668,54 @@ class Replace(Normalizer):
         """
         pass
 
+class ReplaceMap(Normalizer):
+    """
+    ReplaceMap normalizer
+
+    Replaces the literal patterns of a table of rules with their content, in a single pass.
+    At each position, the longest pattern that matches is replaced, and the new content is
+    never matched again.
+
+    Args:
+        rules (:obj:`Dict[str, str]`):
+            The content to use for each pattern. The patterns can't be empty.
+    """
+
+    def __init__(self, rules):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
+
 class Sequence(Normalizer):
     """
     Allows concatenating multiple other Normalizer as a Sequence.
@@ -543,3 +838,52 @@ class StripAccents(Normalizer):
             :obj:`str`: A string after normalization
         """
         pass
+
+class Transliterate(Normalizer):
+    """
+    Transliterate normalizer
+
+    Transliterates the characters of the given scripts to their closest ASCII forms,
+    using built-in tables. The accents are removed, and the characters without a known
+    transliteration are kept as is.
+
+    Args:
+        scripts (:obj:`List[str]`, `optional`):
+            The scripts to transliterate, among :obj:`latin`, :obj:`greek` and
+            :obj:`cyrillic`. All of them by default.
+    """
+
+    def __init__(self, scripts=None):
+        pass
+    def normalize(self, normalized):
+        """
+        Normalize a :class:`~tokenizers.NormalizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.NormalizedString` to
+        keep track of the alignment information. If you just want to see the result
+        of the normalization on a raw string, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize_str`
+
+        Args:
+            normalized (:class:`~tokenizers.NormalizedString`):
+                The normalized string on which to apply this
+                :class:`~tokenizers.normalizers.Normalizer`
+        """
+        pass
+    def normalize_str(self, sequence):
+        """
+        Normalize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.normalizers.Normalizer` but it does not keep track of the alignment
+        information. If you need to get/convert offsets, you can use
+        :meth:`~tokenizers.normalizers.Normalizer.normalize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to normalize
+
+        Returns:
+            :obj:`str`: A string after normalization
+        """
+        pass
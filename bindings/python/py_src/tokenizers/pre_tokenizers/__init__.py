@@ -11,5 +11,8 @@ Punctuation = pre_tokenizers.Punctuation
 Sequence = pre_tokenizers.Sequence
 Split = pre_tokenizers.Split
 UnicodeScripts = pre_tokenizers.UnicodeScripts
+UnicodeWords = pre_tokenizers.UnicodeWords
+DictionarySplit = pre_tokenizers.DictionarySplit
+CodeIdentifiers = pre_tokenizers.CodeIdentifiers
 Whitespace = pre_tokenizers.Whitespace
 WhitespaceSplit = pre_tokenizers.WhitespaceSplit
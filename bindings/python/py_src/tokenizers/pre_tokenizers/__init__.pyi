@@ -198,6 +198,146 @@ class CharDelimiterSplit(PreTokenizer):
         """
         pass
 
+class CodeIdentifiers(PreTokenizer):
+    """
+    This pre-tokenizer splits source code for code search
+
+    The identifiers are split on their case changes, underscores and digits, the operators
+    like ``->``, ``::`` or ``!=`` are kept together, and every other punctuation is on its own::
+
+        "getHTTP(max_len) -> x" -> "get", "HTTP", "(", "max", "_", "len", ")", "->", "x"
+
+    Args:
+        split_identifiers (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to split the camelCase, PascalCase, snake_case and kebab-case identifiers
+
+        keep_indentation (:obj:`bool`, `optional`, defaults to :obj:`False`):
+            Whether to keep each run of spaces and tabs at the start of a line as a pre-token
+    """
+
+    def __init__(self, split_identifiers=True, keep_indentation=False):
+        pass
+    def pre_tokenize(self, pretok):
+        """
+        Pre-tokenize a :class:`~tokenizers.PyPreTokenizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.PreTokenizedString` to
+        keep track of the pre-tokenization, and leverage the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you just want to see the result of
+        the pre-tokenization of a raw string, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize_str`
+
+        Args:
+            pretok (:class:`~tokenizers.PreTokenizedString):
+                The pre-tokenized string on which to apply this
+                :class:`~tokenizers.pre_tokenizers.PreTokenizer`
+        """
+        pass
+    def pre_tokenize_str(self, sequence):
+        """
+        Pre tokenize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.pre_tokenizers.PreTokenizer` but it does not keep track of the
+        alignment, nor does it provide all the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you need some of these, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to pre-tokeize
+
+        Returns:
+            :obj:`List[Tuple[str, Offsets]]`:
+                A list of tuple with the pre-tokenized parts and their offsets
+        """
+        pass
+
+class DictionarySplit(PreTokenizer):
+    """
+    This pre-tokenizer segments the runs of a script written without spaces into the words of
+    a dictionary
+
+    The chars that are not part of any word of the dictionary are split on their own, and the
+    text of the other scripts is left as is. The dictionary is saved along with the
+    pre-tokenizer.
+
+    Args:
+        words (:obj:`Dict[str, int]`):
+            The frequency of each word of the dictionary
+
+        script (:obj:`str`, `optional`, defaults to :obj:`han`):
+            The script to segment. Either :obj:`han`, for the Chinese and Japanese text, along
+            with Hiragana and Katakana, or :obj:`thai`.
+
+        segmentation (:obj:`str`, `optional`, defaults to :obj:`max_probability`):
+            How to choose between the possible segmentations. Either :obj:`max_probability`,
+            for the one with the highest probability given the frequencies of its words, or
+            :obj:`max_match`, for the longest word at each position, from left to right.
+    """
+
+    def __init__(self, words, script="han", segmentation="max_probability"):
+        pass
+    @staticmethod
+    def from_file(cls, path, script="han", segmentation="max_probability"):
+        """
+        Instantiate a new :class:`~tokenizers.pre_tokenizers.DictionarySplit` from the given
+        dictionary file
+
+        The file has a word on each line, optionally followed by its frequency after a space
+        or a tab. Anything after the frequency is ignored, and the words without frequency
+        count as seen once.
+
+        Args:
+            path (:obj:`str`):
+                The path to the dictionary file
+
+            script (:obj:`str`, `optional`, defaults to :obj:`han`):
+                The script to segment, either :obj:`han` or :obj:`thai`
+
+            segmentation (:obj:`str`, `optional`, defaults to :obj:`max_probability`):
+                Either :obj:`max_probability` or :obj:`max_match`
+
+        Returns:
+            :class:`~tokenizers.pre_tokenizers.DictionarySplit`: An instance loaded from this file
+        """
+        pass
+    def pre_tokenize(self, pretok):
+        """
+        Pre-tokenize a :class:`~tokenizers.PyPreTokenizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.PreTokenizedString` to
+        keep track of the pre-tokenization, and leverage the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you just want to see the result of
+        the pre-tokenization of a raw string, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize_str`
+
+        Args:
+            pretok (:class:`~tokenizers.PreTokenizedString):
+                The pre-tokenized string on which to apply this
+                :class:`~tokenizers.pre_tokenizers.PreTokenizer`
+        """
+        pass
+    def pre_tokenize_str(self, sequence):
+        """
+        Pre tokenize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.pre_tokenizers.PreTokenizer` but it does not keep track of the
+        alignment, nor does it provide all the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you need some of these, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to pre-tokeize
+
+        Returns:
+            :obj:`List[Tuple[str, Offsets]]`:
+                A list of tuple with the pre-tokenized parts and their offsets
+        """
+        pass
+
 class Digits(PreTokenizer):
     """
     This pre-tokenizer simply splits using the digits in separate tokens
@@ -497,6 +637,58 @@ class UnicodeScripts(PreTokenizer):
         """
         pass
 
+class UnicodeWords(PreTokenizer):
+    """
+    This pre-tokenizer splits on the word boundaries of the Unicode Standard Annex #29
+
+    The words keep their apostrophes and the separators of their numbers, and each
+    punctuation is on its own::
+
+        "I can't pay $3.14!" -> "I", "can't", "pay", "$", "3.14", "!"
+
+    Args:
+        remove_whitespace (:obj:`bool`, `optional`, defaults to :obj:`True`):
+            Whether to remove the segments that only contain whitespace
+    """
+
+    def __init__(self, remove_whitespace=True):
+        pass
+    def pre_tokenize(self, pretok):
+        """
+        Pre-tokenize a :class:`~tokenizers.PyPreTokenizedString` in-place
+
+        This method allows to modify a :class:`~tokenizers.PreTokenizedString` to
+        keep track of the pre-tokenization, and leverage the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you just want to see the result of
+        the pre-tokenization of a raw string, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize_str`
+
+        Args:
+            pretok (:class:`~tokenizers.PreTokenizedString):
+                The pre-tokenized string on which to apply this
+                :class:`~tokenizers.pre_tokenizers.PreTokenizer`
+        """
+        pass
+    def pre_tokenize_str(self, sequence):
+        """
+        Pre tokenize the given string
+
+        This method provides a way to visualize the effect of a
+        :class:`~tokenizers.pre_tokenizers.PreTokenizer` but it does not keep track of the
+        alignment, nor does it provide all the capabilities of the
+        :class:`~tokenizers.PreTokenizedString`. If you need some of these, you can use
+        :meth:`~tokenizers.pre_tokenizers.PreTokenizer.pre_tokenize`
+
+        Args:
+            sequence (:obj:`str`):
+                A string to pre-tokeize
+
+        Returns:
+            :obj:`List[Tuple[str, Offsets]]`:
+                A list of tuple with the pre-tokenized parts and their offsets
+        """
+        pass
+
 class Whitespace(PreTokenizer):
     """
     This pre-tokenizer simply splits using the following regex: `\w+|[^\w\s]+`
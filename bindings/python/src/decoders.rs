@@ -282,8 +282,9 @@ impl Decoder for CustomDecoder {
         Python::with_gil(|py| {
             let decoded = self
                 .inner
-                .call_method(py, "decode", (tokens,), None)?
-                .extract::<String>(py)?;
+                .call_method(py, "decode", (tokens,), None)
+                .and_then(|decoded| decoded.extract::<String>(py))
+                .map_err(tk::Error::other)?;
             Ok(decoded)
         })
     }
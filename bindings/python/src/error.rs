@@ -2,7 +2,7 @@ use pyo3::exceptions;
 use pyo3::prelude::*;
 use pyo3::type_object::PyTypeObject;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use tokenizers::tokenizer::Result;
+use tokenizers::tokenizer::{Error, Result};
 
 #[derive(Debug)]
 pub struct PyError(pub String);
@@ -22,11 +22,39 @@ impl Display for PyError {
 }
 impl std::error::Error for PyError {}
 
+/// Converts a `tokenizers::Error` into the Python exception matching its category: an
+/// `IOError` for the IO errors, a `ValueError` for the invalid values, like a malformed JSON, a
+/// missing token or invalid parameters, and an `Exception` for everything else.
+pub fn into_pyerr(error: Error) -> PyErr {
+    let message = format!("{}", error);
+    new_pyerr(&error, message)
+}
+
+/// Same as `into_pyerr`, with the message prefixed by the given context
+pub fn into_pyerr_with_context(error: Error, context: &str) -> PyErr {
+    let message = format!("{}: {}", context, error);
+    new_pyerr(&error, message)
+}
+
+fn new_pyerr(error: &Error, message: String) -> PyErr {
+    match error {
+        Error::Io(_) => exceptions::PyIOError::new_err(message),
+        Error::Serialization(_)
+        | Error::Regex(_)
+        | Error::Bpe(_)
+        | Error::WordPiece(_)
+        | Error::WordLevel(_)
+        | Error::Unigram(_)
+        | Error::Truncation(_)
+        | Error::Configuration(_) => exceptions::PyValueError::new_err(message),
+        _ => exceptions::PyException::new_err(message),
+    }
+}
+
 pub struct ToPyResult<T>(pub Result<T>);
 impl<T> std::convert::Into<PyResult<T>> for ToPyResult<T> {
     fn into(self) -> PyResult<T> {
-        self.0
-            .map_err(|e| exceptions::PyException::new_err(format!("{}", e)))
+        self.0.map_err(into_pyerr)
     }
 }
 impl<T> ToPyResult<T> {
@@ -75,6 +75,9 @@ fn pre_tokenizers(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<pre_tokenizers::PySequence>()?;
     m.add_class::<pre_tokenizers::PyDigits>()?;
     m.add_class::<pre_tokenizers::PyUnicodeScripts>()?;
+    m.add_class::<pre_tokenizers::PyUnicodeWords>()?;
+    m.add_class::<pre_tokenizers::PyDictionarySplit>()?;
+    m.add_class::<pre_tokenizers::PyCodeIdentifiers>()?;
     Ok(())
 }
 
@@ -111,11 +114,18 @@ fn normalizers(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<normalizers::PyNFKC>()?;
     m.add_class::<normalizers::PySequence>()?;
     m.add_class::<normalizers::PyLowercase>()?;
+    m.add_class::<normalizers::PyLocaleLowercase>()?;
+    m.add_class::<normalizers::PyCaseFold>()?;
+    m.add_class::<normalizers::PyNfkcCasefold>()?;
     m.add_class::<normalizers::PyStrip>()?;
     m.add_class::<normalizers::PyStripAccents>()?;
     m.add_class::<normalizers::PyNmt>()?;
+    m.add_class::<normalizers::PyTransliterate>()?;
+    m.add_class::<normalizers::PyHtmlStrip>()?;
     m.add_class::<normalizers::PyPrecompiled>()?;
     m.add_class::<normalizers::PyReplace>()?;
+    m.add_class::<normalizers::PyReplaceMap>()?;
+    m.add_class::<normalizers::PyCleanWhitespace>()?;
     Ok(())
 }
 
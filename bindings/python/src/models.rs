@@ -16,7 +16,7 @@ use tk::models::ModelWrapper;
 use tk::{Model, Token};
 use tokenizers as tk;
 
-use super::error::{deprecation_warning, ToPyResult};
+use super::error::{deprecation_warning, into_pyerr, into_pyerr_with_context, ToPyResult};
 
 /// Base class for all models
 ///
@@ -477,9 +477,8 @@ impl PyBPE {
         merges: &str,
         kwargs: Option<&PyDict>,
     ) -> PyResult<Py<Self>> {
-        let (vocab, merges) = BPE::read_file(vocab, merges).map_err(|e| {
-            exceptions::PyException::new_err(format!("Error while reading BPE files: {}", e))
-        })?;
+        let (vocab, merges) = BPE::read_file(vocab, merges)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading BPE files"))?;
         Py::new(
             py,
             PyBPE::new(
@@ -615,9 +614,8 @@ impl PyWordPiece {
     #[staticmethod]
     #[text_signature = "(vocab)"]
     fn read_file(vocab: &str) -> PyResult<Vocab> {
-        WordPiece::read_file(vocab).map_err(|e| {
-            exceptions::PyException::new_err(format!("Error while reading WordPiece file: {}", e))
-        })
+        WordPiece::read_file(vocab)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading WordPiece file"))
     }
 
     /// Instantiate a WordPiece model from the given file
@@ -646,9 +644,8 @@ impl PyWordPiece {
         vocab: &str,
         kwargs: Option<&PyDict>,
     ) -> PyResult<Py<Self>> {
-        let vocab = WordPiece::read_file(vocab).map_err(|e| {
-            exceptions::PyException::new_err(format!("Error while reading WordPiece file: {}", e))
-        })?;
+        let vocab = WordPiece::read_file(vocab)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading WordPiece file"))?;
         Py::new(py, PyWordPiece::new(Some(PyVocab::Vocab(vocab)), kwargs)?)
     }
 }
@@ -703,13 +700,7 @@ impl PyWordLevel {
             builder = builder.unk_token(unk_token);
         }
 
-        Ok((
-            PyWordLevel {},
-            builder
-                .build()
-                .map_err(|e| exceptions::PyException::new_err(e.to_string()))?
-                .into(),
-        ))
+        Ok((PyWordLevel {}, builder.build().map_err(into_pyerr)?.into()))
     }
 
     /// Read a :obj:`vocab.json`
@@ -727,9 +718,8 @@ impl PyWordLevel {
     #[staticmethod]
     #[text_signature = "(vocab)"]
     fn read_file(vocab: &str) -> PyResult<Vocab> {
-        WordLevel::read_file(vocab).map_err(|e| {
-            exceptions::PyException::new_err(format!("Error while reading WordLevel file: {}", e))
-        })
+        WordLevel::read_file(vocab)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading WordLevel file"))
     }
 
     /// Instantiate a WordLevel model from the given file
@@ -757,9 +747,8 @@ impl PyWordLevel {
         vocab: &str,
         unk_token: Option<String>,
     ) -> PyResult<Py<Self>> {
-        let vocab = WordLevel::read_file(vocab).map_err(|e| {
-            exceptions::PyException::new_err(format!("Error while reading WordLevel file: {}", e))
-        })?;
+        let vocab = WordLevel::read_file(vocab)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading WordLevel file"))?;
         Py::new(
             py,
             PyWordLevel::new(Some(PyVocab::Vocab(vocab)), unk_token)?,
@@ -782,9 +771,8 @@ impl PyUnigram {
     fn new(vocab: Option<Vec<(String, f64)>>, unk_id: Option<usize>) -> PyResult<(Self, PyModel)> {
         match (vocab, unk_id) {
             (Some(vocab), unk_id) => {
-                let model = Unigram::from(vocab, unk_id).map_err(|e| {
-                    exceptions::PyException::new_err(format!("Error while loading Unigram: {}", e))
-                })?;
+                let model = Unigram::from(vocab, unk_id)
+                    .map_err(|e| into_pyerr_with_context(e, "Error while loading Unigram"))?;
                 Ok((PyUnigram {}, model.into()))
             }
             (None, None) => Ok((PyUnigram {}, Unigram::default().into())),
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
 use pyo3::exceptions;
@@ -5,12 +6,15 @@ use pyo3::prelude::*;
 use pyo3::types::*;
 use pyo3::PySequenceProtocol;
 
-use crate::error::ToPyResult;
+use crate::error::{into_pyerr, ToPyResult};
 use crate::utils::{PyNormalizedString, PyNormalizedStringRefMut, PyPattern};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tk::normalizers::html::BlockSeparator;
+use tk::normalizers::transliterate::Script;
 use tk::normalizers::{
-    BertNormalizer, Lowercase, Nmt, NormalizerWrapper, Precompiled, Replace, Strip, StripAccents,
+    BertNormalizer, CaseFold, CleanWhitespace, HtmlStrip, LocaleLowercase, Lowercase, NfkcCasefold,
+    Nmt, NormalizerWrapper, Precompiled, Replace, ReplaceMap, Strip, StripAccents, Transliterate,
     NFC, NFD, NFKC, NFKD,
 };
 use tk::{NormalizedString, Normalizer};
@@ -35,7 +39,7 @@ impl PyNormalizedStringMut<'_> {
             PyNormalizedStringMut::Owned(ref mut n) => normalizer.normalize(&mut n.normalized),
             PyNormalizedStringMut::RefMut(n) => n.map_as_mut(|n| normalizer.normalize(n))?,
         }
-        .map_err(|e| exceptions::PyException::new_err(format!("{}", e)))
+        .map_err(into_pyerr)
     }
 }
 
@@ -87,6 +91,27 @@ impl PyNormalizer {
                     }
                     NormalizerWrapper::Replace(_) => Py::new(py, (PyReplace {}, base))?.into_py(py),
                     NormalizerWrapper::Nmt(_) => Py::new(py, (PyNmt {}, base))?.into_py(py),
+                    NormalizerWrapper::CaseFold(_) => {
+                        Py::new(py, (PyCaseFold {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::NfkcCasefold(_) => {
+                        Py::new(py, (PyNfkcCasefold {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::LocaleLowercase(_) => {
+                        Py::new(py, (PyLocaleLowercase {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::Transliterate(_) => {
+                        Py::new(py, (PyTransliterate {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::HtmlStrip(_) => {
+                        Py::new(py, (PyHtmlStrip {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::ReplaceMap(_) => {
+                        Py::new(py, (PyReplaceMap {}, base))?.into_py(py)
+                    }
+                    NormalizerWrapper::CleanWhitespace(_) => {
+                        Py::new(py, (PyCleanWhitespace {}, base))?.into_py(py)
+                    }
                 },
             },
         })
@@ -171,12 +196,12 @@ impl PyNormalizer {
 }
 
 macro_rules! getter {
-    ($self: ident, $variant: ident, $name: ident) => {{
+    ($self: ident, $variant: ident, $($name: tt)+) => {{
         let super_ = $self.as_ref();
         if let PyNormalizerTypeWrapper::Single(ref norm) = super_.normalizer {
             let wrapper = norm.read().unwrap();
-            if let PyNormalizerWrapper::Wrapped(NormalizerWrapper::$variant(o)) = *wrapper {
-                o.$name
+            if let PyNormalizerWrapper::Wrapped(NormalizerWrapper::$variant(ref o)) = *wrapper {
+                o.$($name)+
             } else {
                 unreachable!()
             }
@@ -384,6 +409,70 @@ impl PyLowercase {
     }
 }
 
+/// LocaleLowercase Normalizer
+///
+/// Lowercases like :class:`Lowercase`, but also applies the conditional rules of the
+/// Unicode SpecialCasing for the language of the given locale: the dotted and dotless i
+/// in Turkish and Azerbaijani, the accents in Lithuanian, and the final sigma.
+///
+/// Args:
+///     locale (:obj:`str`):
+///         The locale of the text, like :obj:`"tr"` or :obj:`"lt-LT"`
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=LocaleLowercase)]
+#[text_signature = "(self, locale)"]
+pub struct PyLocaleLowercase {}
+#[pymethods]
+impl PyLocaleLowercase {
+    #[getter]
+    fn get_locale(self_: PyRef<Self>) -> String {
+        getter!(self_, LocaleLowercase, locale.clone())
+    }
+
+    #[setter]
+    fn set_locale(self_: PyRef<Self>, locale: String) {
+        setter!(self_, LocaleLowercase, locale, locale)
+    }
+
+    #[new]
+    fn new(locale: String) -> PyResult<(Self, PyNormalizer)> {
+        Ok((PyLocaleLowercase {}, LocaleLowercase::new(locale).into()))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &[""]))
+    }
+}
+
+/// CaseFold Normalizer
+///
+/// Applies the full Unicode case folding, which is more thorough than :class:`Lowercase`
+/// to compare strings regardless of their case. For example, both "ß" and "ẞ" give "ss".
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=CaseFold)]
+#[text_signature = "(self)"]
+pub struct PyCaseFold {}
+#[pymethods]
+impl PyCaseFold {
+    #[new]
+    fn new() -> PyResult<(Self, PyNormalizer)> {
+        Ok((PyCaseFold {}, CaseFold.into()))
+    }
+}
+
+/// NfkcCasefold Normalizer
+///
+/// Applies the NFKC_Casefold normalization of Unicode: removes the default ignorable
+/// code points, and then applies NFKC, the case folding, and NFKC again.
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=NfkcCasefold)]
+#[text_signature = "(self)"]
+pub struct PyNfkcCasefold {}
+#[pymethods]
+impl PyNfkcCasefold {
+    #[new]
+    fn new() -> PyResult<(Self, PyNormalizer)> {
+        Ok((PyNfkcCasefold {}, NfkcCasefold.into()))
+    }
+}
+
 /// Strip normalizer
 #[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=Strip)]
 #[text_signature = "(self, left=True, right=True)"]
@@ -429,6 +518,227 @@ impl PyStripAccents {
     }
 }
 
+fn script_from_str(script: &str) -> PyResult<Script> {
+    match script {
+        "latin" => Ok(Script::Latin),
+        "greek" => Ok(Script::Greek),
+        "cyrillic" => Ok(Script::Cyrillic),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "Unknown script: {}, expected one of `latin`, `greek`, `cyrillic`",
+            script
+        ))),
+    }
+}
+
+fn script_to_str(script: &Script) -> &'static str {
+    match script {
+        Script::Latin => "latin",
+        Script::Greek => "greek",
+        Script::Cyrillic => "cyrillic",
+    }
+}
+
+/// Transliterate normalizer
+///
+/// Transliterates the characters of the given scripts to their closest ASCII forms,
+/// using built-in tables. The accents are removed, and the characters without a known
+/// transliteration are kept as is.
+///
+/// Args:
+///     scripts (:obj:`List[str]`, `optional`):
+///         The scripts to transliterate, among :obj:`latin`, :obj:`greek` and
+///         :obj:`cyrillic`. All of them by default.
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=Transliterate)]
+#[text_signature = "(self, scripts=None)"]
+pub struct PyTransliterate {}
+#[pymethods]
+impl PyTransliterate {
+    #[getter]
+    fn get_scripts(self_: PyRef<Self>) -> Vec<&'static str> {
+        getter!(
+            self_,
+            Transliterate,
+            scripts.iter().map(script_to_str).collect()
+        )
+    }
+
+    #[setter]
+    fn set_scripts(self_: PyRef<Self>, scripts: Vec<String>) -> PyResult<()> {
+        let scripts = scripts
+            .iter()
+            .map(|s| script_from_str(s))
+            .collect::<PyResult<Vec<_>>>()?;
+        setter!(self_, Transliterate, scripts, scripts);
+        Ok(())
+    }
+
+    #[new]
+    #[args(scripts = "None")]
+    fn new(scripts: Option<Vec<String>>) -> PyResult<(Self, PyNormalizer)> {
+        let transliterate = match scripts {
+            Some(scripts) => Transliterate::new(
+                scripts
+                    .iter()
+                    .map(|s| script_from_str(s))
+                    .collect::<PyResult<Vec<_>>>()?,
+            ),
+            None => Transliterate::default(),
+        };
+        Ok((PyTransliterate {}, transliterate.into()))
+    }
+}
+
+fn block_separator_from_str(separator: &str) -> PyResult<BlockSeparator> {
+    match separator {
+        "none" => Ok(BlockSeparator::None),
+        "space" => Ok(BlockSeparator::Space),
+        "newline" => Ok(BlockSeparator::Newline),
+        _ => Err(exceptions::PyValueError::new_err(format!(
+            "Unknown block_separator: {}, expected one of `none`, `space`, `newline`",
+            separator
+        ))),
+    }
+}
+
+/// HtmlStrip normalizer
+///
+/// Removes the HTML tags and comments, along with the content of the script and style
+/// elements, and decodes the character references like :obj:`&amp;`. The offsets still
+/// point into the raw markup.
+///
+/// Args:
+///     decode_entities (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to decode the character references
+///
+///     block_separator (:obj:`str`, `optional`, defaults to :obj:`space`):
+///         What replaces the tags of the block elements, like :obj:`<p>` or :obj:`<br>`.
+///         Either :obj:`none`, :obj:`space` or :obj:`newline`.
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=HtmlStrip)]
+#[text_signature = "(self, decode_entities=True, block_separator=\"space\")"]
+pub struct PyHtmlStrip {}
+#[pymethods]
+impl PyHtmlStrip {
+    #[getter]
+    fn get_decode_entities(self_: PyRef<Self>) -> bool {
+        getter!(self_, HtmlStrip, decode_entities)
+    }
+
+    #[setter]
+    fn set_decode_entities(self_: PyRef<Self>, decode_entities: bool) {
+        setter!(self_, HtmlStrip, decode_entities, decode_entities);
+    }
+
+    #[getter]
+    fn get_block_separator(self_: PyRef<Self>) -> &'static str {
+        match getter!(self_, HtmlStrip, block_separator) {
+            BlockSeparator::None => "none",
+            BlockSeparator::Space => "space",
+            BlockSeparator::Newline => "newline",
+        }
+    }
+
+    #[setter]
+    fn set_block_separator(self_: PyRef<Self>, block_separator: &str) -> PyResult<()> {
+        let block_separator = block_separator_from_str(block_separator)?;
+        setter!(self_, HtmlStrip, block_separator, block_separator);
+        Ok(())
+    }
+
+    #[new]
+    #[args(decode_entities = "true", block_separator = "\"space\"")]
+    fn new(decode_entities: bool, block_separator: &str) -> PyResult<(Self, PyNormalizer)> {
+        Ok((
+            PyHtmlStrip {},
+            HtmlStrip::new(decode_entities, block_separator_from_str(block_separator)?).into(),
+        ))
+    }
+}
+
+/// CleanWhitespace normalizer
+///
+/// Cleans the whitespace of the input, while keeping the alignments.
+///
+/// Args:
+///     collapse (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to collapse each run of whitespace into its first character
+///
+///     normalize_spaces (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to convert every kind of whitespace, like tabs or no-break spaces,
+///         to a space
+///
+///     remove_control (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to remove the control and format characters, like the zero-width
+///         spaces and joiners, or the bidi controls
+///
+///     keep_newlines (:obj:`bool`, `optional`, defaults to :obj:`False`):
+///         Whether to keep the newlines apart from the other whitespace. They are then
+///         converted to a line feed instead of a space, and a collapsed run that contains
+///         one becomes this newline.
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=CleanWhitespace)]
+#[text_signature = "(self, collapse=True, normalize_spaces=True, remove_control=True, keep_newlines=False)"]
+pub struct PyCleanWhitespace {}
+#[pymethods]
+impl PyCleanWhitespace {
+    #[getter]
+    fn get_collapse(self_: PyRef<Self>) -> bool {
+        getter!(self_, CleanWhitespace, collapse)
+    }
+
+    #[setter]
+    fn set_collapse(self_: PyRef<Self>, collapse: bool) {
+        setter!(self_, CleanWhitespace, collapse, collapse);
+    }
+
+    #[getter]
+    fn get_normalize_spaces(self_: PyRef<Self>) -> bool {
+        getter!(self_, CleanWhitespace, normalize_spaces)
+    }
+
+    #[setter]
+    fn set_normalize_spaces(self_: PyRef<Self>, normalize_spaces: bool) {
+        setter!(self_, CleanWhitespace, normalize_spaces, normalize_spaces);
+    }
+
+    #[getter]
+    fn get_remove_control(self_: PyRef<Self>) -> bool {
+        getter!(self_, CleanWhitespace, remove_control)
+    }
+
+    #[setter]
+    fn set_remove_control(self_: PyRef<Self>, remove_control: bool) {
+        setter!(self_, CleanWhitespace, remove_control, remove_control);
+    }
+
+    #[getter]
+    fn get_keep_newlines(self_: PyRef<Self>) -> bool {
+        getter!(self_, CleanWhitespace, keep_newlines)
+    }
+
+    #[setter]
+    fn set_keep_newlines(self_: PyRef<Self>, keep_newlines: bool) {
+        setter!(self_, CleanWhitespace, keep_newlines, keep_newlines);
+    }
+
+    #[new]
+    #[args(
+        collapse = "true",
+        normalize_spaces = "true",
+        remove_control = "true",
+        keep_newlines = "false"
+    )]
+    fn new(
+        collapse: bool,
+        normalize_spaces: bool,
+        remove_control: bool,
+        keep_newlines: bool,
+    ) -> (Self, PyNormalizer) {
+        (
+            PyCleanWhitespace {},
+            CleanWhitespace::new(collapse, normalize_spaces, remove_control, keep_newlines).into(),
+        )
+    }
+}
+
 /// Nmt normalizer
 #[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=Nmt)]
 #[text_signature = "(self)"]
@@ -480,6 +790,38 @@ impl PyReplace {
     }
 }
 
+/// ReplaceMap normalizer
+///
+/// Replaces the literal patterns of a table of rules with their content, in a single pass.
+/// At each position, the longest pattern that matches is replaced, and the new content is
+/// never matched again.
+///
+/// Args:
+///     rules (:obj:`Dict[str, str]`):
+///         The content to use for each pattern. The patterns can't be empty.
+#[pyclass(extends=PyNormalizer, module = "tokenizers.normalizers", name=ReplaceMap)]
+#[text_signature = "(self, rules)"]
+pub struct PyReplaceMap {}
+#[pymethods]
+impl PyReplaceMap {
+    #[getter]
+    fn get_rules(self_: PyRef<Self>) -> BTreeMap<String, String> {
+        getter!(self_, ReplaceMap, get_rules().clone())
+    }
+
+    #[new]
+    fn new(rules: HashMap<String, String>) -> PyResult<(Self, PyNormalizer)> {
+        Ok((
+            PyReplaceMap {},
+            ToPyResult(ReplaceMap::new(rules)).into_py()?.into(),
+        ))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &[PyDict::new(py)]))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct CustomNormalizer {
     inner: PyObject,
@@ -495,7 +837,9 @@ impl tk::tokenizer::Normalizer for CustomNormalizer {
         Python::with_gil(|py| {
             let normalized = PyNormalizedStringRefMut::new(normalized);
             let py_normalized = self.inner.as_ref(py);
-            py_normalized.call_method("normalize", (normalized.get(),), None)?;
+            py_normalized
+                .call_method("normalize", (normalized.get(),), None)
+                .map_err(tk::Error::other)?;
             Ok(())
         })
     }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 use pyo3::exceptions;
@@ -8,19 +9,22 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use tk::pre_tokenizers::bert::BertPreTokenizer;
 use tk::pre_tokenizers::byte_level::ByteLevel;
+use tk::pre_tokenizers::code_identifiers::CodeIdentifiers;
 use tk::pre_tokenizers::delimiter::CharDelimiterSplit;
+use tk::pre_tokenizers::dictionary::{DictionarySplit, Script, Segmentation};
 use tk::pre_tokenizers::digits::Digits;
 use tk::pre_tokenizers::metaspace::Metaspace;
 use tk::pre_tokenizers::punctuation::Punctuation;
 use tk::pre_tokenizers::split::Split;
 use tk::pre_tokenizers::unicode_scripts::UnicodeScripts;
+use tk::pre_tokenizers::unicode_words::UnicodeWords;
 use tk::pre_tokenizers::whitespace::{Whitespace, WhitespaceSplit};
 use tk::pre_tokenizers::PreTokenizerWrapper;
 use tk::tokenizer::Offsets;
 use tk::{PreTokenizedString, PreTokenizer};
 use tokenizers as tk;
 
-use super::error::ToPyResult;
+use super::error::{into_pyerr_with_context, ToPyResult};
 use super::utils::*;
 
 /// Base class for all pre-tokenizers
@@ -85,6 +89,15 @@ impl PyPreTokenizer {
                         PreTokenizerWrapper::UnicodeScripts(_) => {
                             Py::new(py, (PyUnicodeScripts {}, base))?.into_py(py)
                         }
+                        PreTokenizerWrapper::UnicodeWords(_) => {
+                            Py::new(py, (PyUnicodeWords {}, base))?.into_py(py)
+                        }
+                        PreTokenizerWrapper::DictionarySplit(_) => {
+                            Py::new(py, (PyDictionarySplit {}, base))?.into_py(py)
+                        }
+                        PreTokenizerWrapper::CodeIdentifiers(_) => {
+                            Py::new(py, (PyCodeIdentifiers {}, base))?.into_py(py)
+                        }
                     },
                 }
             }
@@ -521,6 +534,207 @@ impl PyUnicodeScripts {
     }
 }
 
+/// This pre-tokenizer splits on the word boundaries of the Unicode Standard Annex #29
+///
+/// The words keep their apostrophes and the separators of their numbers, and each
+/// punctuation is on its own::
+///
+///     "I can't pay $3.14!" -> "I", "can't", "pay", "$", "3.14", "!"
+///
+/// Args:
+///     remove_whitespace (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to remove the segments that only contain whitespace
+#[pyclass(extends=PyPreTokenizer, module = "tokenizers.pre_tokenizers", name=UnicodeWords)]
+#[text_signature = "(self, remove_whitespace=True)"]
+pub struct PyUnicodeWords {}
+#[pymethods]
+impl PyUnicodeWords {
+    #[getter]
+    fn get_remove_whitespace(self_: PyRef<Self>) -> bool {
+        getter!(self_, UnicodeWords, remove_whitespace)
+    }
+
+    #[setter]
+    fn set_remove_whitespace(self_: PyRef<Self>, remove_whitespace: bool) {
+        setter!(self_, UnicodeWords, remove_whitespace, remove_whitespace);
+    }
+
+    #[new]
+    #[args(remove_whitespace = true)]
+    fn new(remove_whitespace: bool) -> PyResult<(Self, PyPreTokenizer)> {
+        Ok((
+            PyUnicodeWords {},
+            UnicodeWords::new(remove_whitespace).into(),
+        ))
+    }
+}
+
+fn dictionary_options(script: &str, segmentation: &str) -> PyResult<(Script, Segmentation)> {
+    let script = match script {
+        "han" => Script::Han,
+        "thai" => Script::Thai,
+        _ => {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Unknown script: {}, expected one of `han`, `thai`",
+                script
+            )))
+        }
+    };
+    let segmentation = match segmentation {
+        "max_probability" => Segmentation::MaxProbability,
+        "max_match" => Segmentation::MaxMatch,
+        _ => {
+            return Err(exceptions::PyValueError::new_err(format!(
+                "Unknown segmentation: {}, expected one of `max_probability`, `max_match`",
+                segmentation
+            )))
+        }
+    };
+    Ok((script, segmentation))
+}
+
+/// This pre-tokenizer segments the runs of a script written without spaces into the words of
+/// a dictionary
+///
+/// The chars that are not part of any word of the dictionary are split on their own, and the
+/// text of the other scripts is left as is. The dictionary is saved along with the
+/// pre-tokenizer.
+///
+/// Args:
+///     words (:obj:`Dict[str, int]`):
+///         The frequency of each word of the dictionary
+///
+///     script (:obj:`str`, `optional`, defaults to :obj:`han`):
+///         The script to segment. Either :obj:`han`, for the Chinese and Japanese text, along
+///         with Hiragana and Katakana, or :obj:`thai`.
+///
+///     segmentation (:obj:`str`, `optional`, defaults to :obj:`max_probability`):
+///         How to choose between the possible segmentations. Either :obj:`max_probability`,
+///         for the one with the highest probability given the frequencies of its words, or
+///         :obj:`max_match`, for the longest word at each position, from left to right.
+#[pyclass(extends=PyPreTokenizer, module = "tokenizers.pre_tokenizers", name=DictionarySplit)]
+#[text_signature = "(self, words, script=\"han\", segmentation=\"max_probability\")"]
+pub struct PyDictionarySplit {}
+#[pymethods]
+impl PyDictionarySplit {
+    #[getter]
+    fn get_script(self_: PyRef<Self>) -> &'static str {
+        match getter!(self_, DictionarySplit, get_script()) {
+            Script::Han => "han",
+            Script::Thai => "thai",
+        }
+    }
+
+    #[getter]
+    fn get_segmentation(self_: PyRef<Self>) -> &'static str {
+        match getter!(self_, DictionarySplit, get_segmentation()) {
+            Segmentation::MaxProbability => "max_probability",
+            Segmentation::MaxMatch => "max_match",
+        }
+    }
+
+    #[new]
+    #[args(script = "\"han\"", segmentation = "\"max_probability\"")]
+    fn new(
+        words: HashMap<String, u64>,
+        script: &str,
+        segmentation: &str,
+    ) -> PyResult<(Self, PyPreTokenizer)> {
+        let (script, segmentation) = dictionary_options(script, segmentation)?;
+        Ok((
+            PyDictionarySplit {},
+            DictionarySplit::new(words, script, segmentation).into(),
+        ))
+    }
+
+    fn __getnewargs__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyTuple> {
+        Ok(PyTuple::new(py, &[PyDict::new(py)]))
+    }
+
+    /// Instantiate a new :class:`~tokenizers.pre_tokenizers.DictionarySplit` from the given
+    /// dictionary file
+    ///
+    /// The file has a word on each line, optionally followed by its frequency after a space
+    /// or a tab. Anything after the frequency is ignored, and the words without frequency
+    /// count as seen once.
+    ///
+    /// Args:
+    ///     path (:obj:`str`):
+    ///         The path to the dictionary file
+    ///
+    ///     script (:obj:`str`, `optional`, defaults to :obj:`han`):
+    ///         The script to segment, either :obj:`han` or :obj:`thai`
+    ///
+    ///     segmentation (:obj:`str`, `optional`, defaults to :obj:`max_probability`):
+    ///         Either :obj:`max_probability` or :obj:`max_match`
+    ///
+    /// Returns:
+    ///     :class:`~tokenizers.pre_tokenizers.DictionarySplit`: An instance loaded from this file
+    #[classmethod]
+    #[args(script = "\"han\"", segmentation = "\"max_probability\"")]
+    #[text_signature = "(cls, path, script=\"han\", segmentation=\"max_probability\")"]
+    fn from_file(
+        _cls: &PyType,
+        py: Python,
+        path: &str,
+        script: &str,
+        segmentation: &str,
+    ) -> PyResult<Py<Self>> {
+        let (script, segmentation) = dictionary_options(script, segmentation)?;
+        let pretok = DictionarySplit::from_file(path, script, segmentation)
+            .map_err(|e| into_pyerr_with_context(e, "Error while reading dictionary file"))?;
+        Py::new(py, (PyDictionarySplit {}, pretok.into()))
+    }
+}
+
+/// This pre-tokenizer splits source code for code search
+///
+/// The identifiers are split on their case changes, underscores and digits, the operators
+/// like ``->``, ``::`` or ``!=`` are kept together, and every other punctuation is on its own::
+///
+///     "getHTTP(max_len) -> x" -> "get", "HTTP", "(", "max", "_", "len", ")", "->", "x"
+///
+/// Args:
+///     split_identifiers (:obj:`bool`, `optional`, defaults to :obj:`True`):
+///         Whether to split the camelCase, PascalCase, snake_case and kebab-case identifiers
+///
+///     keep_indentation (:obj:`bool`, `optional`, defaults to :obj:`False`):
+///         Whether to keep each run of spaces and tabs at the start of a line as a pre-token
+#[pyclass(extends=PyPreTokenizer, module = "tokenizers.pre_tokenizers", name=CodeIdentifiers)]
+#[text_signature = "(self, split_identifiers=True, keep_indentation=False)"]
+pub struct PyCodeIdentifiers {}
+#[pymethods]
+impl PyCodeIdentifiers {
+    #[getter]
+    fn get_split_identifiers(self_: PyRef<Self>) -> bool {
+        getter!(self_, CodeIdentifiers, split_identifiers)
+    }
+
+    #[setter]
+    fn set_split_identifiers(self_: PyRef<Self>, split_identifiers: bool) {
+        setter!(self_, CodeIdentifiers, split_identifiers, split_identifiers);
+    }
+
+    #[getter]
+    fn get_keep_indentation(self_: PyRef<Self>) -> bool {
+        getter!(self_, CodeIdentifiers, keep_indentation)
+    }
+
+    #[setter]
+    fn set_keep_indentation(self_: PyRef<Self>, keep_indentation: bool) {
+        setter!(self_, CodeIdentifiers, keep_indentation, keep_indentation);
+    }
+
+    #[new]
+    #[args(split_identifiers = true, keep_indentation = false)]
+    fn new(split_identifiers: bool, keep_indentation: bool) -> PyResult<(Self, PyPreTokenizer)> {
+        Ok((
+            PyCodeIdentifiers {},
+            CodeIdentifiers::new(split_identifiers, keep_indentation).into(),
+        ))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct CustomPreTokenizer {
     inner: PyObject,
@@ -537,7 +751,9 @@ impl tk::tokenizer::PreTokenizer for CustomPreTokenizer {
         Python::with_gil(|py| {
             let pretok = PyPreTokenizedStringRefMut::new(sentence);
             let py_pretok = self.inner.as_ref(py);
-            py_pretok.call_method("pre_tokenize", (pretok.get(),), None)?;
+            py_pretok
+                .call_method("pre_tokenize", (pretok.get(),), None)
+                .map_err(tk::Error::other)?;
             Ok(())
         })
     }
@@ -10,14 +10,14 @@ use pyo3::PyObjectProtocol;
 use tk::models::bpe::BPE;
 use tk::tokenizer::{
     Model, PaddingDirection, PaddingParams, PaddingStrategy, PostProcessor, TokenizerImpl,
-    TruncationParams, TruncationStrategy,
+    TruncationDirection, TruncationParams, TruncationStrategy,
 };
 use tk::utils::iter::ResultShunt;
 use tokenizers as tk;
 
 use super::decoders::PyDecoder;
 use super::encoding::PyEncoding;
-use super::error::{PyError, ToPyResult};
+use super::error::{into_pyerr, PyError, ToPyResult};
 use super::models::PyModel;
 use super::normalizers::PyNormalizer;
 use super::pre_tokenizers::PyPreTokenizer;
@@ -625,19 +625,47 @@ impl PyTokenizer {
     ///     strategy (:obj:`str`, `optional`, defaults to :obj:`longest_first`):
     ///         The strategy used to truncation. Can be one of ``longest_first``, ``only_first`` or
     ///         ``only_second``.
+    ///
+    ///     direction (:obj:`str`, `optional`, defaults to :obj:`right`):
+    ///         The tokens to keep in each truncated sequence. Can be one of ``right`` to keep its
+    ///         start, ``left`` to keep its end, or ``head_tail`` to keep ``head_length`` tokens
+    ///         at its start and its end for the rest.
+    ///
+    ///     head_length (:obj:`int`, `optional`, defaults to :obj:`0`):
+    ///         The number of tokens kept at the start of each sequence with ``head_tail``
+    ///
+    ///     word_boundaries (:obj:`bool`, `optional`, defaults to :obj:`False`):
+    ///         Whether to cut only between words, in the truncated sequences and their
+    ///         overflowing parts. A word longer than ``max_length`` still gets cut.
+    ///
+    ///     max_first_length (:obj:`int`, `optional`):
+    ///         The maximum length of the first sequence. The rest of it gets dropped, so that it
+    ///         is repeated as is in each overflowing part of a pair.
+    ///
+    ///     max_second_length (:obj:`int`, `optional`):
+    ///         The maximum length of the second sequence, whose overflowing parts use ``stride``.
+    ///         With ``only_second``, it gets the rest of ``max_length`` after the first sequence,
+    ///         up to this length.
     #[args(kwargs = "**")]
-    #[text_signature = "(self, max_length, stride=0, strategy='longest_first')"]
+    #[text_signature = "(self, max_length, stride=0, strategy='longest_first', direction='right', head_length=0, word_boundaries=False, max_first_length=None, max_second_length=None)"]
     fn enable_truncation(&mut self, max_length: usize, kwargs: Option<&PyDict>) -> PyResult<()> {
         let mut params = TruncationParams {
             max_length,
             ..Default::default()
         };
 
+        let mut direction = None;
+        let mut head_length = 0;
         if let Some(kwargs) = kwargs {
             for (key, value) in kwargs {
                 let key: &str = key.extract()?;
                 match key {
                     "stride" => params.stride = value.extract()?,
+                    "direction" => direction = Some(value.extract::<&str>()?),
+                    "head_length" => head_length = value.extract()?,
+                    "word_boundaries" => params.word_boundaries = value.extract()?,
+                    "max_first_length" => params.max_first_length = value.extract()?,
+                    "max_second_length" => params.max_second_length = value.extract()?,
                     "strategy" => {
                         let value: &str = value.extract()?;
                         params.strategy = match value {
@@ -656,6 +684,16 @@ impl PyTokenizer {
                 }
             }
         }
+        params.direction = match direction {
+            None | Some("right") => Ok(TruncationDirection::Right),
+            Some("left") => Ok(TruncationDirection::Left),
+            Some("head_tail") => Ok(TruncationDirection::HeadTail(head_length)),
+            Some(value) => Err(PyError(format!(
+                "Unknown `direction`: `{}`. Use one of `right`, `left`, or `head_tail`",
+                value
+            ))
+            .into_pyerr::<exceptions::PyValueError>()),
+        }?;
 
         self.tokenizer.with_truncation(Some(params));
 
@@ -683,6 +721,13 @@ impl PyTokenizer {
             dict.set_item("max_length", params.max_length)?;
             dict.set_item("stride", params.stride)?;
             dict.set_item("strategy", params.strategy.as_ref())?;
+            dict.set_item("direction", params.direction.as_ref())?;
+            if let TruncationDirection::HeadTail(head_length) = params.direction {
+                dict.set_item("head_length", head_length)?;
+            }
+            dict.set_item("word_boundaries", params.word_boundaries)?;
+            dict.set_item("max_first_length", params.max_first_length)?;
+            dict.set_item("max_second_length", params.max_second_length)?;
 
             Ok(Some(dict))
         })
@@ -711,10 +756,21 @@ impl PyTokenizer {
     ///     length (:obj:`int`, `optional`):
     ///         If specified, the length at which to pad. If not specified we pad using the size of
     ///         the longest sequence in a batch.
+    ///
+    ///     bucket_lengths (:obj:`List[int]`, `optional`):
+    ///         If specified, and ``length`` is not, we pad to the smallest of these lengths that
+    ///         fits the longest sequence in a batch, or to this longest sequence if none does.
+    ///
+    ///     min_pad_length (:obj:`int`, `optional`):
+    ///         If specified, we pad to at least this length.
+    ///
+    ///     max_pad_length (:obj:`int`, `optional`):
+    ///         If specified, we never pad beyond this length. The longer sequences are left as is.
     #[args(kwargs = "**")]
-    #[text_signature = "(self, direction='right', pad_id=0, pad_type_id=0, pad_token='[PAD]', length=None, pad_to_multiple_of=None)"]
+    #[text_signature = "(self, direction='right', pad_id=0, pad_type_id=0, pad_token='[PAD]', length=None, pad_to_multiple_of=None, bucket_lengths=None, min_pad_length=None, max_pad_length=None)"]
     fn enable_padding(&mut self, kwargs: Option<&PyDict>) -> PyResult<()> {
         let mut params = PaddingParams::default();
+        let mut bucket_lengths = None;
 
         if let Some(kwargs) = kwargs {
             for (key, value) in kwargs {
@@ -759,10 +815,16 @@ impl PyTokenizer {
                             params.strategy = PaddingStrategy::BatchLongest;
                         }
                     }
+                    "bucket_lengths" => bucket_lengths = value.extract()?,
+                    "min_pad_length" => params.min_pad_length = value.extract()?,
+                    "max_pad_length" => params.max_pad_length = value.extract()?,
                     _ => println!("Ignored unknown kwarg option {}", key),
                 }
             }
         }
+        if let (PaddingStrategy::BatchLongest, Some(lengths)) = (&params.strategy, bucket_lengths) {
+            params.strategy = PaddingStrategy::Bucketed(lengths);
+        }
 
         self.tokenizer.with_padding(Some(params));
 
@@ -790,10 +852,15 @@ impl PyTokenizer {
             dict.set_item(
                 "length",
                 match params.strategy {
-                    tk::PaddingStrategy::BatchLongest => None,
                     tk::PaddingStrategy::Fixed(size) => Some(size),
+                    _ => None,
                 },
             )?;
+            if let tk::PaddingStrategy::Bucketed(lengths) = &params.strategy {
+                dict.set_item("bucket_lengths", lengths)?;
+            }
+            dict.set_item("min_pad_length", params.min_pad_length)?;
+            dict.set_item("max_pad_length", params.max_pad_length)?;
             dict.set_item("pad_to_multiple_of", params.pad_to_multiple_of)?;
             dict.set_item("pad_id", params.pad_id)?;
             dict.set_item("pad_token", &params.pad_token)?;
@@ -1159,7 +1226,7 @@ impl PyTokenizer {
                 self.tokenizer
                     .train(&mut trainer, MaybeSizedIterator::new(iter, length))
                     .map(|_| {})
-                    .map_err(|e| exceptions::PyException::new_err(e.to_string()))
+                    .map_err(into_pyerr)
             })?
         })
     }
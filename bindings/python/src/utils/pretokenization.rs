@@ -20,11 +20,11 @@ fn split(pretok: &mut PreTokenizedString, func: &PyAny) -> PyResult<()> {
         ))
     } else {
         ToPyResult(pretok.split(|i, normalized| {
-            let output = func.call((i, PyNormalizedString::from(normalized)), None)?;
-            Ok(output
-                .extract::<Vec<PyNormalizedString>>()?
-                .into_iter()
-                .map(tk::NormalizedString::from))
+            let output = func
+                .call((i, PyNormalizedString::from(normalized)), None)
+                .and_then(|output| output.extract::<Vec<PyNormalizedString>>())
+                .map_err(tk::Error::other)?;
+            Ok(output.into_iter().map(tk::NormalizedString::from))
         }))
         .into()
     }
@@ -39,7 +39,7 @@ fn normalize(pretok: &mut PreTokenizedString, func: &PyAny) -> PyResult<()> {
     } else {
         ToPyResult(pretok.normalize(|normalized| {
             let norm = PyNormalizedStringRefMut::new(normalized);
-            func.call((norm.get(),), None)?;
+            func.call((norm.get(),), None).map_err(tk::Error::other)?;
             Ok(())
         }))
         .into()
@@ -54,12 +54,18 @@ fn tokenize(pretok: &mut PreTokenizedString, func: &PyAny) -> PyResult<()> {
         ))
     } else {
         ToPyResult(pretok.tokenize(|normalized| {
-            let output = func.call((normalized.get(),), None)?;
+            let output = func
+                .call((normalized.get(),), None)
+                .map_err(tk::Error::other)?;
             Ok(output
-                .extract::<&PyList>()?
-                .into_iter()
-                .map(|obj| Ok(Token::from(obj.extract::<PyToken>()?)))
-                .collect::<PyResult<Vec<_>>>()?)
+                .extract::<&PyList>()
+                .and_then(|tokens| {
+                    tokens
+                        .into_iter()
+                        .map(|obj| Ok(Token::from(obj.extract::<PyToken>()?)))
+                        .collect::<PyResult<Vec<_>>>()
+                })
+                .map_err(tk::Error::other)?)
         }))
         .into()
     }
@@ -3,7 +3,20 @@ import pytest
 
 from tokenizers import Tokenizer, NormalizedString
 from tokenizers.models import BPE
-from tokenizers.normalizers import Normalizer, BertNormalizer, Sequence, Lowercase, Strip
+from tokenizers.normalizers import (
+    Normalizer,
+    BertNormalizer,
+    Sequence,
+    Lowercase,
+    LocaleLowercase,
+    CaseFold,
+    NfkcCasefold,
+    Strip,
+    Transliterate,
+    HtmlStrip,
+    ReplaceMap,
+    CleanWhitespace,
+)
 
 
 class TestBertNormalizer:
@@ -91,6 +104,128 @@ class TestLowercase:
         assert output == "hello"
 
 
+class TestLocaleLowercase:
+    def test_instantiate(self):
+        assert isinstance(LocaleLowercase("tr"), Normalizer)
+        assert isinstance(LocaleLowercase("tr"), LocaleLowercase)
+        assert isinstance(pickle.loads(pickle.dumps(LocaleLowercase("tr"))), LocaleLowercase)
+
+    def test_lowercase(self):
+        assert LocaleLowercase("tr").normalize_str("KIZ İSTANBUL") == "kız istanbul"
+        assert LocaleLowercase("en").normalize_str("KIZ ΟΔΥΣΣΕΥΣ") == "kiz οδυσσευς"
+
+    def test_can_modify(self):
+        normalizer = LocaleLowercase("tr")
+        assert normalizer.locale == "tr"
+        normalizer.locale = "lt"
+        assert normalizer.locale == "lt"
+
+
+class TestCaseFold:
+    def test_instantiate(self):
+        assert isinstance(CaseFold(), Normalizer)
+        assert isinstance(CaseFold(), CaseFold)
+        assert isinstance(pickle.loads(pickle.dumps(CaseFold())), CaseFold)
+        assert isinstance(NfkcCasefold(), Normalizer)
+        assert isinstance(NfkcCasefold(), NfkcCasefold)
+        assert isinstance(pickle.loads(pickle.dumps(NfkcCasefold())), NfkcCasefold)
+
+    def test_casefold(self):
+        assert CaseFold().normalize_str("Straße ẞ ΣΑΣ ①") == "strasse ss σασ ①"
+        assert NfkcCasefold().normalize_str("Straße ẞ ΣΑΣ ①\u200b") == "strasse ss σασ 1"
+
+
+class TestTransliterate:
+    def test_instantiate(self):
+        assert isinstance(Transliterate(), Normalizer)
+        assert isinstance(Transliterate(), Transliterate)
+        assert isinstance(pickle.loads(pickle.dumps(Transliterate())), Transliterate)
+
+    def test_transliterate(self):
+        assert Transliterate().normalize_str("Жуков Ἀθῆναι Straße") == "Zhukov Athinai Strasse"
+        normalizer = Transliterate(["cyrillic"])
+        assert normalizer.normalize_str("Жуков Straße") == "Zhukov Straße"
+
+    def test_can_modify(self):
+        normalizer = Transliterate(["greek"])
+        assert normalizer.scripts == ["greek"]
+        normalizer.scripts = ["latin", "cyrillic"]
+        assert normalizer.scripts == ["latin", "cyrillic"]
+        with pytest.raises(ValueError):
+            Transliterate(["arabic"])
+
+
+class TestHtmlStrip:
+    def test_instantiate(self):
+        assert isinstance(HtmlStrip(), Normalizer)
+        assert isinstance(HtmlStrip(), HtmlStrip)
+        assert isinstance(pickle.loads(pickle.dumps(HtmlStrip())), HtmlStrip)
+
+    def test_strip(self):
+        normalizer = HtmlStrip(block_separator="newline")
+        assert normalizer.normalize_str("<p>Fish &amp; <b>chips</b></p>") == "\nFish & chips\n"
+
+        normalized = NormalizedString("<i>AT&amp;T</i>")
+        HtmlStrip().normalize(normalized)
+        assert normalized.normalized == "AT&T"
+
+    def test_can_modify(self):
+        normalizer = HtmlStrip(decode_entities=False)
+        assert normalizer.decode_entities == False
+        assert normalizer.block_separator == "space"
+        normalizer.decode_entities = True
+        normalizer.block_separator = "none"
+        assert normalizer.decode_entities == True
+        assert normalizer.block_separator == "none"
+
+
+class TestReplaceMap:
+    def test_instantiate(self):
+        assert isinstance(ReplaceMap({"a": "b"}), Normalizer)
+        assert isinstance(ReplaceMap({"a": "b"}), ReplaceMap)
+        assert isinstance(pickle.loads(pickle.dumps(ReplaceMap({"a": "b"}))), ReplaceMap)
+        with pytest.raises(Exception):
+            ReplaceMap({"": "b"})
+
+    def test_replace(self):
+        normalizer = ReplaceMap({":)": "🙂", ":))": "😀", "a": "b", "b": "c"})
+        assert normalizer.normalize_str("ab :)) :)") == "bc 😀 🙂"
+        assert normalizer.rules == {":)": "🙂", ":))": "😀", "a": "b", "b": "c"}
+
+        pickled = pickle.loads(pickle.dumps(normalizer))
+        assert pickled.normalize_str("ab") == "bc"
+
+
+class TestCleanWhitespace:
+    def test_instantiate(self):
+        assert isinstance(CleanWhitespace(), Normalizer)
+        assert isinstance(CleanWhitespace(), CleanWhitespace)
+        assert isinstance(pickle.loads(pickle.dumps(CleanWhitespace())), CleanWhitespace)
+
+    def test_clean(self):
+        normalizer = CleanWhitespace()
+        assert normalizer.normalize_str("Hello\u00a0\t\u200b world\r\n!") == "Hello world !"
+
+        normalizer = CleanWhitespace(keep_newlines=True)
+        assert normalizer.normalize_str("Hello\u00a0\t\u200b world\r\n!") == "Hello world\n!"
+
+    def test_can_modify(self):
+        normalizer = CleanWhitespace(collapse=False)
+        assert normalizer.collapse == False
+        assert normalizer.normalize_spaces == True
+        assert normalizer.remove_control == True
+        assert normalizer.keep_newlines == False
+
+        normalizer.collapse = True
+        normalizer.normalize_spaces = False
+        normalizer.remove_control = False
+        normalizer.keep_newlines = True
+        assert normalizer.collapse == True
+        assert normalizer.normalize_spaces == False
+        assert normalizer.remove_control == False
+        assert normalizer.keep_newlines == True
+
+
 class TestStrip:
     def test_instantiate(self):
         assert isinstance(Strip(), Normalizer)
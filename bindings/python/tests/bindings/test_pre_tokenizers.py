@@ -14,6 +14,9 @@ from tokenizers.pre_tokenizers import (
     Sequence,
     Digits,
     UnicodeScripts,
+    UnicodeWords,
+    DictionarySplit,
+    CodeIdentifiers,
     Split,
 )
 
@@ -191,6 +194,102 @@ class TestUnicodeScripts:
         assert isinstance(pickle.loads(pickle.dumps(UnicodeScripts())), UnicodeScripts)
 
 
+class TestUnicodeWords:
+    def test_instantiate(self):
+        assert UnicodeWords() is not None
+        assert isinstance(UnicodeWords(), PreTokenizer)
+        assert isinstance(UnicodeWords(), UnicodeWords)
+        assert isinstance(UnicodeWords(False), UnicodeWords)
+        assert isinstance(pickle.loads(pickle.dumps(UnicodeWords())), UnicodeWords)
+
+    def test_pre_tokenize(self):
+        pretok = UnicodeWords()
+        assert pretok.pre_tokenize_str("I can't pay $3.14!") == [
+            ("I", (0, 1)),
+            ("can't", (2, 7)),
+            ("pay", (8, 11)),
+            ("$", (12, 13)),
+            ("3.14", (13, 17)),
+            ("!", (17, 18)),
+        ]
+
+    def test_can_modify(self):
+        pretok = UnicodeWords(remove_whitespace=True)
+        assert pretok.remove_whitespace == True
+
+        # Modify these
+        pretok.remove_whitespace = False
+        assert pretok.remove_whitespace == False
+        assert pretok.pre_tokenize_str("a b") == [("a", (0, 1)), (" ", (1, 2)), ("b", (2, 3))]
+
+
+class TestDictionarySplit:
+    def test_instantiate(self):
+        assert DictionarySplit({}) is not None
+        assert isinstance(DictionarySplit({"研究": 1}), PreTokenizer)
+        assert isinstance(DictionarySplit({"研究": 1}), DictionarySplit)
+        assert isinstance(pickle.loads(pickle.dumps(DictionarySplit({}))), DictionarySplit)
+        with pytest.raises(ValueError):
+            DictionarySplit({}, script="latin")
+
+    def test_pre_tokenize(self):
+        words = {"研究": 100, "研究生": 20, "生命": 80}
+        pretok = DictionarySplit(words)
+        assert pretok.script == "han"
+        assert pretok.segmentation == "max_probability"
+        assert pretok.pre_tokenize_str("研究生命 ok") == [
+            ("研究", (0, 2)),
+            ("生命", (2, 4)),
+            (" ok", (4, 7)),
+        ]
+
+        pretok = DictionarySplit(words, segmentation="max_match")
+        assert pretok.pre_tokenize_str("研究生命") == [("研究生", (0, 3)), ("命", (3, 4))]
+
+        pretok = pickle.loads(pickle.dumps(DictionarySplit(words)))
+        assert pretok.pre_tokenize_str("研究生命") == [("研究", (0, 2)), ("生命", (2, 4))]
+
+    def test_from_file(self, tmp_path):
+        path = tmp_path / "words.txt"
+        path.write_text("สวัสดี 10\nครับ 5\n", encoding="utf-8")
+        pretok = DictionarySplit.from_file(str(path), script="thai")
+        assert pretok.pre_tokenize_str("สวัสดีครับ") == [("สวัสดี", (0, 6)), ("ครับ", (6, 10))]
+
+
+class TestCodeIdentifiers:
+    def test_instantiate(self):
+        assert CodeIdentifiers() is not None
+        assert CodeIdentifiers(split_identifiers=False, keep_indentation=True) is not None
+        assert isinstance(CodeIdentifiers(), PreTokenizer)
+        assert isinstance(CodeIdentifiers(), CodeIdentifiers)
+        assert isinstance(pickle.loads(pickle.dumps(CodeIdentifiers())), CodeIdentifiers)
+
+    def test_can_modify(self):
+        pretok = CodeIdentifiers()
+        assert pretok.split_identifiers == True
+        assert pretok.keep_indentation == False
+
+        pretok.split_identifiers = False
+        assert pretok.split_identifiers == False
+        pretok.keep_indentation = True
+        assert pretok.keep_indentation == True
+
+    def test_pre_tokenize(self):
+        pretok = CodeIdentifiers(keep_indentation=True)
+        assert pretok.pre_tokenize_str("if été:\n  getX() -> 1") == [
+            ("if", (0, 2)),
+            ("été", (3, 6)),
+            (":", (6, 7)),
+            ("  ", (8, 10)),
+            ("get", (10, 13)),
+            ("X", (13, 14)),
+            ("(", (14, 15)),
+            (")", (15, 16)),
+            ("->", (17, 19)),
+            ("1", (20, 21)),
+        ]
+
+
 class TestCustomPreTokenizer:
     class BadCustomPretok:
         def pre_tokenize(self, pretok, wrong):
@@ -11,7 +11,7 @@ from ..utils import (
 from tokenizers import AddedToken, Tokenizer, Encoding
 from tokenizers.models import Model, BPE, WordPiece
 from tokenizers.pre_tokenizers import ByteLevel
-from tokenizers.processors import RobertaProcessing, BertProcessing
+from tokenizers.processors import RobertaProcessing, BertProcessing, TemplateProcessing
 from tokenizers.normalizers import Lowercase
 from tokenizers.implementations import BertWordPieceTokenizer
 
@@ -307,6 +307,24 @@ class TestTokenizer:
         output = tokenizer.encode("my name is john", "pair")
         assert output.tokens == ["my", "pair"]
 
+        # Can keep the end, or both the start and the end
+        tokenizer.enable_truncation(2, direction="left")
+        output = tokenizer.encode("my name is john")
+        assert output.tokens == ["is", "john"]
+        tokenizer.enable_truncation(3, direction="head_tail", head_length=1)
+        output = tokenizer.encode("my name is john")
+        assert output.tokens == ["my", "is", "john"]
+        assert tokenizer.truncation["head_length"] == 1
+
+        # Can cap the first sequence, repeated in each overflowing part
+        tokenizer.enable_truncation(4, strategy="only_second", max_first_length=1)
+        output = tokenizer.encode("my name", "is john pair")
+        assert output.tokens == ["my", "is", "john", "pair"]
+        tokenizer.enable_truncation(3, strategy="only_second", max_first_length=1)
+        output = tokenizer.encode("my name", "is john pair")
+        assert output.tokens == ["my", "is", "john"]
+        assert [o.tokens for o in output.overflowing] == [["my", "pair"]]
+
         # Can get the params and give them to enable_truncation
         trunc = tokenizer.truncation
         tokenizer.enable_truncation(**trunc)
@@ -331,6 +349,13 @@ class TestTokenizer:
         output = tokenizer.encode("my name", "pair")
         assert output.tokens == ["my", "name", "pair", "[PAD]"]
 
+        # Can pad to the smallest bucket length that fits
+        tokenizer.enable_padding(bucket_lengths=[3, 6])
+        output = tokenizer.encode("my name")
+        assert output.tokens == ["my", "name", "[PAD]"]
+        output = tokenizer.encode_batch(["my name", "my name is john"])
+        assert all([len(encoding) == 6 for encoding in output])
+
         # Can get the params and give them to enable_padding
         padding = tokenizer.padding
         tokenizer.enable_padding(**padding)
@@ -392,3 +417,14 @@ class TestTokenizer:
         tokenizer = Tokenizer(BPE())
         multiprocessing_with_parallelism(tokenizer, False)
         multiprocessing_with_parallelism(tokenizer, True)
+
+    def test_error_types(self, tmp_path):
+        with pytest.raises(IOError):
+            Tokenizer.from_file(str(tmp_path / "missing.json"))
+        with pytest.raises(ValueError):
+            Tokenizer.from_str("{")
+        with pytest.raises(ValueError):
+            TemplateProcessing(
+                single="[X] $0",
+                special_tokens=[{"id": "[X]", "ids": [1, 2], "tokens": ["[X]"]}],
+            )
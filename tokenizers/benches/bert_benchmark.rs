@@ -15,14 +15,14 @@ use tokenizers::{
     pre_tokenizers::bert::BertPreTokenizer, processors::bert::BertProcessing, EncodeInput, Model,
     TokenizerImpl,
 };
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 use tokenizers::{
     decoders::DecoderWrapper, models::wordpiece::WordPieceTrainerBuilder,
     normalizers::NormalizerWrapper, pre_tokenizers::whitespace::Whitespace,
     processors::PostProcessorWrapper,
 };
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 use common::iter_bench_train;
 use common::{iter_bench_encode, iter_bench_encode_batch};
 
@@ -76,7 +76,7 @@ pub fn bench_bert(c: &mut Criterion) {
     });
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 fn bench_train(c: &mut Criterion) {
     let mut trainer = WordPieceTrainerBuilder::default()
         .show_progress(false)
@@ -121,14 +121,14 @@ criterion_group! {
     targets = bench_bert
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 criterion_group! {
     name = benches_train;
     config = Criterion::default().sample_size(10);
     targets = bench_train
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 criterion_main!(bert_benches, benches_train);
-#[cfg(feature = "bert")]
+#[cfg(not(feature = "training"))]
 criterion_main!(bert_benches);
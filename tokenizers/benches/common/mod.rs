@@ -2,7 +2,7 @@ use std::time::{Duration, Instant};
 
 use criterion::black_box;
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 use tokenizers::Trainer;
 use tokenizers::{
     Decoder, EncodeInput, Model, Normalizer, PostProcessor, PreTokenizer, TokenizerImpl,
@@ -60,7 +60,7 @@ where
     duration
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 pub fn iter_bench_train<T, M, N, PT, PP, D>(
     iters: u64,
     tokenizer: &mut TokenizerImpl<M, N, PT, PP, D>,
@@ -68,7 +68,7 @@ pub fn iter_bench_train<T, M, N, PT, PP, D>(
     files: Vec<String>,
 ) -> Duration
 where
-    T: Trainer<Model = M> + Sync,
+    T: Trainer<Model = M> + Send + Sync,
     M: Model + Send + Sync,
     N: Normalizer + Send + Sync,
     PT: PreTokenizer + Send + Sync,
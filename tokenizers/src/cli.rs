@@ -6,7 +6,7 @@ use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
 use std::io::{self, BufRead, Write};
 use tokenizers::models::bpe::BPE;
 use tokenizers::pre_tokenizers::byte_level::ByteLevel;
-use tokenizers::tokenizer::{AddedToken, Result};
+use tokenizers::tokenizer::{AddedToken, Explanation, Result, ShardsParams, SplitTrace};
 use tokenizers::Tokenizer;
 
 fn shell(matches: &ArgMatches) -> Result<()> {
@@ -54,6 +54,125 @@ fn shell(matches: &ArgMatches) -> Result<()> {
     }
 }
 
+fn print_splits(title: &str, splits: &[SplitTrace]) {
+    println!("  {}:", title);
+    for split in splits {
+        let tokens = split
+            .tokens
+            .iter()
+            .map(|token| format!("{}({}) {:?}", token.value, token.id, token.offsets))
+            .collect::<Vec<_>>();
+        println!(
+            "    {:?}\t{:?}{}\t{}",
+            split.value,
+            split.offsets,
+            if split.is_added_token { " [added]" } else { "" },
+            tokens.join(" ")
+        );
+    }
+}
+
+fn print_explanation(explanation: &Explanation) {
+    for sequence in &explanation.sequences {
+        match sequence.word_idx {
+            Some(word) => println!(
+                "Sequence {} (word {}): {:?}",
+                sequence.type_id, word, sequence.original
+            ),
+            None => println!("Sequence {}: {:?}", sequence.type_id, sequence.original),
+        }
+        print_splits("Added tokens", &sequence.added_tokens);
+        println!("  Normalized: {:?}", sequence.normalized.normalized);
+        for (normalized, original) in &sequence.normalized.alignments {
+            println!(
+                "    {:?}\t{:?} <- {:?} {:?}",
+                &sequence.normalized.normalized[normalized.0..normalized.1],
+                normalized,
+                &sequence.original[original.0..original.1],
+                original
+            );
+        }
+        print_splits("Pre-tokens", &sequence.pre_tokens);
+    }
+
+    if let Some(truncation) = &explanation.truncation {
+        println!(
+            "Truncation (max length {}): {:?} -> {:?}, {} overflowing",
+            truncation.max_length,
+            truncation.lengths_before,
+            truncation.lengths_after,
+            truncation.overflowing
+        );
+    }
+    println!("Special tokens: {:?}", explanation.special_tokens);
+    println!("Padding: {}", explanation.padding);
+
+    let encoding = &explanation.encoding;
+    println!("Tokens:\t\t{:?}", encoding.get_tokens());
+    println!("IDs:\t\t{:?}", encoding.get_ids());
+    println!("Type IDs:\t{:?}", encoding.get_type_ids());
+    println!("Offsets:\t{:?}", encoding.get_offsets());
+}
+
+fn explain(matches: &ArgMatches) -> Result<()> {
+    let tokenizer = Tokenizer::from_file(
+        matches
+            .value_of("tokenizer")
+            .expect("Must give a tokenizer.json file"),
+    )?;
+    let sequence = matches.value_of("sequence").expect("Must give a sequence");
+    let add_special_tokens = !matches.is_present("no-special-tokens");
+
+    let explanation = match matches.value_of("pair") {
+        Some(pair) => tokenizer.explain((sequence, pair), add_special_tokens)?,
+        None => tokenizer.explain(sequence, add_special_tokens)?,
+    };
+    print_explanation(&explanation);
+
+    Ok(())
+}
+
+fn encode_corpus(matches: &ArgMatches) -> Result<()> {
+    let tokenizer = Tokenizer::from_file(
+        matches
+            .value_of("tokenizer")
+            .expect("Must give a tokenizer.json file"),
+    )?;
+    let output = matches
+        .value_of("output")
+        .expect("Must give an output directory");
+    let files = matches
+        .values_of("files")
+        .expect("Must give some files")
+        .map(|f| f.to_owned())
+        .collect();
+
+    let mut params = ShardsParams {
+        add_special_tokens: !matches.is_present("no-special-tokens"),
+        ..Default::default()
+    };
+    if let Some(shard_size) = matches.value_of("shard-size") {
+        params.shard_size = shard_size.parse().map_err(tokenizers::Error::other)?;
+    }
+    if let Some(batch_size) = matches.value_of("batch-size") {
+        params.batch_size = batch_size.parse().map_err(tokenizers::Error::other)?;
+    }
+    if let Some(id_type) = matches.value_of("id-type") {
+        params.id_type = id_type.parse()?;
+    }
+
+    let manifest = tokenizer.encode_to_shards(files, output, &params)?;
+    println!(
+        "Wrote {} documents and {} tokens in {} shards to {}",
+        manifest.shards.iter().map(|s| s.documents).sum::<usize>(),
+        manifest.shards.iter().map(|s| s.tokens).sum::<usize>(),
+        manifest.shards.len(),
+        output
+    );
+
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let matches = App::new("tokenizers")
         .version("0.0.1")
@@ -78,10 +197,89 @@ fn main() -> Result<()> {
                         .required(true),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("explain")
+                .about("Show what each step of a tokenizer does to the given input")
+                .arg(
+                    Arg::with_name("tokenizer")
+                        .long("tokenizer")
+                        .value_name("TOKENIZER_FILE")
+                        .help("Path to the tokenizer.json file")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("pair")
+                        .long("pair")
+                        .value_name("PAIR")
+                        .help("An optional pair sequence"),
+                )
+                .arg(
+                    Arg::with_name("no-special-tokens")
+                        .long("no-special-tokens")
+                        .help("Do not add the special tokens"),
+                )
+                .arg(
+                    Arg::with_name("sequence")
+                        .value_name("SEQUENCE")
+                        .help("The sequence to explain")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("encode-corpus")
+                .about("Encode text files into binary shards of token ids, one document per line")
+                .arg(
+                    Arg::with_name("tokenizer")
+                        .long("tokenizer")
+                        .value_name("TOKENIZER_FILE")
+                        .help("Path to the tokenizer.json file")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("DIR")
+                        .help("The directory where to write the shards. Resumes if it has some already")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("shard-size")
+                        .long("shard-size")
+                        .value_name("TOKENS")
+                        .help("The minimum number of tokens in each shard"),
+                )
+                .arg(
+                    Arg::with_name("batch-size")
+                        .long("batch-size")
+                        .value_name("DOCUMENTS")
+                        .help("The number of documents encoded in parallel at once"),
+                )
+                .arg(
+                    Arg::with_name("id-type")
+                        .long("id-type")
+                        .value_name("TYPE")
+                        .possible_values(&["u16", "u32"])
+                        .help("The type used to store each id"),
+                )
+                .arg(
+                    Arg::with_name("no-special-tokens")
+                        .long("no-special-tokens")
+                        .help("Do not add the special tokens"),
+                )
+                .arg(
+                    Arg::with_name("files")
+                        .value_name("FILES")
+                        .help("The text files to encode, in order")
+                        .multiple(true)
+                        .required(true),
+                ),
+        )
         .get_matches();
 
     match matches.subcommand() {
         ("shell", matches) => shell(matches.unwrap()),
+        ("explain", matches) => explain(matches.unwrap()),
+        ("encode-corpus", matches) => encode_corpus(matches.unwrap()),
         (subcommand, _) => panic!("Unknown subcommand {}", subcommand),
     }
 }
@@ -1,50 +1,63 @@
-#[cfg(not(feature = "bert"))]
 pub mod bpe;
 pub mod wordpiece;
 
 // Re-export these as decoders
-#[cfg(not(feature = "bert"))]
-pub use super::pre_tokenizers::{byte_level, metaspace};
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
+pub use super::pre_tokenizers::byte_level;
+pub use super::pre_tokenizers::metaspace;
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
+use crate::pre_tokenizers::byte_level::ByteLevel;
 use crate::{
-    decoders::bpe::BPEDecoder,
-    pre_tokenizers::{byte_level::ByteLevel, metaspace::Metaspace},
+    decoders::{bpe::BPEDecoder, wordpiece::WordPiece},
+    pre_tokenizers::metaspace::Metaspace,
+    Decoder, Result,
 };
-use crate::{decoders::wordpiece::WordPiece, Decoder, Result};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum DecoderWrapper {
-    #[cfg(not(feature = "bert"))]
     BPE(BPEDecoder),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     ByteLevel(ByteLevel),
     WordPiece(WordPiece),
-    #[cfg(not(feature = "bert"))]
     Metaspace(Metaspace),
 }
 
+/// Find the features required by the given decoder `type`, if it has been disabled
+#[allow(clippy::match_single_binding)]
+fn disabled_feature(ty: &str) -> Option<&'static str> {
+    match ty {
+        #[cfg(not(any(feature = "onig", feature = "fancy-regex")))]
+        "ByteLevel" => Some("`onig` or `fancy-regex`"),
+        _ => None,
+    }
+}
+
+impl_serde_wrapper!(DecoderWrapper, disabled_feature, {
+    "BPEDecoder" => BPE,
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "ByteLevel" => ByteLevel,
+    "WordPiece" => WordPiece,
+    "Metaspace" => Metaspace,
+});
+
 impl Decoder for DecoderWrapper {
     fn decode(&self, tokens: Vec<String>) -> Result<String> {
         match self {
-            #[cfg(not(feature = "bert"))]
             DecoderWrapper::BPE(bpe) => bpe.decode(tokens),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             DecoderWrapper::ByteLevel(bl) => bl.decode(tokens),
-            #[cfg(not(feature = "bert"))]
             DecoderWrapper::Metaspace(ms) => ms.decode(tokens),
             DecoderWrapper::WordPiece(wp) => wp.decode(tokens),
         }
     }
 }
 
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(BPEDecoder, DecoderWrapper, BPE);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(ByteLevel, DecoderWrapper, ByteLevel);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Metaspace, DecoderWrapper, Metaspace);
 impl_enum_from!(WordPiece, DecoderWrapper, WordPiece);
@@ -23,12 +23,12 @@
 //! ## Deserialization and tokenization example
 //!
 //! ```no_run
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(feature = "bpe")]
 //! use tokenizers::tokenizer::{Result, Tokenizer, EncodeInput};
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(feature = "bpe")]
 //! use tokenizers::models::bpe::BPE;
 //!
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(feature = "bpe")]
 //! fn main() -> Result<()> {
 //!     let bpe_builder = BPE::from_file("./path/to/vocab.json", "./path/to/merges.txt");
 //!     let bpe = bpe_builder
@@ -43,31 +43,31 @@
 //!
 //!     Ok(())
 //! }
-//! # #[cfg(feature = "bert")] fn main() {}
+//! # #[cfg(not(feature = "bpe"))] fn main() {}
 //! ```
 //!
 //! ## Training and serialization example
 //!
 //! ```no_run
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::decoders::DecoderWrapper;
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::models::bpe::{BpeTrainerBuilder, BPE};
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::normalizers::{strip::Strip, unicode::NFC, utils::Sequence, NormalizerWrapper};
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::pre_tokenizers::byte_level::ByteLevel;
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::pre_tokenizers::PreTokenizerWrapper;
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::processors::PostProcessorWrapper;
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use tokenizers::{AddedToken, Model, Result, TokenizerBuilder};
 //!
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! use std::path::Path;
 //!
-//! # #[cfg(not(feature = "bert"))]
+//! # #[cfg(all(feature = "training", feature = "onig"))]
 //! fn main() -> Result<()> {
 //!     let vocab_size: usize = 100;
 //!
@@ -105,31 +105,69 @@
 //!
 //!     Ok(())
 //! }
-//! # #[cfg(feature = "bert")] fn main() {}
+//! # #[cfg(not(all(feature = "training", feature = "onig")))] fn main() {}
 //! ```
 //!
 //! # Additional information
 //!
 //! - tokenizers is designed to leverage CPU parallelism when possible. The level of parallelism is determined
-//! by the total number of core/threads your CPU provides but this can be tuned by setting the `RAYON_RS_NUM_CPUS`
-//! environment variable. As an example setting `RAYON_RS_NUM_CPUS=4` will allocate a maximum of 4 threads.
-//! **_Please note this behavior may evolve in the future_**
+//!   by the total number of core/threads your CPU provides but this can be tuned by setting the `RAYON_RS_NUM_CPUS`
+//!   environment variable. As an example setting `RAYON_RS_NUM_CPUS=4` will allocate a maximum of 4 threads.
+//!   Each tokenizer can also be given its own settings using
+//!   [`TokenizerImpl::with_parallelism`](tokenizer/struct.TokenizerImpl.html#method.with_parallelism),
+//!   for example to run on a dedicated thread pool, or to disable parallelism altogether.
+//!   **_Please note this behavior may evolve in the future_**
 //!
 //! # Features
+//! All the following features, except **fancy-regex** and **bert**, are enabled by default.
+//! Disabling some of them (with `default-features = false`) removes the matching components from
+//! the crate, and from the `*Wrapper` enums. Trying to deserialize a disabled component returns an
+//! error naming the feature to enable.
+//!
+//! **bpe**, **wordpiece**, **wordlevel**, **unigram**: Each of the available models.
+//!
+//! **onig**: The components relying on the [onig](https://crates.io/crates/onig) regex library:
+//!   `ByteLevel`, `Split`, `Replace` and `RobertaProcessing`.
+//!
+//! **fancy-regex**: Provides the same components as **onig**, using the pure-Rust
+//!   [fancy-regex](https://crates.io/crates/fancy-regex) library instead. Useful for targets where
+//!   `onig` can't be compiled, like WASM. If both are enabled, `onig` is used.
+//!
+//! **precompiled**: The `Precompiled` normalizer, used by tokenizers converted from SentencePiece.
+//!
+//! **training**: The trainers of all the enabled models. This also enables **parallel**, and
+//!   **bpe** since the `WordPieceTrainer` relies on the `BpeTrainer`.
+//!
+//! **parallel**: Parallelism using [rayon](https://crates.io/crates/rayon). Without it, everything
+//!   runs serially.
+//!
 //! **progressbar**: The progress bar visualization is enabled by default. It might be disabled if
 //!   compilation for certain targets is not supported by the [termios](https://crates.io/crates/termios)
 //!   dependency of the [indicatif](https://crates.io/crates/indicatif) progress bar.
+//!
+//! **masking**: `WholeWordMasking`, to build the inputs of masked language models.
+//!
+//! **cli**: Dependencies of the `cli` binary.
+//!
+//! **bert**: Alias for the components needed by BERT-like tokenizers. Use it along with
+//!   `default-features = false` to get a minimal build.
 
 #[macro_use]
 extern crate log;
-#[cfg(not(feature = "bert"))]
 #[macro_use]
 extern crate lazy_static;
 
-#[cfg(not(feature = "bert"))]
 #[macro_use]
 extern crate derive_builder;
 
+#[cfg(not(any(
+    feature = "bpe",
+    feature = "wordpiece",
+    feature = "wordlevel",
+    feature = "unigram"
+)))]
+compile_error!("At least one model feature must be enabled: bpe, wordpiece, wordlevel or unigram");
+
 #[macro_use]
 pub mod utils;
 pub mod decoders;
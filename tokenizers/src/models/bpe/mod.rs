@@ -1,8 +1,11 @@
 //! [Byte Pair Encoding](https://www.aclweb.org/anthology/P16-1162/) model.
-use std::{convert::From, io, iter, mem};
+use std::{convert::From, io};
+#[cfg(feature = "training")]
+use std::{iter, mem};
 
 mod model;
 mod serialization;
+#[cfg(feature = "training")]
 mod trainer;
 mod word;
 
@@ -69,10 +72,12 @@ impl std::error::Error for Error {
 }
 
 /// Provides access to the `FirstLastIterator` to any Iterator
+#[cfg(feature = "training")]
 pub(crate) trait WithFirstLastIterator: Iterator + Sized {
     fn with_first_and_last(self) -> FirstLastIterator<Self>;
 }
 
+#[cfg(feature = "training")]
 impl<I> WithFirstLastIterator for I
 where
     I: Iterator,
@@ -86,6 +91,7 @@ where
 }
 
 /// Provides information about whether an item is the first and/or the last of the iterator
+#[cfg(feature = "training")]
 pub(crate) struct FirstLastIterator<I>
 where
     I: Iterator,
@@ -94,6 +100,7 @@ where
     iter: iter::Peekable<I>,
 }
 
+#[cfg(feature = "training")]
 impl<I> Iterator for FirstLastIterator<I>
 where
     I: Iterator,
@@ -111,5 +118,6 @@ where
 
 // Re-export
 pub use model::*;
+#[cfg(feature = "training")]
 pub use trainer::*;
 use word::*;
@@ -1,4 +1,6 @@
-use super::{super::OrderedVocabIter, trainer::BpeTrainer, Error, Pair, Word};
+#[cfg(feature = "training")]
+use super::trainer::BpeTrainer;
+use super::{super::OrderedVocabIter, Error, Pair, Word};
 use crate::tokenizer::{Model, Result, Token};
 use crate::utils::cache::{Cache, DEFAULT_CACHE_CAPACITY};
 use crate::utils::iter::ResultShunt;
@@ -298,7 +300,7 @@ impl BPE {
                     }
                 }
             }
-            _ => return Err(Box::new(Error::BadVocabulary)),
+            _ => return Err(Error::BadVocabulary.into()),
         };
 
         // Read merges file
@@ -415,6 +417,7 @@ impl BPE {
 }
 
 impl Model for BPE {
+    #[cfg(feature = "training")]
     type Trainer = BpeTrainer;
 
     fn get_vocab(&self) -> HashMap<String, u32> {
@@ -490,6 +493,7 @@ impl Model for BPE {
         Ok(vec![vocab_path, merges_path])
     }
 
+    #[cfg(feature = "training")]
     fn get_trainer(&self) -> BpeTrainer {
         BpeTrainer::default()
     }
@@ -718,9 +722,9 @@ mod tests {
         .build()
         {
             Ok(_) => unreachable!(),
-            Err(err) => match err.downcast_ref::<Error>() {
-                Some(Error::MergeTokenOutOfVocabulary(token)) => {
-                    assert_eq!(*token, String::from("d"))
+            Err(err) => match err {
+                crate::Error::Bpe(Error::MergeTokenOutOfVocabulary(token)) => {
+                    assert_eq!(token, String::from("d"))
                 }
                 _ => unreachable!(),
             },
@@ -749,8 +753,8 @@ mod tests {
         .build()
         {
             Ok(_) => unreachable!(),
-            Err(err) => match err.downcast_ref::<Error>() {
-                Some(Error::BadMerges(line)) => assert_eq!(*line, 2),
+            Err(err) => match err {
+                crate::Error::Bpe(Error::BadMerges(line)) => assert_eq!(line, 2),
                 _ => unreachable!(),
             },
         }
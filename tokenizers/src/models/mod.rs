@@ -1,46 +1,56 @@
 //! Popular tokenizer models.
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "bpe")]
 pub mod bpe;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "unigram")]
 pub mod unigram;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "wordlevel")]
 pub mod wordlevel;
+#[cfg(feature = "wordpiece")]
 pub mod wordpiece;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
-use crate::{models::wordpiece::WordPiece, Model, Result, Token};
-#[cfg(not(feature = "bert"))]
-use crate::{
-    models::{
-        bpe::{BpeTrainer, BPE},
-        unigram::{Unigram, UnigramTrainer},
-        wordlevel::{WordLevel, WordLevelTrainer},
-        wordpiece::WordPieceTrainer,
-    },
-    AddedToken, Trainer,
-};
+#[cfg(feature = "bpe")]
+use crate::models::bpe::BPE;
+#[cfg(feature = "unigram")]
+use crate::models::unigram::Unigram;
+#[cfg(all(feature = "training", feature = "unigram"))]
+use crate::models::unigram::UnigramTrainer;
+#[cfg(feature = "wordlevel")]
+use crate::models::wordlevel::WordLevel;
+#[cfg(all(feature = "training", feature = "wordlevel"))]
+use crate::models::wordlevel::WordLevelTrainer;
+#[cfg(feature = "wordpiece")]
+use crate::models::wordpiece::WordPiece;
+#[cfg(all(feature = "training", feature = "wordpiece"))]
+use crate::models::wordpiece::WordPieceTrainer;
+#[cfg(feature = "training")]
+use crate::{models::bpe::BpeTrainer, AddedToken, Error, Trainer};
+use crate::{Model, Result, Token};
 
 /// Wraps a vocab mapping (ID -> token) to a struct that will be serialized in order
 /// of token ID, smallest to largest.
+#[cfg(any(feature = "bpe", feature = "wordpiece", feature = "wordlevel"))]
 struct OrderedVocabIter<'a> {
     vocab_r: &'a HashMap<u32, String>,
 }
 
+#[cfg(any(feature = "bpe", feature = "wordpiece", feature = "wordlevel"))]
 impl<'a> OrderedVocabIter<'a> {
     fn new(vocab_r: &'a HashMap<u32, String>) -> Self {
         Self { vocab_r }
     }
 }
 
+#[cfg(any(feature = "bpe", feature = "wordpiece", feature = "wordlevel"))]
 impl<'a> Serialize for OrderedVocabIter<'a> {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
-        S: Serializer,
+        S: serde::Serializer,
     {
         let iter = (0u32..(self.vocab_r.len() as u32)).map(|i| (&self.vocab_r[&i], i));
         serializer.collect_map(iter)
@@ -48,40 +58,70 @@ impl<'a> Serialize for OrderedVocabIter<'a> {
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum ModelWrapper {
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "bpe")]
     BPE(BPE),
     // WordPiece must stay before WordLevel here for deserialization (for retrocompatibility
     // with the versions not including the "type"), since WordLevel is a subset of WordPiece
+    #[cfg(feature = "wordpiece")]
     WordPiece(WordPiece),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "wordlevel")]
     WordLevel(WordLevel),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "unigram")]
     Unigram(Unigram),
 }
 
-#[cfg(not(feature = "bert"))]
+/// Find the features required by the given model `type`, if it has been disabled
+#[allow(clippy::match_single_binding)]
+fn disabled_feature(ty: &str) -> Option<&'static str> {
+    match ty {
+        #[cfg(not(feature = "bpe"))]
+        "BPE" => Some("`bpe`"),
+        #[cfg(not(feature = "wordpiece"))]
+        "WordPiece" => Some("`wordpiece`"),
+        #[cfg(not(feature = "wordlevel"))]
+        "WordLevel" => Some("`wordlevel`"),
+        #[cfg(not(feature = "unigram"))]
+        "Unigram" => Some("`unigram`"),
+        _ => None,
+    }
+}
+
+impl_serde_wrapper!(ModelWrapper, disabled_feature, {
+    #[cfg(feature = "bpe")]
+    "BPE" => BPE,
+    #[cfg(feature = "wordpiece")]
+    "WordPiece" => WordPiece,
+    #[cfg(feature = "wordlevel")]
+    "WordLevel" => WordLevel,
+    #[cfg(feature = "unigram")]
+    "Unigram" => Unigram,
+});
+
+#[cfg(feature = "wordlevel")]
 impl_enum_from!(WordLevel, ModelWrapper, WordLevel);
+#[cfg(feature = "wordpiece")]
 impl_enum_from!(WordPiece, ModelWrapper, WordPiece);
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "bpe")]
 impl_enum_from!(BPE, ModelWrapper, BPE);
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "unigram")]
 impl_enum_from!(Unigram, ModelWrapper, Unigram);
 
 impl Model for ModelWrapper {
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     type Trainer = TrainerWrapper;
 
     fn tokenize(&self, tokens: &str) -> Result<Vec<Token>> {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.tokenize(tokens),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.tokenize(tokens),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.tokenize(tokens),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.tokenize(tokens),
         }
     }
@@ -89,12 +129,13 @@ impl Model for ModelWrapper {
     fn token_to_id(&self, token: &str) -> Option<u32> {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.token_to_id(token),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.token_to_id(token),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.token_to_id(token),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.token_to_id(token),
         }
     }
@@ -102,12 +143,13 @@ impl Model for ModelWrapper {
     fn id_to_token(&self, id: u32) -> Option<String> {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.id_to_token(id),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.id_to_token(id),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.id_to_token(id),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.id_to_token(id),
         }
     }
@@ -115,12 +157,13 @@ impl Model for ModelWrapper {
     fn get_vocab(&self) -> HashMap<String, u32> {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.get_vocab(),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.get_vocab(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.get_vocab(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.get_vocab(),
         }
     }
@@ -128,12 +171,13 @@ impl Model for ModelWrapper {
     fn get_vocab_size(&self) -> usize {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.get_vocab_size(),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.get_vocab_size(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.get_vocab_size(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.get_vocab_size(),
         }
     }
@@ -141,69 +185,88 @@ impl Model for ModelWrapper {
     fn save(&self, folder: &Path, name: Option<&str>) -> Result<Vec<PathBuf>> {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.save(folder, name),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.save(folder, name),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.save(folder, name),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.save(folder, name),
         }
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     fn get_trainer(&self) -> Self::Trainer {
         use ModelWrapper::*;
         match self {
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "wordlevel")]
             WordLevel(t) => t.get_trainer().into(),
+            #[cfg(feature = "wordpiece")]
             WordPiece(t) => t.get_trainer().into(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "bpe")]
             BPE(t) => t.get_trainer().into(),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(feature = "unigram")]
             Unigram(t) => t.get_trainer().into(),
         }
     }
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 pub enum TrainerWrapper {
     BpeTrainer(BpeTrainer),
+    #[cfg(feature = "wordpiece")]
     WordPieceTrainer(WordPieceTrainer),
+    #[cfg(feature = "wordlevel")]
     WordLevelTrainer(WordLevelTrainer),
+    #[cfg(feature = "unigram")]
     UnigramTrainer(UnigramTrainer),
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 impl Trainer for TrainerWrapper {
     type Model = ModelWrapper;
 
     fn should_show_progress(&self) -> bool {
         match self {
             TrainerWrapper::BpeTrainer(bpe) => bpe.should_show_progress(),
+            #[cfg(feature = "wordpiece")]
             TrainerWrapper::WordPieceTrainer(wpt) => wpt.should_show_progress(),
+            #[cfg(feature = "wordlevel")]
             TrainerWrapper::WordLevelTrainer(wpt) => wpt.should_show_progress(),
+            #[cfg(feature = "unigram")]
             TrainerWrapper::UnigramTrainer(wpt) => wpt.should_show_progress(),
         }
     }
 
+    // With a single model enabled, the fallback arms can't be reached
+    #[allow(unreachable_patterns)]
     fn train(&self, model: &mut ModelWrapper) -> Result<Vec<AddedToken>> {
         match self {
             TrainerWrapper::BpeTrainer(t) => match model {
                 ModelWrapper::BPE(bpe) => t.train(bpe),
-                _ => Err("BpeTrainer can only train a BPE".into()),
+                _ => Err(Error::configuration("BpeTrainer can only train a BPE")),
             },
+            #[cfg(feature = "wordpiece")]
             TrainerWrapper::WordPieceTrainer(t) => match model {
                 ModelWrapper::WordPiece(wp) => t.train(wp),
-                _ => Err("WordPieceTrainer can only train a WordPiece".into()),
+                _ => Err(Error::configuration(
+                    "WordPieceTrainer can only train a WordPiece",
+                )),
             },
+            #[cfg(feature = "wordlevel")]
             TrainerWrapper::WordLevelTrainer(t) => match model {
                 ModelWrapper::WordLevel(wl) => t.train(wl),
-                _ => Err("WordLevelTrainer can only train a WordLevel".into()),
+                _ => Err(Error::configuration(
+                    "WordLevelTrainer can only train a WordLevel",
+                )),
             },
+            #[cfg(feature = "unigram")]
             TrainerWrapper::UnigramTrainer(t) => match model {
                 ModelWrapper::Unigram(u) => t.train(u),
-                _ => Err("UnigramTrainer can only train a Unigram".into()),
+                _ => Err(Error::configuration(
+                    "UnigramTrainer can only train a Unigram",
+                )),
             },
         }
     }
@@ -216,32 +279,50 @@ impl Trainer for TrainerWrapper {
     {
         match self {
             TrainerWrapper::BpeTrainer(bpe) => bpe.feed(iterator, process),
+            #[cfg(feature = "wordpiece")]
             TrainerWrapper::WordPieceTrainer(wpt) => wpt.feed(iterator, process),
+            #[cfg(feature = "wordlevel")]
             TrainerWrapper::WordLevelTrainer(wpt) => wpt.feed(iterator, process),
+            #[cfg(feature = "unigram")]
             TrainerWrapper::UnigramTrainer(wpt) => wpt.feed(iterator, process),
         }
     }
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 impl_enum_from!(BpeTrainer, TrainerWrapper, BpeTrainer);
-#[cfg(not(feature = "bert"))]
+#[cfg(all(feature = "training", feature = "wordpiece"))]
 impl_enum_from!(WordPieceTrainer, TrainerWrapper, WordPieceTrainer);
-#[cfg(not(feature = "bert"))]
+#[cfg(all(feature = "training", feature = "unigram"))]
 impl_enum_from!(UnigramTrainer, TrainerWrapper, UnigramTrainer);
-#[cfg(not(feature = "bert"))]
+#[cfg(all(feature = "training", feature = "wordlevel"))]
 impl_enum_from!(WordLevelTrainer, TrainerWrapper, WordLevelTrainer);
 
-#[cfg(all(test, not(feature = "bert")))]
+#[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(all(feature = "training", feature = "unigram"))]
     #[test]
     fn trainer_wrapper_train_model_wrapper() {
         let trainer = TrainerWrapper::BpeTrainer(BpeTrainer::default());
         let mut model = ModelWrapper::Unigram(Unigram::default());
 
         let result = trainer.train(&mut model);
-        assert!(result.is_err());
+        assert!(matches!(result, Err(Error::Configuration(_))));
+    }
+
+    #[test]
+    fn deserialize_disabled_model() {
+        let bpe = r#"{"type":"BPE","dropout":null,"unk_token":null,"continuing_subword_prefix":null,"end_of_word_suffix":null,"fuse_unk":false,"vocab":{},"merges":[]}"#;
+        let result = serde_json::from_str::<ModelWrapper>(bpe);
+        if cfg!(feature = "bpe") {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "`BPE` is not available, tokenizers must be compiled with the `bpe` feature"
+            );
+        }
     }
 }
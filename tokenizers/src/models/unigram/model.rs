@@ -1,6 +1,7 @@
+#[cfg(feature = "training")]
+use super::trainer::UnigramTrainer;
 use super::{
     lattice::Lattice,
-    trainer::UnigramTrainer,
     trie::{Trie, TrieBuilder},
 };
 use crate::tokenizer::{Model, Result, Token};
@@ -111,10 +112,10 @@ impl Unigram {
 
         if let Some(unk_id) = unk_id {
             if vocab.is_empty() {
-                return Err(Box::new(UnigramError::EmptyVocabulary));
+                return Err(UnigramError::EmptyVocabulary.into());
             }
             if unk_id >= vocab.len() {
-                return Err(Box::new(UnigramError::UnkIdNotInVocabulary));
+                return Err(UnigramError::UnkIdNotInVocabulary.into());
             }
         }
 
@@ -407,6 +408,7 @@ impl<'a> Iterator for UnigramIterator<'a> {
 }
 
 impl Model for Unigram {
+    #[cfg(feature = "training")]
     type Trainer = UnigramTrainer;
 
     fn get_vocab(&self) -> HashMap<String, u32> {
@@ -455,6 +457,7 @@ impl Model for Unigram {
         Ok(vec![fullpath])
     }
 
+    #[cfg(feature = "training")]
     fn get_trainer(&self) -> Self::Trainer {
         UnigramTrainer::default()
     }
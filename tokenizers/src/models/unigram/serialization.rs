@@ -49,7 +49,7 @@ impl<'de> Visitor<'de> for UnigramVisitor {
                     unk_id = map.next_value()?;
                 }
                 "vocab" => vocab = Some(map.next_value()?),
-                "type" => match map.next_value()? {
+                "type" => match map.next_value::<String>()?.as_ref() {
                     "Unigram" => {}
                     u => {
                         return Err(serde::de::Error::invalid_value(
@@ -1,7 +1,7 @@
 //! [WordPiece](https://static.googleusercontent.com/media/research.google.com/en//pubs/archive/37842.pdf)
 //! model.
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "bpe")]
 use crate::models::bpe::BPE;
 use crate::tokenizer::{Model, Result, Token};
 use std::{
@@ -15,9 +15,9 @@ use std::{
 };
 
 mod serialization;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 mod trainer;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 pub use trainer::*;
 
 #[derive(Debug)]
@@ -186,7 +186,7 @@ impl WordPiece {
         WordPiece::builder().files(vocab.to_owned())
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "bpe")]
     /// Create a `WordPiece` model from a `BPE` model.
     pub fn from_bpe(bpe: &BPE) -> Self {
         let mut wp = Self::builder().vocab(bpe.get_vocab()).build().unwrap();
@@ -201,7 +201,7 @@ impl WordPiece {
 }
 
 impl Model for WordPiece {
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     type Trainer = WordPieceTrainer;
 
     fn get_vocab(&self) -> HashMap<String, u32> {
@@ -304,7 +304,7 @@ impl Model for WordPiece {
         Ok(vec![vocab_path])
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     fn get_trainer(&self) -> Self::Trainer {
         WordPieceTrainer::builder().build()
     }
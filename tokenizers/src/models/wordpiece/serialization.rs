@@ -79,7 +79,7 @@ impl<'de> Visitor<'de> for WordPieceVisitor {
                     builder = builder.max_input_chars_per_word(map.next_value()?)
                 }
                 "vocab" => builder = builder.vocab(map.next_value()?),
-                "type" => match map.next_value()? {
+                "type" => match map.next_value::<String>()?.as_ref() {
                     "WordPiece" => {}
                     u => {
                         return Err(serde::de::Error::invalid_value(
@@ -0,0 +1,401 @@
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+
+/// What replaces the tags of the block elements, like `<p>`, `<br>` or `<li>`, with `HtmlStrip`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockSeparator {
+    /// Remove them like any other tag
+    None,
+    Space,
+    Newline,
+}
+
+impl BlockSeparator {
+    fn as_char(self) -> Option<char> {
+        match self {
+            BlockSeparator::None => None,
+            BlockSeparator::Space => Some(' '),
+            BlockSeparator::Newline => Some('\n'),
+        }
+    }
+}
+
+/// The elements that start a new block of text
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// The elements whose content gets removed along with their tags
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Removes the HTML tags and comments, along with the content of `<script>` and `<style>`, and
+/// decodes the character references like `&amp;` or `&#233;`. The tags of the block elements
+/// can be replaced by a separator, so that the words of two blocks don't get merged. Everything
+/// else keeps its alignments with the raw markup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct HtmlStrip {
+    pub decode_entities: bool,
+    pub block_separator: BlockSeparator,
+}
+
+impl Default for HtmlStrip {
+    fn default() -> Self {
+        Self {
+            decode_entities: true,
+            block_separator: BlockSeparator::Space,
+        }
+    }
+}
+
+impl HtmlStrip {
+    pub fn new(decode_entities: bool, block_separator: BlockSeparator) -> Self {
+        Self {
+            decode_entities,
+            block_separator,
+        }
+    }
+
+    /// The number of chars of the tag or comment starting at `start`, if any, with its separator
+    fn tag(&self, chars: &[char], start: usize) -> Option<(usize, Option<char>)> {
+        if chars[start] != '<' {
+            return None;
+        }
+        if starts_with(chars, start, "<!--") {
+            let end = find(chars, start + 4, "-->").map_or(chars.len(), |end| end + 3);
+            return Some((end - start, None));
+        }
+        let is_tag = match chars.get(start + 1) {
+            Some(c) => c.is_ascii_alphabetic() || "/!?".contains(*c),
+            None => false,
+        };
+        if !is_tag {
+            return None;
+        }
+
+        // Look for the end of the tag, ignoring the `>` in the quoted attribute values. Another
+        // `<` means that this one was just text, and stopping there keeps the scan linear.
+        let mut quote = None;
+        let mut end = None;
+        for (i, c) in chars.iter().enumerate().skip(start + 1) {
+            match (quote, *c) {
+                (None, '>') => {
+                    end = Some(i + 1);
+                    break;
+                }
+                (None, '<') => break,
+                (None, '"') | (None, '\'') => quote = Some(*c),
+                (Some(q), c) if q == c => quote = None,
+                _ => {}
+            }
+        }
+        let mut end = end?;
+
+        let closing = chars[start + 1] == '/';
+        let name = chars[start + 1..end]
+            .iter()
+            .skip_while(|c| **c == '/')
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>();
+        if !closing && RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let closing_tag = format!("</{}", name);
+            end = find(chars, end, &closing_tag)
+                .and_then(|close| {
+                    chars[close..]
+                        .iter()
+                        .position(|c| *c == '>')
+                        .map(|p| close + p + 1)
+                })
+                .unwrap_or(chars.len());
+        }
+
+        let separator = if BLOCK_ELEMENTS.contains(&name.as_str()) {
+            self.block_separator.as_char()
+        } else {
+            None
+        };
+        Some((end - start, separator))
+    }
+}
+
+impl Normalizer for HtmlStrip {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        let chars = normalized.get().chars().collect::<Vec<_>>();
+        let mut transformations: Vec<(char, isize)> = Vec::with_capacity(chars.len());
+        let mut initial_offset = 0;
+        // The byte range of each character reference once the tags are removed, with its char
+        let mut entities = vec![];
+        let mut len_bytes = 0;
+
+        let mut i = 0;
+        while i < chars.len() {
+            if let Some((len, separator)) = self.tag(&chars, i) {
+                let mut removed = len;
+                if let Some(c) = separator {
+                    transformations.push((c, 0));
+                    len_bytes += c.len_utf8();
+                    removed -= 1;
+                }
+                match transformations.last_mut() {
+                    Some((_, change)) => *change -= removed as isize,
+                    None => initial_offset += removed,
+                }
+                i += len;
+                continue;
+            }
+
+            let entity = if self.decode_entities && chars[i] == '&' {
+                entity(&chars, i)
+            } else {
+                None
+            };
+            let len = entity.map_or(1, |(len, _)| len);
+            let start = len_bytes;
+            for c in &chars[i..i + len] {
+                transformations.push((*c, 0));
+                len_bytes += c.len_utf8();
+            }
+            if let Some((_, c)) = entity {
+                entities.push((start..len_bytes, c));
+            }
+            i += len;
+        }
+        normalized.transform(transformations, initial_offset);
+
+        // The decoded chars are aligned with their whole reference
+        let entities = entities
+            .into_iter()
+            .map(|(range, c)| (range, c.to_string()))
+            .collect::<Vec<_>>();
+        normalized
+            .replace_ranges(
+                entities
+                    .iter()
+                    .map(|(range, c)| (range.clone(), c.as_str())),
+            )
+            .ok_or("HtmlStrip: invalid character reference range")?;
+        Ok(())
+    }
+}
+
+/// The number of chars of the character reference starting at `start`, with its decoded char
+fn entity(chars: &[char], start: usize) -> Option<(usize, char)> {
+    // The longest references we decode, like `&#x10FFFF;`
+    let len = chars[start + 1..]
+        .iter()
+        .take(9)
+        .take_while(|c| c.is_ascii_alphanumeric() || **c == '#')
+        .count();
+    let end = start + 1 + len;
+    if chars.get(end) != Some(&';') {
+        return None;
+    }
+    let name = chars[start + 1..end].iter().collect::<String>();
+
+    let c = if let Some(code) = name.strip_prefix('#') {
+        let code = match code.strip_prefix('x').or_else(|| code.strip_prefix('X')) {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => code.parse(),
+        };
+        code.ok()
+            .filter(|code| *code != 0)
+            .and_then(std::char::from_u32)?
+    } else {
+        named_entity(&name)?
+    };
+    Some((end + 1 - start, c))
+}
+
+/// The names of the Latin-1 references of HTML 4, from `&nbsp;` (U+00A0) to `&yuml;` (U+00FF)
+const LATIN1_ENTITIES: [&str; 96] = [
+    "nbsp", "iexcl", "cent", "pound", "curren", "yen", "brvbar", "sect", "uml", "copy", "ordf",
+    "laquo", "not", "shy", "reg", "macr", "deg", "plusmn", "sup2", "sup3", "acute", "micro",
+    "para", "middot", "cedil", "sup1", "ordm", "raquo", "frac14", "frac12", "frac34", "iquest",
+    "Agrave", "Aacute", "Acirc", "Atilde", "Auml", "Aring", "AElig", "Ccedil", "Egrave", "Eacute",
+    "Ecirc", "Euml", "Igrave", "Iacute", "Icirc", "Iuml", "ETH", "Ntilde", "Ograve", "Oacute",
+    "Ocirc", "Otilde", "Ouml", "times", "Oslash", "Ugrave", "Uacute", "Ucirc", "Uuml", "Yacute",
+    "THORN", "szlig", "agrave", "aacute", "acirc", "atilde", "auml", "aring", "aelig", "ccedil",
+    "egrave", "eacute", "ecirc", "euml", "igrave", "iacute", "icirc", "iuml", "eth", "ntilde",
+    "ograve", "oacute", "ocirc", "otilde", "ouml", "divide", "oslash", "ugrave", "uacute", "ucirc",
+    "uuml", "yacute", "thorn", "yuml",
+];
+
+fn named_entity(name: &str) -> Option<char> {
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "OElig" => 'Œ',
+        "oelig" => 'œ',
+        "Scaron" => 'Š',
+        "scaron" => 'š',
+        "Yuml" => 'Ÿ',
+        "trade" => '™',
+        "euro" => '€',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "sbquo" => '‚',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "bdquo" => '„',
+        "lsaquo" => '‹',
+        "rsaquo" => '›',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "bull" => '•',
+        "dagger" => '†',
+        "Dagger" => '‡',
+        "permil" => '‰',
+        _ => {
+            let index = LATIN1_ENTITIES.iter().position(|entity| *entity == name)?;
+            return std::char::from_u32(0xA0 + index as u32);
+        }
+    })
+}
+
+fn starts_with(chars: &[char], start: usize, pattern: &str) -> bool {
+    let mut chars = chars[start..].iter();
+    pattern
+        .chars()
+        .all(|p| matches!(chars.next(), Some(c) if c.to_ascii_lowercase() == p))
+}
+
+/// The position of the first occurrence of `pattern` from `start`, ignoring the ASCII case
+fn find(chars: &[char], start: usize, pattern: &str) -> Option<usize> {
+    (start..chars.len()).find(|i| starts_with(chars, *i, pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::normalizer::Range;
+
+    fn strip(normalizer: &HtmlStrip, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        normalizer.normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn strip_tags() {
+        let normalizer = HtmlStrip::default();
+        let n = strip(
+            &normalizer,
+            r#"<p class="a>b">Fish &amp; <b>chips</b></p><!-- <p>no</p> --><SCRIPT>x < 1</script>1 < 2"#,
+        );
+        assert_eq!(n.get(), " Fish & chips 1 < 2");
+
+        // The offsets point into the raw markup
+        let chips = n.get().find("chips").unwrap();
+        assert_eq!(
+            n.get_range_original(Range::Normalized(chips..chips + 5)),
+            Some("chips")
+        );
+        assert_eq!(
+            n.get_range_original(Range::Normalized(1..n.len())),
+            Some(r#"Fish &amp; <b>chips</b></p><!-- <p>no</p> --><SCRIPT>x < 1</script>1 < 2"#)
+        );
+    }
+
+    #[test]
+    fn block_separator() {
+        let s = "<ul><li>one</li><li>two<br/>three</li></ul>";
+        let newline = HtmlStrip::new(true, BlockSeparator::Newline);
+        assert_eq!(strip(&newline, s).get(), "\n\none\n\ntwo\nthree\n\n");
+        let none = HtmlStrip::new(true, BlockSeparator::None);
+        assert_eq!(strip(&none, s).get(), "onetwothree");
+    }
+
+    #[test]
+    fn entities() {
+        let normalizer = HtmlStrip::default();
+        let n = strip(
+            &normalizer,
+            "&lt;caf&#233; &#x2014; &unknown; &amp AT&amp;T&gt; Z&uuml;rich, Espa&ntilde;a",
+        );
+        assert_eq!(n.get(), "<café — &unknown; &amp AT&T> Zürich, España");
+
+        // The decoded chars map back to their whole reference
+        assert_eq!(n.get_range_original(Range::Normalized(0..1)), Some("&lt;"));
+        let cafe = n.get().find("café").unwrap();
+        assert_eq!(
+            n.get_range_original(Range::Normalized(cafe..cafe + "café".len())),
+            Some("caf&#233;")
+        );
+        let zurich = n.get().find("Zürich").unwrap();
+        assert_eq!(
+            n.get_range_original(Range::Normalized(zurich..zurich + "Zürich".len())),
+            Some("Z&uuml;rich")
+        );
+        assert_eq!(
+            strip(&normalizer, "<p>d&eacute;j&agrave;</p>")
+                .get_range_original(Range::Normalized(1..7)),
+            Some("d&eacute;j&agrave;")
+        );
+
+        let normalizer = HtmlStrip::new(false, BlockSeparator::Space);
+        assert_eq!(strip(&normalizer, "<i>&amp;</i>").get(), "&amp;");
+    }
+
+    #[test]
+    fn long_inputs() {
+        // Without any `>`, each `<` is text, and gets found in linear time
+        let text = "if a<b and b<c ".repeat(20_000);
+        assert_eq!(strip(&HtmlStrip::default(), &text).get(), text);
+        assert_eq!(strip(&HtmlStrip::default(), "a<b <i>c</i>").get(), "a<b c");
+
+        let n = strip(&HtmlStrip::default(), &"a &lt; b ".repeat(20_000));
+        assert_eq!(n.get(), "a < b ".repeat(20_000));
+        assert_eq!(n.get_range_original(Range::Normalized(8..9)), Some("&lt;"));
+    }
+
+    #[test]
+    fn serialization() {
+        let normalizer = HtmlStrip::default();
+        let normalizer_s =
+            r#"{"type":"HtmlStrip","decode_entities":true,"block_separator":"Space"}"#;
+        assert_eq!(serde_json::to_string(&normalizer).unwrap(), normalizer_s);
+        assert_eq!(
+            serde_json::from_str::<HtmlStrip>(normalizer_s).unwrap(),
+            normalizer
+        );
+    }
+}
@@ -1,23 +1,29 @@
 pub mod bert;
-#[cfg(not(feature = "bert"))]
+pub mod html;
+#[cfg(feature = "precompiled")]
 pub mod precompiled;
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 pub mod replace;
-#[cfg(not(feature = "bert"))]
+pub mod replace_map;
 pub mod strip;
-#[cfg(not(feature = "bert"))]
+pub mod transliterate;
 pub mod unicode;
-#[cfg(not(feature = "bert"))]
 pub mod utils;
+pub mod whitespace;
 
 pub use crate::normalizers::bert::BertNormalizer;
-#[cfg(not(feature = "bert"))]
+pub use crate::normalizers::html::HtmlStrip;
+#[cfg(feature = "precompiled")]
+pub use crate::normalizers::precompiled::Precompiled;
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
+pub use crate::normalizers::replace::Replace;
 pub use crate::normalizers::{
-    precompiled::Precompiled,
-    replace::Replace,
+    replace_map::ReplaceMap,
     strip::{Strip, StripAccents},
-    unicode::{Nmt, NFC, NFD, NFKC, NFKD},
-    utils::{Lowercase, Sequence},
+    transliterate::Transliterate,
+    unicode::{CaseFold, NfkcCasefold, Nmt, NFC, NFD, NFKC, NFKD},
+    utils::{LocaleLowercase, Lowercase, Sequence},
+    whitespace::CleanWhitespace,
 };
 
 use serde::{Deserialize, Serialize};
@@ -26,83 +32,113 @@ use crate::{NormalizedString, Normalizer};
 
 /// Wrapper for known Normalizers.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum NormalizerWrapper {
     BertNormalizer(BertNormalizer),
-    #[cfg(not(feature = "bert"))]
     StripNormalizer(Strip),
-    #[cfg(not(feature = "bert"))]
     StripAccents(StripAccents),
-    #[cfg(not(feature = "bert"))]
     NFC(NFC),
-    #[cfg(not(feature = "bert"))]
     NFD(NFD),
-    #[cfg(not(feature = "bert"))]
     NFKC(NFKC),
-    #[cfg(not(feature = "bert"))]
     NFKD(NFKD),
-    #[cfg(not(feature = "bert"))]
     Sequence(Sequence),
-    #[cfg(not(feature = "bert"))]
     Lowercase(Lowercase),
-    #[cfg(not(feature = "bert"))]
     Nmt(Nmt),
-    #[cfg(not(feature = "bert"))]
+    CaseFold(CaseFold),
+    NfkcCasefold(NfkcCasefold),
+    LocaleLowercase(LocaleLowercase),
+    Transliterate(Transliterate),
+    HtmlStrip(HtmlStrip),
+    ReplaceMap(ReplaceMap),
+    CleanWhitespace(CleanWhitespace),
+    #[cfg(feature = "precompiled")]
     Precompiled(Precompiled),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     Replace(Replace),
 }
 
+/// Find the features required by the given normalizer `type`, if it has been disabled
+#[allow(clippy::match_single_binding)]
+fn disabled_feature(ty: &str) -> Option<&'static str> {
+    match ty {
+        #[cfg(not(feature = "precompiled"))]
+        "Precompiled" => Some("`precompiled`"),
+        #[cfg(not(any(feature = "onig", feature = "fancy-regex")))]
+        "Replace" => Some("`onig` or `fancy-regex`"),
+        _ => None,
+    }
+}
+
+impl_serde_wrapper!(NormalizerWrapper, disabled_feature, {
+    "BertNormalizer" => BertNormalizer,
+    "Strip" => StripNormalizer,
+    "StripAccents" => StripAccents,
+    "NFC" => NFC,
+    "NFD" => NFD,
+    "NFKC" => NFKC,
+    "NFKD" => NFKD,
+    "Sequence" => Sequence,
+    "Lowercase" => Lowercase,
+    "Nmt" => Nmt,
+    "CaseFold" => CaseFold,
+    "NfkcCasefold" => NfkcCasefold,
+    "LocaleLowercase" => LocaleLowercase,
+    "Transliterate" => Transliterate,
+    "HtmlStrip" => HtmlStrip,
+    "ReplaceMap" => ReplaceMap,
+    "CleanWhitespace" => CleanWhitespace,
+    #[cfg(feature = "precompiled")]
+    "Precompiled" => Precompiled,
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "Replace" => Replace,
+});
+
 impl Normalizer for NormalizerWrapper {
     fn normalize(&self, normalized: &mut NormalizedString) -> crate::Result<()> {
         match self {
             NormalizerWrapper::BertNormalizer(bn) => bn.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::StripNormalizer(sn) => sn.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::StripAccents(sn) => sn.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::NFC(nfc) => nfc.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::NFD(nfd) => nfd.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::NFKC(nfkc) => nfkc.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::NFKD(nfkd) => nfkd.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::Sequence(sequence) => sequence.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::Lowercase(lc) => lc.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
             NormalizerWrapper::Nmt(lc) => lc.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
+            NormalizerWrapper::CaseFold(cf) => cf.normalize(normalized),
+            NormalizerWrapper::NfkcCasefold(cf) => cf.normalize(normalized),
+            NormalizerWrapper::LocaleLowercase(lc) => lc.normalize(normalized),
+            NormalizerWrapper::Transliterate(tr) => tr.normalize(normalized),
+            NormalizerWrapper::HtmlStrip(hs) => hs.normalize(normalized),
+            NormalizerWrapper::ReplaceMap(rm) => rm.normalize(normalized),
+            NormalizerWrapper::CleanWhitespace(cw) => cw.normalize(normalized),
+            #[cfg(feature = "precompiled")]
             NormalizerWrapper::Precompiled(lc) => lc.normalize(normalized),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             NormalizerWrapper::Replace(lc) => lc.normalize(normalized),
         }
     }
 }
 
 impl_enum_from!(BertNormalizer, NormalizerWrapper, BertNormalizer);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(NFKD, NormalizerWrapper, NFKD);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(NFKC, NormalizerWrapper, NFKC);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(NFC, NormalizerWrapper, NFC);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(NFD, NormalizerWrapper, NFD);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Strip, NormalizerWrapper, StripNormalizer);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(StripAccents, NormalizerWrapper, StripAccents);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Sequence, NormalizerWrapper, Sequence);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Lowercase, NormalizerWrapper, Lowercase);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Nmt, NormalizerWrapper, Nmt);
-#[cfg(not(feature = "bert"))]
+impl_enum_from!(CaseFold, NormalizerWrapper, CaseFold);
+impl_enum_from!(NfkcCasefold, NormalizerWrapper, NfkcCasefold);
+impl_enum_from!(LocaleLowercase, NormalizerWrapper, LocaleLowercase);
+impl_enum_from!(Transliterate, NormalizerWrapper, Transliterate);
+impl_enum_from!(HtmlStrip, NormalizerWrapper, HtmlStrip);
+impl_enum_from!(ReplaceMap, NormalizerWrapper, ReplaceMap);
+impl_enum_from!(CleanWhitespace, NormalizerWrapper, CleanWhitespace);
+#[cfg(feature = "precompiled")]
 impl_enum_from!(Precompiled, NormalizerWrapper, Precompiled);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(Replace, NormalizerWrapper, Replace);
@@ -1,6 +1,9 @@
-use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use crate::normalizers::NormalizerWrapper;
+use crate::tokenizer::{Error, NormalizedString, Normalizer, Result};
 pub use spm_precompiled::Precompiled;
 use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use unicode_normalization_alignments::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
 fn replace(transformations: &mut Vec<(char, isize)>, old_part: &str, new_part: &str) {
@@ -67,3 +70,274 @@ impl Normalizer for Precompiled {
         Ok(())
     }
 }
+
+/// Compile the given chain of normalizers into a `Precompiled` normalizer, backed by a
+/// SentencePiece compatible charsmap.
+///
+/// Only the normalizers that map each character on its own can be compiled: `NFC`, `NFD`,
+/// `NFKC`, `NFKD`, `Lowercase`, `CaseFold`, `NfkcCasefold`, `StripAccents`, `Nmt`, `Replace`
+/// with a single character `ReplacePattern::String`, and any `Sequence` of those. The charsmap
+/// gets an entry for each character that the chain modifies, and for the canonical
+/// decomposition of each composed character, so that decomposed inputs get composed again by
+/// `NFC` and `NFKC`.
+///
+/// `Precompiled` maps a whole grapheme using the shortest entry that it starts with, so, just as
+/// with the charsmaps built by SentencePiece, a modified character followed by other characters
+/// in the same grapheme (a decomposed accent, or `\n` after `\r`) loses them. The `\0`
+/// character can't be part of a charsmap, and is always kept as is.
+pub fn compile(normalizer: &NormalizerWrapper) -> Result<Precompiled> {
+    let mut replaced = vec![];
+    check_compilable(normalizer, &mut replaced)?;
+
+    let normalize = |s: &str| -> Result<String> {
+        let mut normalized = NormalizedString::from(s);
+        normalizer.normalize(&mut normalized)?;
+        Ok(normalized.get().to_owned())
+    };
+
+    let mut entries = BTreeMap::new();
+    // Past these ranges, the code points are either unassigned or for private use, and only a
+    // `Replace` can modify them
+    let chars = (1..0x40000)
+        .chain(0xE0000..0xE1000)
+        .filter_map(std::char::from_u32)
+        .chain(replaced);
+    for c in chars {
+        let key = c.to_string();
+        let decomposed = key.nfd().map(|(c, _)| c).collect::<String>();
+
+        let mut keys = vec![key];
+        // Graphemes of 6 bytes or more are never looked up as a whole
+        if decomposed.chars().count() > 1 && decomposed.len() < 6 {
+            keys.push(decomposed);
+        }
+        for key in keys {
+            let value = normalize(&key)?;
+            if value != key {
+                entries.insert(key, value);
+            }
+        }
+    }
+
+    // The normalized blob holds each distinct value once, terminated by `\0`
+    let mut normalized = vec![];
+    let mut offsets = HashMap::new();
+    let mut keys = BTreeMap::new();
+    for (key, value) in entries {
+        let offset = match offsets.get(&value) {
+            Some(offset) => *offset,
+            None => {
+                let offset = normalized.len() as u32;
+                normalized.extend(value.as_bytes());
+                normalized.push(0);
+                offsets.insert(value, offset);
+                offset
+            }
+        };
+        keys.insert(key.into_bytes(), offset);
+    }
+
+    let units = build_double_array(&keys)?;
+    let mut charsmap = Vec::with_capacity(4 + units.len() * 4 + normalized.len());
+    charsmap.extend(&((units.len() * 4) as u32).to_le_bytes());
+    for unit in units {
+        charsmap.extend(&unit.to_le_bytes());
+    }
+    charsmap.extend(normalized);
+
+    Precompiled::from(&charsmap).map_err(Error::other)
+}
+
+/// Make sure that the given normalizer only maps each character on its own, and collect the
+/// characters replaced by a `Replace`
+fn check_compilable(normalizer: &NormalizerWrapper, replaced: &mut Vec<char>) -> Result<()> {
+    match normalizer {
+        NormalizerWrapper::NFC(_)
+        | NormalizerWrapper::NFD(_)
+        | NormalizerWrapper::NFKC(_)
+        | NormalizerWrapper::NFKD(_)
+        | NormalizerWrapper::Lowercase(_)
+        | NormalizerWrapper::StripAccents(_)
+        | NormalizerWrapper::Nmt(_)
+        | NormalizerWrapper::CaseFold(_)
+        | NormalizerWrapper::NfkcCasefold(_) => Ok(()),
+        NormalizerWrapper::Sequence(sequence) => sequence
+            .get_normalizers()
+            .iter()
+            .try_for_each(|n| check_compilable(n, replaced)),
+        #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+        NormalizerWrapper::Replace(replace) => match replace.get_pattern() {
+            crate::normalizers::replace::ReplacePattern::String(s) if s.chars().count() == 1 => {
+                replaced.extend(s.chars());
+                Ok(())
+            }
+            _ => Err(Error::configuration(
+                "Only a `Replace` of a single character can be compiled",
+            )),
+        },
+        _ => Err(Error::configuration(format!(
+            "Cannot compile the normalizer {:?}",
+            normalizer
+        ))),
+    }
+}
+
+// The units of the double array, following the layout of Darts-clone, used by SentencePiece
+const HAS_LEAF: u32 = 1 << 8;
+const IS_VALUE: u32 = 1 << 31;
+const MAX_OFFSET: usize = 1 << 21;
+
+#[derive(Default)]
+struct TrieNode {
+    value: Option<u32>,
+    children: BTreeMap<u8, usize>,
+}
+
+/// Build the double array for the given keys, each associated with an offset in the normalized
+/// blob. Each node lives at a position whose label is checked during the lookups, and its
+/// children live at `base ^ label`, where `base` is unique to each node. The value of a key lives
+/// at `base` itself, where a child with the label `0` would be.
+fn build_double_array(keys: &BTreeMap<Vec<u8>, u32>) -> Result<Vec<u32>> {
+    let mut nodes = vec![TrieNode::default()];
+    for (key, value) in keys {
+        let mut node = 0;
+        for byte in key {
+            node = match nodes[node].children.get(byte) {
+                Some(child) => *child,
+                None => {
+                    nodes.push(TrieNode::default());
+                    let child = nodes.len() - 1;
+                    nodes[node].children.insert(*byte, child);
+                    child
+                }
+            };
+        }
+        nodes[node].value = Some(*value);
+    }
+
+    let mut units: Vec<u32> = vec![0; 256];
+    let mut used = vec![false; 256];
+    used[0] = true;
+    let mut bases = HashSet::new();
+    let mut first_free = 1;
+    let mut queue = VecDeque::new();
+    queue.push_back((0, 0));
+    while let Some((node, position)) = queue.pop_front() {
+        let node: &TrieNode = &nodes[node];
+        let labels = node
+            .value
+            .map(|_| 0)
+            .into_iter()
+            .chain(node.children.keys().copied())
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            continue;
+        }
+
+        // Find the first base that puts every label on a free position
+        let is_free = |used: &[bool], position: usize| position >= used.len() || !used[position];
+        let mut candidate = first_free;
+        let base = loop {
+            let base = candidate ^ labels[0] as usize;
+            if !bases.contains(&base)
+                && labels
+                    .iter()
+                    .all(|label| is_free(&used, base ^ *label as usize))
+            {
+                break base;
+            }
+            candidate += 1;
+            while !is_free(&used, candidate) {
+                candidate += 1;
+            }
+        };
+        bases.insert(base);
+
+        let offset = position ^ base;
+        if offset >= MAX_OFFSET {
+            return Err(Error::configuration("Too many entries to build a charsmap"));
+        }
+        units[position] |= (offset as u32) << 10;
+
+        // Every position reachable from `base` must exist, so we grow by blocks of 256
+        let end = (base | 0xFF) + 1;
+        if units.len() < end {
+            units.resize(end, 0);
+            used.resize(end, false);
+        }
+        if let Some(value) = node.value {
+            units[position] |= HAS_LEAF;
+            units[base] = value | IS_VALUE;
+            used[base] = true;
+        }
+        for (label, child) in &node.children {
+            let child_position = base ^ *label as usize;
+            units[child_position] = *label as u32;
+            used[child_position] = true;
+            queue.push_back((*child, child_position));
+        }
+        while !is_free(&used, first_free) {
+            first_free += 1;
+        }
+    }
+
+    Ok(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizers::{Lowercase, Nmt, Sequence, StripAccents, NFKC};
+
+    fn normalize<N: Normalizer>(normalizer: &N, s: &str) -> String {
+        let mut normalized = NormalizedString::from(s);
+        normalizer.normalize(&mut normalized).unwrap();
+        normalized.get().to_owned()
+    }
+
+    #[test]
+    fn compile_chain() {
+        let mut chain = vec![Nmt.into(), NFKC.into(), Lowercase.into()];
+        #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+        chain.push(crate::normalizers::Replace::new("`", "'").unwrap().into());
+        let chain = NormalizerWrapper::Sequence(Sequence::new(chain));
+        let precompiled = compile(&chain).unwrap();
+
+        for s in &[
+            "Hello World",
+            "ＨＥＬＬＯ ｆｕｌｌ\u{3000}width ①②",
+            "Ça VA\tbien ? `Ünïcode` ﬁne",
+            "Ἀθῆναι ΣΟΦΙΑ",
+            "e\u{301}te\u{301} \u{200b}",
+            "ｶﾀｶﾅ 한국어 ㈜",
+        ] {
+            assert_eq!(normalize(&precompiled, s), normalize(&chain, s), "{}", s);
+        }
+
+        // The charsmap is kept through serialization
+        let serialized = serde_json::to_string(&precompiled).unwrap();
+        let deserialized: Precompiled = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(normalize(&deserialized, "ＨＥＬＬＯ"), "hello");
+    }
+
+    #[test]
+    fn compile_strip_accents() {
+        let chain = NormalizerWrapper::Sequence(Sequence::new(vec![
+            crate::normalizers::NFD.into(),
+            StripAccents.into(),
+        ]));
+        let precompiled = compile(&chain).unwrap();
+        assert_eq!(normalize(&precompiled, "Café crème"), "Cafe creme");
+        assert_eq!(normalize(&precompiled, "Cafe\u{301}"), "Cafe");
+    }
+
+    #[test]
+    fn compile_unsupported() {
+        let strip: NormalizerWrapper = crate::normalizers::Strip::new(true, true).into();
+        assert!(compile(&strip).is_err());
+        let chain = NormalizerWrapper::Sequence(Sequence::new(vec![NFKC.into(), strip]));
+        assert!(compile(&chain).is_err());
+        #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+        assert!(compile(&crate::normalizers::Replace::new("''", "\"").unwrap().into()).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::tokenizer::{Error, NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// We use this custom deserializer to build the trie of `ReplaceMap`
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+struct ReplaceMapDeserializer {
+    rules: BTreeMap<String, String>,
+}
+
+impl std::convert::TryFrom<ReplaceMapDeserializer> for ReplaceMap {
+    type Error = crate::Error;
+
+    fn try_from(v: ReplaceMapDeserializer) -> Result<Self> {
+        ReplaceMap::new(v.rules)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    /// The rule ending on this node, if any
+    rule: Option<usize>,
+}
+
+/// Replaces every occurrence of the literal patterns of a table of rules with their content,
+/// in a single pass. At each position, the longest pattern that matches is replaced, and the
+/// replaced text is never matched again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", try_from = "ReplaceMapDeserializer")]
+pub struct ReplaceMap {
+    rules: BTreeMap<String, String>,
+    #[serde(skip)]
+    trie: Vec<TrieNode>,
+    #[serde(skip)]
+    contents: Vec<String>,
+}
+
+impl PartialEq for ReplaceMap {
+    fn eq(&self, other: &ReplaceMap) -> bool {
+        self.rules == other.rules
+    }
+}
+
+impl ReplaceMap {
+    pub fn new<I, P, C>(rules: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (P, C)>,
+        P: Into<String>,
+        C: Into<String>,
+    {
+        let rules = rules
+            .into_iter()
+            .map(|(pattern, content)| (pattern.into(), content.into()))
+            .collect::<BTreeMap<String, String>>();
+
+        let mut trie = vec![TrieNode::default()];
+        let mut contents = Vec::with_capacity(rules.len());
+        for (pattern, content) in &rules {
+            if pattern.is_empty() {
+                return Err(Error::configuration(
+                    "The patterns of `ReplaceMap` can't be empty",
+                ));
+            }
+            let mut node = 0;
+            for c in pattern.chars() {
+                node = match trie[node].children.get(&c) {
+                    Some(child) => *child,
+                    None => {
+                        trie.push(TrieNode::default());
+                        let child = trie.len() - 1;
+                        trie[node].children.insert(c, child);
+                        child
+                    }
+                };
+            }
+            trie[node].rule = Some(contents.len());
+            contents.push(content.clone());
+        }
+
+        Ok(Self {
+            rules,
+            trie,
+            contents,
+        })
+    }
+
+    pub fn get_rules(&self) -> &BTreeMap<String, String> {
+        &self.rules
+    }
+
+    /// The number of chars of the longest pattern matching at `start`, with its content
+    fn longest_match(&self, chars: &[char], start: usize) -> Option<(usize, &str)> {
+        let mut node = 0;
+        let mut longest = None;
+        for (i, c) in chars[start..].iter().enumerate() {
+            match self.trie[node].children.get(c) {
+                Some(child) => node = *child,
+                None => break,
+            }
+            if let Some(rule) = self.trie[node].rule {
+                longest = Some((i + 1, self.contents[rule].as_str()));
+            }
+        }
+        longest
+    }
+}
+
+impl Normalizer for ReplaceMap {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        let chars = normalized.get().chars().collect::<Vec<_>>();
+        let mut transformations: Vec<(char, isize)> = Vec::with_capacity(chars.len());
+        let mut initial_offset = 0;
+
+        let mut i = 0;
+        while i < chars.len() {
+            match self.longest_match(&chars, i) {
+                Some((len, content)) => {
+                    // Each char of the content replaces one char of the pattern, so that the
+                    // content stays aligned with as much of the pattern as possible. The extra
+                    // chars of the content are added, and the extra chars of the pattern removed.
+                    let mut added = 0;
+                    for (index, c) in content.chars().enumerate() {
+                        transformations.push((c, if index < len { 0 } else { 1 }));
+                        added += 1;
+                    }
+                    if added < len {
+                        // Removed along with the last char replacing one of the pattern, and
+                        // not with a char added by a previous longer content
+                        let removed = len - added;
+                        let last = transformations.iter_mut().rev().find(|(_, ch)| *ch <= 0);
+                        match last {
+                            Some((_, change)) => *change -= removed as isize,
+                            None => initial_offset += removed,
+                        }
+                    }
+                    i += len;
+                }
+                None => {
+                    transformations.push((chars[i], 0));
+                    i += 1;
+                }
+            }
+        }
+
+        normalized.transform(transformations, initial_offset);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizer::Range;
+
+    fn replace(normalizer: &ReplaceMap, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        normalizer.normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn leftmost_longest() {
+        let normalizer = ReplaceMap::new(vec![
+            (":)", "🙂"),
+            (":))", "😀"),
+            ("“", "\""),
+            ("”", "\""),
+            ("approx.", "approximately"),
+            ("a", "A"),
+        ])
+        .unwrap();
+        let n = replace(&normalizer, "“ok” :)) approx. :) ab");
+        assert_eq!(n.get(), "\"ok\" 😀 approximately 🙂 Ab");
+
+        // Each replacement is aligned with its pattern
+        let start = n.get().find('a').unwrap();
+        assert_eq!(
+            n.get_range_original(Range::Normalized(start..start + "approximately".len())),
+            Some("approx.")
+        );
+        assert_eq!(n.get_range_original(Range::Normalized(0..1)), Some("“"));
+    }
+
+    #[test]
+    fn single_pass() {
+        // The content of a rule is never replaced by another rule
+        let normalizer = ReplaceMap::new(vec![("a", "b"), ("b", "c"), ("xyz", "")]).unwrap();
+        assert_eq!(replace(&normalizer, "abxyzxy").get(), "bcxy");
+
+        assert!(ReplaceMap::new(vec![("", "a")]).is_err());
+    }
+
+    #[test]
+    fn empty_after_longer() {
+        // The removed `c` doesn't take over the `z` added by the previous rule
+        let normalizer = ReplaceMap::new(vec![("ab", "xyz"), ("c", "")]).unwrap();
+        let n = replace(&normalizer, "abcd");
+        assert_eq!(n.get(), "xyzd");
+        assert_eq!(n.get_range_original(Range::Normalized(0..1)), Some("a"));
+        assert_eq!(n.get_range_original(Range::Normalized(1..3)), Some("b"));
+        assert_eq!(n.get_range_original(Range::Normalized(3..4)), Some("d"));
+    }
+
+    #[test]
+    fn serialization() {
+        let normalizer = ReplaceMap::new(vec![("b", "B"), ("a", "A")]).unwrap();
+        let normalizer_s = r#"{"type":"ReplaceMap","rules":{"a":"A","b":"B"}}"#;
+        assert_eq!(serde_json::to_string(&normalizer).unwrap(), normalizer_s);
+        let deserialized = serde_json::from_str::<ReplaceMap>(normalizer_s).unwrap();
+        assert_eq!(deserialized, normalizer);
+        assert_eq!(replace(&deserialized, "ab").get(), "AB");
+    }
+}
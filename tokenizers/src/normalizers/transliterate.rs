@@ -0,0 +1,269 @@
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+use unicode_normalization_alignments::char::is_combining_mark;
+use unicode_normalization_alignments::UnicodeNormalization;
+
+/// The scripts that `Transliterate` can map to ASCII
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Script {
+    /// The accented and special Latin letters, like `é`, `ß` or `ł`
+    Latin,
+    Greek,
+    Cyrillic,
+}
+
+impl Script {
+    fn of(c: char) -> Option<Self> {
+        match c as u32 {
+            0x00C0..=0x024F | 0x1E00..=0x1EFF => Some(Script::Latin),
+            0x0370..=0x03FF | 0x1F00..=0x1FFF => Some(Script::Greek),
+            0x0400..=0x052F => Some(Script::Cyrillic),
+            _ => None,
+        }
+    }
+}
+
+/// Transliterates the characters of the given scripts to their closest ASCII forms, using
+/// built-in tables: `Ж` gives `Zh`, `Ω` gives `O` and `é` gives `e`. The accents are removed,
+/// and the characters without a known transliteration are kept as is.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct Transliterate {
+    pub scripts: Vec<Script>,
+}
+
+impl Default for Transliterate {
+    fn default() -> Self {
+        Self::new(vec![Script::Latin, Script::Greek, Script::Cyrillic])
+    }
+}
+
+impl Transliterate {
+    pub fn new(scripts: Vec<Script>) -> Self {
+        Self { scripts }
+    }
+
+    /// The transliteration of the given char, if it belongs to one of our scripts
+    fn transliterate(&self, c: char) -> Option<String> {
+        let script = Script::of(c).filter(|s| self.scripts.contains(s))?;
+        let table = match script {
+            Script::Latin => latin,
+            Script::Greek => greek,
+            Script::Cyrillic => cyrillic,
+        };
+
+        // Some letters with accents have their own transliteration, like `ё`, otherwise we
+        // transliterate the letter without its accents
+        let lowercase = c.to_lowercase().next()?;
+        let ascii = match table(lowercase) {
+            Some(ascii) => ascii,
+            None => {
+                let base = c.to_string().nfd().map(|(c, _)| c).next()?;
+                if base.is_ascii() {
+                    return Some(base.to_string());
+                }
+                table(base.to_lowercase().next()?)?
+            }
+        };
+        if lowercase == c {
+            Some(ascii.to_owned())
+        } else {
+            // Only the first letter of an uppercase one, like `Zh` for `Ж`
+            let mut chars = ascii.chars();
+            Some(match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            })
+        }
+    }
+}
+
+impl Normalizer for Transliterate {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        let mut transformations: Vec<(char, isize)> = vec![];
+        let mut initial_offset = 0;
+        // The combining marks following a transliterated char, or an ASCII letter when
+        // transliterating Latin, are removed like the accents of the composed chars
+        let latin = self.scripts.contains(&Script::Latin);
+        let mut after_letter = false;
+        normalized.for_each(|c| {
+            let transliterated = if after_letter && is_combining_mark(c) {
+                Some(String::new())
+            } else {
+                self.transliterate(c)
+            };
+            after_letter = transliterated.is_some() || (latin && c.is_ascii_alphabetic());
+            match transliterated {
+                // The removed char goes with the last char that replaced one, so that the
+                // added chars of a longer transliteration, like the `h` of `sh`, keep theirs
+                Some(s) if s.is_empty() => {
+                    let last = transformations.iter_mut().rev().find(|(_, ch)| *ch <= 0);
+                    match last {
+                        Some((_, change)) => *change -= 1,
+                        None => initial_offset += 1,
+                    }
+                }
+                Some(s) => s.chars().enumerate().for_each(|(index, c)| {
+                    transformations.push((c, if index > 0 { 1 } else { 0 }));
+                }),
+                None => transformations.push((c, 0)),
+            }
+        });
+        normalized.transform(transformations, initial_offset);
+        Ok(())
+    }
+}
+
+fn latin(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'ø' => "o",
+        'đ' | 'ð' => "d",
+        'ł' => "l",
+        'þ' => "th",
+        'ı' => "i",
+        'ħ' => "h",
+        'ŋ' => "n",
+        'ſ' => "s",
+        _ => return None,
+    })
+}
+
+fn greek(c: char) -> Option<&'static str> {
+    Some(match c {
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' => "i",
+        'θ' => "th",
+        'ι' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' => "o",
+        _ => return None,
+    })
+}
+
+fn cyrillic(c: char) -> Option<&'static str> {
+    Some(match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'э' => "e",
+        'ё' => "yo",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'й' | 'ы' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' | 'ў' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+        'є' => "ye",
+        'ї' => "yi",
+        'ђ' => "dj",
+        'ј' => "j",
+        'љ' => "lj",
+        'њ' => "nj",
+        'ћ' => "c",
+        'џ' => "dz",
+        'ѓ' => "gj",
+        'ќ' => "kj",
+        'ѕ' => "dz",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizer::Range;
+
+    fn transliterate(normalizer: &Transliterate, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        normalizer.normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn scripts() {
+        let normalizer = Transliterate::default();
+        let n = transliterate(&normalizer, "Жуков Объём Ἀθῆναι Straße Łódź Ωmega");
+        assert_eq!(n.get(), "Zhukov Obyom Athinai Strasse Lodz Omega");
+
+        // Only the selected scripts
+        let normalizer = Transliterate::new(vec![Script::Cyrillic]);
+        let n = transliterate(&normalizer, "Жуков Ἀθῆναι café");
+        assert_eq!(n.get(), "Zhukov Ἀθῆναι café");
+
+        // The decomposed accents are removed too, but not the ones of other scripts
+        let normalizer = Transliterate::new(vec![Script::Latin]);
+        assert_eq!(
+            transliterate(&normalizer, "cafe\u{301} 1\u{301}").get(),
+            "cafe 1\u{301}"
+        );
+    }
+
+    #[test]
+    fn alignments() {
+        let n = transliterate(&Transliterate::default(), "Щи съел");
+        assert_eq!(n.get(), "Shchi sel");
+        assert_eq!(n.get_range_original(Range::Normalized(0..4)), Some("Щ"));
+        assert_eq!(n.get_range_original(Range::Normalized(6..9)), Some("съел"));
+        assert_eq!(n.get_range_original(Range::Normalized(7..8)), Some("е"));
+
+        // The signs after a longer transliteration don't take over its added chars
+        let n = transliterate(&Transliterate::default(), "мышь ежъ");
+        assert_eq!(n.get(), "mysh ezh");
+        assert_eq!(n.get_range_original(Range::Normalized(2..3)), Some("ш"));
+        assert_eq!(n.get_range_original(Range::Normalized(3..4)), Some("ш"));
+        assert_eq!(n.get_range_original(Range::Normalized(4..5)), Some(" "));
+        assert_eq!(n.get_range_original(Range::Normalized(7..8)), Some("ж"));
+    }
+
+    #[test]
+    fn serialization() {
+        let normalizer = Transliterate::new(vec![Script::Greek, Script::Cyrillic]);
+        let normalizer_s = r#"{"type":"Transliterate","scripts":["Greek","Cyrillic"]}"#;
+        assert_eq!(serde_json::to_string(&normalizer).unwrap(), normalizer_s);
+        assert_eq!(
+            serde_json::from_str::<Transliterate>(normalizer_s).unwrap(),
+            normalizer
+        );
+    }
+}
@@ -36,6 +36,56 @@ impl Normalizer for NFKC {
     }
 }
 
+/// Applies the full Unicode case folding, which is more thorough than `Lowercase` to compare
+/// strings without their case: `ß` and `ẞ` both give `ss`, and `ς` gives `σ`.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct CaseFold;
+impl Normalizer for CaseFold {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.casefold();
+        Ok(())
+    }
+}
+
+/// Applies the NFKC_Casefold normalization of Unicode: removes the default ignorable code
+/// points, and then applies `NFKC`, `CaseFold`, and `NFKC` again.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct NfkcCasefold;
+impl Normalizer for NfkcCasefold {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized
+            .filter(|c| !is_default_ignorable(c))
+            .nfkc()
+            .casefold()
+            .nfkc();
+        Ok(())
+    }
+}
+
+/// The `Default_Ignorable_Code_Point` property, from `DerivedCoreProperties.txt`
+fn is_default_ignorable(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x00AD
+            | 0x034F
+            | 0x061C
+            | 0x115F..=0x1160
+            | 0x17B4..=0x17B5
+            | 0x180B..=0x180F
+            | 0x200B..=0x200F
+            | 0x202A..=0x202E
+            | 0x2060..=0x206F
+            | 0x3164
+            | 0xFE00..=0xFE0F
+            | 0xFEFF
+            | 0xFFA0
+            | 0xFFF0..=0xFFF8
+            | 0x1BCA0..=0x1BCA3
+            | 0x1D173..=0x1D17A
+            | 0xE0000..=0xE0FFF
+    )
+}
+
 fn do_nmt(normalized: &mut NormalizedString) {
     // Ascii Control characters
     normalized
@@ -81,10 +131,13 @@ impl_serde_unit_struct!(NFCKVisitor, NFKC);
 impl_serde_unit_struct!(NFKDVisitor, NFKD);
 impl_serde_unit_struct!(NFDVisitor, NFD);
 impl_serde_unit_struct!(NMTVisitor, Nmt);
+impl_serde_unit_struct!(CaseFoldVisitor, CaseFold);
+impl_serde_unit_struct!(NfkcCasefoldVisitor, NfkcCasefold);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::normalizer::Range;
 
     #[test]
     fn test_nfkc() {
@@ -100,4 +153,37 @@ mod tests {
 
         assert_eq!(n.alignments_original(), vec![(0, 2), (0, 2), (0, 2)]);
     }
+
+    #[test]
+    fn casefold() {
+        let original = "Straße STRASSE ὈΔΥΣΣΕΎΣ ﬁ ①";
+        let mut n = NormalizedString::from(original);
+        CaseFold.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "strasse strasse ὀδυσσεύσ fi ①");
+        // The `ss` from `ß` is aligned with it
+        assert_eq!(n.get_range_original(Range::Normalized(4..6)), Some("ß"));
+
+        let mut n = NormalizedString::from(original);
+        NfkcCasefold.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "strasse strasse ὀδυσσεύσ fi 1");
+
+        let mut n = NormalizedString::from("Ａ\u{200b}Ｂ\u{ad}ẞ");
+        NfkcCasefold.normalize(&mut n).unwrap();
+        assert_eq!(n.get(), "abss");
+        assert_eq!(n.get_range_original(Range::Normalized(1..2)), Some("Ｂ"));
+    }
+
+    #[test]
+    fn casefold_serialization() {
+        let casefold: crate::normalizers::NormalizerWrapper = CaseFold.into();
+        let casefold_s = r#"{"type":"CaseFold"}"#;
+        assert_eq!(serde_json::to_string(&casefold).unwrap(), casefold_s);
+        let nfkc_cf: crate::normalizers::NormalizerWrapper = NfkcCasefold.into();
+        let nfkc_cf_s = r#"{"type":"NfkcCasefold"}"#;
+        assert_eq!(serde_json::to_string(&nfkc_cf).unwrap(), nfkc_cf_s);
+        assert!(matches!(
+            serde_json::from_str::<crate::normalizers::NormalizerWrapper>(nfkc_cf_s).unwrap(),
+            crate::normalizers::NormalizerWrapper::NfkcCasefold(_)
+        ));
+    }
 }
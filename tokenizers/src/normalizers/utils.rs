@@ -45,3 +45,67 @@ impl Normalizer for Lowercase {
 }
 
 impl_serde_unit_struct!(LowercaseVisitor, Lowercase);
+
+/// Lowercases the input, like `Lowercase`, but also applies the conditional rules of the
+/// Unicode SpecialCasing for the language of the given `locale`: the dotted and dotless `i` in
+/// Turkish (`tr`) and Azerbaijani (`az`), the accents in Lithuanian (`lt`), and the final sigma.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct LocaleLowercase {
+    pub locale: String,
+}
+
+impl LocaleLowercase {
+    pub fn new<S: Into<String>>(locale: S) -> Self {
+        Self {
+            locale: locale.into(),
+        }
+    }
+}
+
+impl Normalizer for LocaleLowercase {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        normalized.lowercase_with_locale(&self.locale);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizer::Range;
+
+    fn lowercase(locale: &str, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        LocaleLowercase::new(locale).normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn locale_lowercase() {
+        // Turkish: `I` gives the dotless `ı`, `İ` and `I` + dot above give `i`
+        let n = lowercase("tr", "KIZ İSTANBUL I\u{307}");
+        assert_eq!(n.get(), "kız istanbul i");
+        assert_eq!(n.get_range_original(Range::Normalized(14..15)), Some("I"));
+        assert_eq!(lowercase("az-AZ", "I").get(), "ı");
+        assert_eq!(lowercase("en", "KIZ İ").get(), "kiz i\u{307}");
+
+        // Lithuanian keeps the dot of `i` under other accents
+        let n = lowercase("lt", "ÌI\u{301}");
+        assert_eq!(n.get(), "i\u{307}\u{300}i\u{307}\u{301}");
+        assert_eq!(n.get_range_original(Range::Normalized(0..5)), Some("Ì"));
+
+        // The final sigma, whatever the language
+        assert_eq!(lowercase("", "ΟΔΥΣΣΕΥΣ. Σ ΑΣ'").get(), "οδυσσευς. σ ας'");
+    }
+
+    #[test]
+    fn locale_lowercase_serialization() {
+        let lowercase: NormalizerWrapper = LocaleLowercase::new("tr").into();
+        let lowercase_s = r#"{"type":"LocaleLowercase","locale":"tr"}"#;
+        assert_eq!(serde_json::to_string(&lowercase).unwrap(), lowercase_s);
+        let deserialized: NormalizerWrapper = serde_json::from_str(lowercase_s).unwrap();
+        assert!(matches!(deserialized, NormalizerWrapper::LocaleLowercase(l) if l.locale == "tr"));
+    }
+}
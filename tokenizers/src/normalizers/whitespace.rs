@@ -0,0 +1,177 @@
+use crate::tokenizer::{NormalizedString, Normalizer, Result};
+use serde::{Deserialize, Serialize};
+use unicode_categories::UnicodeCategories;
+
+fn is_newline(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{B}' | '\u{C}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// Checks whether a character is a control or a format character, which is not whitespace
+fn is_control(c: char) -> bool {
+    !c.is_whitespace() && (c.is_other_control() || c.is_other_format())
+}
+
+/// Cleans the whitespace of the input, while keeping the alignments: the runs of Unicode
+/// whitespace can be collapsed, every kind of whitespace converted to a space, and the
+/// invisible control and format characters removed.
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct CleanWhitespace {
+    /// Whether to collapse each run of whitespace into its first character
+    pub collapse: bool,
+    /// Whether to convert every kind of whitespace, like tabs or no-break spaces, to ` `
+    pub normalize_spaces: bool,
+    /// Whether to remove the control (Cc) and format (Cf) characters, like the zero-width
+    /// spaces and joiners, the bidi controls or the byte order mark
+    pub remove_control: bool,
+    /// Whether to keep the newlines apart from the other whitespace: they are converted to
+    /// `\n` instead of ` `, and a collapsed run that contains one becomes this newline
+    pub keep_newlines: bool,
+}
+
+impl Default for CleanWhitespace {
+    fn default() -> Self {
+        Self {
+            collapse: true,
+            normalize_spaces: true,
+            remove_control: true,
+            keep_newlines: false,
+        }
+    }
+}
+
+impl CleanWhitespace {
+    pub fn new(
+        collapse: bool,
+        normalize_spaces: bool,
+        remove_control: bool,
+        keep_newlines: bool,
+    ) -> Self {
+        Self {
+            collapse,
+            normalize_spaces,
+            remove_control,
+            keep_newlines,
+        }
+    }
+}
+
+impl Normalizer for CleanWhitespace {
+    fn normalize(&self, normalized: &mut NormalizedString) -> Result<()> {
+        let mut transformations: Vec<(char, isize)> = Vec::with_capacity(normalized.len());
+        let mut initial_offset = 0;
+        // Whether the last transformation is a run of whitespace, and if it is a newline
+        let mut run: Option<bool> = None;
+
+        normalized.for_each(|c| {
+            if self.remove_control && is_control(c) {
+                // The removed characters don't end the current run
+                match transformations.last_mut() {
+                    Some((_, change)) => *change -= 1,
+                    None => initial_offset += 1,
+                }
+            } else if c.is_whitespace() {
+                let newline = self.keep_newlines && is_newline(c);
+                let c = match (self.normalize_spaces, newline) {
+                    (false, _) => c,
+                    (true, false) => ' ',
+                    (true, true) => '\n',
+                };
+                match run {
+                    Some(run_newline) if self.collapse && (run_newline || !newline) => {
+                        if let Some((_, change)) = transformations.last_mut() {
+                            *change -= 1;
+                        }
+                    }
+                    Some(_) if self.collapse => {
+                        // The first newline of the run replaces it, so that it keeps its own
+                        // alignment
+                        if let Some((_, change)) = transformations.pop() {
+                            let removed = (1 - change) as usize;
+                            match transformations.last_mut() {
+                                Some((_, change)) => *change -= removed as isize,
+                                None => initial_offset += removed,
+                            }
+                        }
+                        transformations.push((c, 0));
+                        run = Some(true);
+                    }
+                    _ => {
+                        transformations.push((c, 0));
+                        run = Some(newline);
+                    }
+                }
+            } else {
+                transformations.push((c, 0));
+                run = None;
+            }
+        });
+
+        normalized.transform(transformations, initial_offset);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalizer::Range;
+
+    fn clean(normalizer: &CleanWhitespace, s: &str) -> NormalizedString {
+        let mut n = NormalizedString::from(s);
+        normalizer.normalize(&mut n).unwrap();
+        n
+    }
+
+    #[test]
+    fn clean_whitespace() {
+        let s = "\u{feff}Hello\u{a0}\t \u{200b} wo\u{200d}rld\u{202e}\r\n\n\u{3000}!";
+        let n = clean(&CleanWhitespace::default(), s);
+        assert_eq!(n.get(), "Hello world !");
+
+        let world = n.get().find("world").unwrap();
+        assert_eq!(
+            n.get_range_original(Range::Normalized(world..world + 5)),
+            Some("wo\u{200d}rld")
+        );
+        assert_eq!(
+            n.get_range_original(Range::Normalized(world - 1..world)),
+            Some("\u{a0}")
+        );
+
+        let n = clean(&CleanWhitespace::new(false, true, false, false), s);
+        assert_eq!(
+            n.get(),
+            "\u{feff}Hello   \u{200b} wo\u{200d}rld\u{202e}    !"
+        );
+        let n = clean(&CleanWhitespace::new(true, false, true, false), s);
+        assert_eq!(n.get(), "Hello\u{a0}world\r!");
+    }
+
+    #[test]
+    fn keep_newlines() {
+        let normalizer = CleanWhitespace::new(true, true, true, true);
+        let n = clean(&normalizer, "a \t\r\n b\u{2028}c  d");
+        assert_eq!(n.get(), "a\nb\nc d");
+        // The newline is aligned with the first one of its run
+        assert_eq!(n.get_range_original(Range::Normalized(1..2)), Some("\r"));
+
+        let normalizer = CleanWhitespace::new(false, true, true, true);
+        assert_eq!(clean(&normalizer, "a \r\nb").get(), "a \n\nb");
+    }
+
+    #[test]
+    fn serialization() {
+        let normalizer = CleanWhitespace::default();
+        let normalizer_s = r#"{"type":"CleanWhitespace","collapse":true,"normalize_spaces":true,"remove_control":true,"keep_newlines":false}"#;
+        assert_eq!(serde_json::to_string(&normalizer).unwrap(), normalizer_s);
+        assert_eq!(
+            serde_json::from_str::<CleanWhitespace>(normalizer_s).unwrap(),
+            normalizer
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+
+use crate::tokenizer::{normalizer::Range, PreTokenizedString, PreTokenizer, Result};
+
+/// The operators made of many chars that are kept together, the longest ones first
+const OPERATORS: &[&str] = &[
+    "<<=", ">>=", "**=", "//=", "===", "!==", "<=>", "...", "->", "=>", "::", "==", "!=", "<=",
+    ">=", "&&", "||", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<", ">>", "**",
+    "//", "..", "?.", "??", ":=", "<-",
+];
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_newline(c: char) -> bool {
+    c == '\n' || c == '\r'
+}
+
+/// Whether an identifier should be split between `prev` and `c`, with the char following `c`
+fn is_boundary(prev: char, c: char, next: Option<char>) -> bool {
+    // snake_case, and the letters and digits, like `int32` or `utf8`
+    (prev == '_') != (c == '_')
+        || prev.is_numeric() != c.is_numeric()
+        // camelCase and PascalCase
+        || (prev.is_lowercase() && c.is_uppercase())
+        // The last uppercase letter of an acronym starts the next word, like in `HTTPResponse`
+        || (prev.is_uppercase() && c.is_uppercase() && matches!(next, Some(n) if n.is_lowercase()))
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+/// Pre tokenizes source code. The identifiers are split on their case changes, underscores and
+/// digits, like `parseHTTPResponse` in `parse`, `HTTP`, `Response`, or `max_input_chars` in
+/// `max`, `_`, `input`, `_`, `chars`. The operators like `->`, `::` or `!=` are kept together,
+/// the number literals like `0x1F` or `3.14` too, and every other punctuation is on its own, so
+/// `kebab-case` gives `kebab`, `-`, `case`. The whitespace is removed, except for the
+/// indentation if keep_indentation is set to true: each run of spaces and tabs at the start of
+/// a line is then a pre-token.
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct CodeIdentifiers {
+    pub split_identifiers: bool,
+    pub keep_indentation: bool,
+}
+
+impl CodeIdentifiers {
+    pub fn new(split_identifiers: bool, keep_indentation: bool) -> Self {
+        Self {
+            split_identifiers,
+            keep_indentation,
+        }
+    }
+
+    /// The byte ranges of the pre-tokens of the given code
+    fn split(&self, code: &str) -> Vec<std::ops::Range<usize>> {
+        let chars = code.chars().collect::<Vec<_>>();
+        let mut offsets = code.char_indices().map(|(o, _)| o).collect::<Vec<_>>();
+        offsets.push(code.len());
+
+        let mut ranges = vec![];
+        let mut line_start = true;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+            if is_newline(c) {
+                line_start = true;
+                i += 1;
+                continue;
+            } else if c.is_whitespace() {
+                while i < chars.len() && chars[i].is_whitespace() && !is_newline(chars[i]) {
+                    i += 1;
+                }
+                if self.keep_indentation && line_start {
+                    ranges.push(offsets[start]..offsets[i]);
+                }
+            } else if c.is_ascii_digit() {
+                // The number literals, with their decimal part
+                while i < chars.len()
+                    && (is_identifier(chars[i])
+                        || (chars[i] == '.'
+                            && matches!(chars.get(i + 1), Some(d) if d.is_ascii_digit())))
+                {
+                    i += 1;
+                }
+                ranges.push(offsets[start]..offsets[i]);
+            } else if is_identifier(c) {
+                let mut part = start;
+                i += 1;
+                while i < chars.len() && is_identifier(chars[i]) {
+                    if self.split_identifiers
+                        && is_boundary(chars[i - 1], chars[i], chars.get(i + 1).copied())
+                    {
+                        ranges.push(offsets[part]..offsets[i]);
+                        part = i;
+                    }
+                    i += 1;
+                }
+                ranges.push(offsets[part]..offsets[i]);
+            } else {
+                let rest = &code[offsets[i]..];
+                // The operators are ASCII, so their length in chars is their length in bytes
+                i += OPERATORS
+                    .iter()
+                    .find(|op| rest.starts_with(*op))
+                    .map_or(1, |op| op.len());
+                ranges.push(offsets[start]..offsets[i]);
+            }
+            line_start = false;
+        }
+        ranges
+    }
+}
+
+impl Default for CodeIdentifiers {
+    fn default() -> Self {
+        Self::new(true, false)
+    }
+}
+
+impl PreTokenizer for CodeIdentifiers {
+    fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> Result<()> {
+        pretokenized.split(|_, normalized| {
+            Ok(self
+                .split(normalized.get())
+                .into_iter()
+                .map(|range| {
+                    normalized
+                        .slice(Range::Normalized(range))
+                        .expect("NormalizedString bad split")
+                })
+                .collect::<Vec<_>>())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OffsetReferential, OffsetType};
+
+    fn splits(pretok: &CodeIdentifiers, s: &str) -> Vec<(String, (usize, usize))> {
+        let mut pretokenized = PreTokenizedString::from(s);
+        pretok.pre_tokenize(&mut pretokenized).unwrap();
+        pretokenized
+            .get_splits(OffsetReferential::Original, OffsetType::Byte)
+            .into_iter()
+            .map(|(s, o, _)| (s.to_owned(), o))
+            .collect()
+    }
+
+    fn tokens(pretok: &CodeIdentifiers, s: &str) -> Vec<String> {
+        splits(pretok, s).into_iter().map(|(s, _)| s).collect()
+    }
+
+    #[test]
+    fn identifiers() {
+        let pretok = CodeIdentifiers::default();
+        assert_eq!(
+            splits(&pretok, "parseHTTPResponse(max_input_chars)"),
+            vec![
+                ("parse".into(), (0, 5)),
+                ("HTTP".into(), (5, 9)),
+                ("Response".into(), (9, 17)),
+                ("(".into(), (17, 18)),
+                ("max".into(), (18, 21)),
+                ("_".into(), (21, 22)),
+                ("input".into(), (22, 27)),
+                ("_".into(), (27, 28)),
+                ("chars".into(), (28, 33)),
+                (")".into(), (33, 34)),
+            ]
+        );
+        assert_eq!(
+            tokens(&pretok, "__init__ IOError utf8 kebab-case étéCourt"),
+            vec![
+                "__", "init", "__", "IO", "Error", "utf", "8", "kebab", "-", "case", "été", "Court"
+            ]
+        );
+
+        let pretok = CodeIdentifiers::new(false, false);
+        assert_eq!(
+            tokens(&pretok, "parseHTTPResponse(max_input_chars)"),
+            vec!["parseHTTPResponse", "(", "max_input_chars", ")"]
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let pretok = CodeIdentifiers::default();
+        assert_eq!(
+            tokens(
+                &pretok,
+                "fn f(x: &u8) -> bool { x != 0x1F && y::z <<= 3.14; }"
+            ),
+            vec![
+                "fn", "f", "(", "x", ":", "&", "u", "8", ")", "->", "bool", "{", "x", "!=", "0x1F",
+                "&&", "y", "::", "z", "<<=", "3.14", ";", "}"
+            ]
+        );
+    }
+
+    #[test]
+    fn indentation() {
+        let code = "def f():\n    if x:\n\treturn  1\n";
+        let pretok = CodeIdentifiers::new(true, true);
+        assert_eq!(
+            splits(&pretok, code),
+            vec![
+                ("def".into(), (0, 3)),
+                ("f".into(), (4, 5)),
+                ("(".into(), (5, 6)),
+                (")".into(), (6, 7)),
+                (":".into(), (7, 8)),
+                ("    ".into(), (9, 13)),
+                ("if".into(), (13, 15)),
+                ("x".into(), (16, 17)),
+                (":".into(), (17, 18)),
+                ("\t".into(), (19, 20)),
+                ("return".into(), (20, 26)),
+                ("1".into(), (28, 29)),
+            ]
+        );
+
+        let pretok = CodeIdentifiers::default();
+        assert_eq!(
+            tokens(&pretok, code),
+            vec!["def", "f", "(", ")", ":", "if", "x", ":", "return", "1"]
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let pretok = CodeIdentifiers::new(true, true);
+        let pretok_s =
+            r#"{"type":"CodeIdentifiers","split_identifiers":true,"keep_indentation":true}"#;
+        assert_eq!(serde_json::to_string(&pretok).unwrap(), pretok_s);
+        let deserialized: CodeIdentifiers = serde_json::from_str(pretok_s).unwrap();
+        assert!(deserialized.split_identifiers && deserialized.keep_indentation);
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::pre_tokenizers::unicode_scripts::scripts::{self, get_script};
+use crate::tokenizer::{normalizer::Range, PreTokenizedString, PreTokenizer, Result};
+
+/// The scripts written without spaces that `DictionarySplit` can segment
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Script {
+    /// The Chinese and Japanese text: Han, along with Hiragana and Katakana
+    Han,
+    Thai,
+}
+
+impl Script {
+    fn contains(self, c: char) -> bool {
+        match (self, get_script(c)) {
+            (Script::Han, scripts::Script::Han)
+            | (Script::Han, scripts::Script::Hiragana)
+            | (Script::Han, scripts::Script::Katakana)
+            | (Script::Thai, scripts::Script::Thai) => true,
+            // The prolonged sound mark of Katakana is Common
+            (Script::Han, _) => c == '\u{30FC}',
+            _ => false,
+        }
+    }
+}
+
+/// How `DictionarySplit` chooses between the possible segmentations
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Segmentation {
+    /// The segmentation with the highest probability, given the frequencies of its words
+    MaxProbability,
+    /// The longest word of the dictionary at each position, from left to right
+    MaxMatch,
+}
+
+/// We use this custom deserializer to compute the probabilities of `DictionarySplit`
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+struct DictionarySplitDeserializer {
+    script: Script,
+    segmentation: Segmentation,
+    words: BTreeMap<String, u64>,
+}
+
+impl From<DictionarySplitDeserializer> for DictionarySplit {
+    fn from(v: DictionarySplitDeserializer) -> Self {
+        DictionarySplit::new(v.words, v.script, v.segmentation)
+    }
+}
+
+/// Segments the runs of a script written without spaces, like Chinese, Japanese or Thai, into
+/// the words of a dictionary. The chars that are not part of any word of the dictionary are
+/// split on their own, and the text of the other scripts is left as is.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", from = "DictionarySplitDeserializer")]
+pub struct DictionarySplit {
+    script: Script,
+    segmentation: Segmentation,
+    /// The frequency of each word
+    words: BTreeMap<String, u64>,
+    #[serde(skip)]
+    log_probs: HashMap<String, f64>,
+    #[serde(skip)]
+    unk_log_prob: f64,
+    /// The number of chars of the longest word
+    #[serde(skip)]
+    max_len: usize,
+}
+
+impl DictionarySplit {
+    pub fn new<I, W>(words: I, script: Script, segmentation: Segmentation) -> Self
+    where
+        I: IntoIterator<Item = (W, u64)>,
+        W: Into<String>,
+    {
+        // A word with a frequency of 0 counts as seen once
+        let words = words
+            .into_iter()
+            .map(|(word, freq)| (word.into(), freq.max(1)))
+            .filter(|(word, _)| !word.is_empty())
+            .collect::<BTreeMap<String, u64>>();
+        // Summed as floats, since the frequencies can be any `u64`
+        let total = words
+            .values()
+            .map(|freq| *freq as f64)
+            .sum::<f64>()
+            .max(1.0);
+        let log_probs = words
+            .iter()
+            .map(|(word, freq)| (word.clone(), (*freq as f64 / total).ln()))
+            .collect();
+        let max_len = words.keys().map(|w| w.chars().count()).max().unwrap_or(0);
+
+        Self {
+            script,
+            segmentation,
+            words,
+            log_probs,
+            // The unknown chars are ten times less probable than the rarest word
+            unk_log_prob: (0.1 / total).ln(),
+            max_len,
+        }
+    }
+
+    /// Load the dictionary from a file with a word on each line, optionally followed by its
+    /// frequency after a space or a tab. Anything after the frequency is ignored, and the
+    /// words without frequency count as seen once.
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        script: Script,
+        segmentation: Segmentation,
+    ) -> Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        let mut words = vec![];
+        for (i, line) in file.lines().enumerate() {
+            let line = line?;
+            let mut fields = line.split_whitespace();
+            let word = match fields.next() {
+                Some(word) => word,
+                None => continue,
+            };
+            let freq = match fields.next() {
+                Some(freq) => freq.parse::<u64>().map_err(|_| {
+                    format!(
+                        "Invalid frequency `{}` on line {} of the dictionary",
+                        freq,
+                        i + 1
+                    )
+                })?,
+                None => 1,
+            };
+            words.push((word.to_owned(), freq));
+        }
+        Ok(Self::new(words, script, segmentation))
+    }
+
+    pub fn get_script(&self) -> Script {
+        self.script
+    }
+
+    pub fn get_segmentation(&self) -> Segmentation {
+        self.segmentation
+    }
+
+    pub fn get_words(&self) -> &BTreeMap<String, u64> {
+        &self.words
+    }
+
+    /// Segment the given run, with the byte offset of each of its chars, and its byte length at
+    /// the end. Returns the number of chars of each word.
+    fn segment(&self, run: &str, offsets: &[usize]) -> Vec<usize> {
+        let n = offsets.len() - 1;
+        let word = |start: usize, len: usize| &run[offsets[start]..offsets[start + len]];
+
+        match self.segmentation {
+            Segmentation::MaxMatch => {
+                let mut lens = vec![];
+                let mut start = 0;
+                while start < n {
+                    let len = (2..=self.max_len.min(n - start))
+                        .rev()
+                        .find(|len| self.log_probs.contains_key(word(start, *len)))
+                        .unwrap_or(1);
+                    lens.push(len);
+                    start += len;
+                }
+                lens
+            }
+            Segmentation::MaxProbability => {
+                // The best log probability of the first chars, with the length of its last word
+                let mut best = vec![(f64::NEG_INFINITY, 0); n + 1];
+                best[0].0 = 0.0;
+                for start in 0..n {
+                    for len in 1..=self.max_len.max(1).min(n - start) {
+                        let log_prob = match self.log_probs.get(word(start, len)) {
+                            Some(log_prob) => *log_prob,
+                            None if len == 1 => self.unk_log_prob,
+                            None => continue,
+                        };
+                        let log_prob = best[start].0 + log_prob;
+                        if log_prob > best[start + len].0 {
+                            best[start + len] = (log_prob, len);
+                        }
+                    }
+                }
+
+                let mut lens = vec![];
+                let mut end = n;
+                while end > 0 {
+                    let len = best[end].1;
+                    lens.push(len);
+                    end -= len;
+                }
+                lens.reverse();
+                lens
+            }
+        }
+    }
+}
+
+impl PreTokenizer for DictionarySplit {
+    fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> Result<()> {
+        pretokenized.split(|_, normalized| {
+            let s = normalized.get();
+
+            // The byte ranges of each split, with whether it is a run of our script
+            let mut parts: Vec<(usize, usize, bool)> = vec![];
+            for (offset, c) in s.char_indices() {
+                let in_script = self.script.contains(c);
+                match parts.last_mut() {
+                    Some((_, end, last)) if *last == in_script => *end = offset + c.len_utf8(),
+                    _ => parts.push((offset, offset + c.len_utf8(), in_script)),
+                }
+            }
+
+            let mut ranges = vec![];
+            for (start, end, in_script) in parts {
+                if !in_script {
+                    ranges.push(start..end);
+                    continue;
+                }
+                let run = &s[start..end];
+                let offsets = run
+                    .char_indices()
+                    .map(|(offset, _)| offset)
+                    .chain(std::iter::once(run.len()))
+                    .collect::<Vec<_>>();
+                let mut index = 0;
+                for len in self.segment(run, &offsets) {
+                    ranges.push(start + offsets[index]..start + offsets[index + len]);
+                    index += len;
+                }
+            }
+
+            Ok(ranges
+                .into_iter()
+                .map(|range| {
+                    normalized
+                        .slice(Range::Normalized(range))
+                        .expect("NormalizedString bad split")
+                })
+                .collect::<Vec<_>>())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OffsetReferential, OffsetType};
+    use std::io::Write;
+
+    fn splits(pretok: &DictionarySplit, s: &str) -> Vec<(String, (usize, usize))> {
+        let mut pretokenized = PreTokenizedString::from(s);
+        pretok.pre_tokenize(&mut pretokenized).unwrap();
+        pretokenized
+            .get_splits(OffsetReferential::Original, OffsetType::Byte)
+            .into_iter()
+            .map(|(s, o, _)| (s.to_owned(), o))
+            .collect()
+    }
+
+    fn words() -> Vec<(&'static str, u64)> {
+        vec![
+            ("研究", 100),
+            ("研究生", 20),
+            ("生命", 80),
+            ("起源", 50),
+            ("的", 500),
+        ]
+    }
+
+    #[test]
+    fn max_probability() {
+        let pretok = DictionarySplit::new(words(), Script::Han, Segmentation::MaxProbability);
+        assert_eq!(
+            splits(&pretok, "研究生命的起源 (ok)"),
+            vec![
+                ("研究".into(), (0, 6)),
+                ("生命".into(), (6, 12)),
+                ("的".into(), (12, 15)),
+                ("起源".into(), (15, 21)),
+                (" (ok)".into(), (21, 26)),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_match() {
+        let pretok = DictionarySplit::new(words(), Script::Han, Segmentation::MaxMatch);
+        assert_eq!(
+            splits(&pretok, "a研究生命x"),
+            vec![
+                ("a".into(), (0, 1)),
+                ("研究生".into(), (1, 10)),
+                ("命".into(), (10, 13)),
+                ("x".into(), (13, 14)),
+            ]
+        );
+    }
+
+    #[test]
+    fn huge_frequencies() {
+        let words = vec![("研究", u64::MAX), ("生命", 2)];
+        let pretok = DictionarySplit::new(words, Script::Han, Segmentation::MaxProbability);
+        assert_eq!(
+            splits(&pretok, "研究生命"),
+            vec![("研究".into(), (0, 6)), ("生命".into(), (6, 12))]
+        );
+    }
+
+    #[test]
+    fn from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "สวัสดี 10\nครับ\t5 x\n\nโลก").unwrap();
+        let pretok =
+            DictionarySplit::from_file(file.path(), Script::Thai, Segmentation::MaxProbability)
+                .unwrap();
+        assert_eq!(pretok.get_words().get("ครับ"), Some(&5));
+        assert_eq!(pretok.get_words().get("โลก"), Some(&1));
+        assert_eq!(
+            splits(&pretok, "สวัสดีครับ"),
+            vec![("สวัสดี".into(), (0, 18)), ("ครับ".into(), (18, 30))]
+        );
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "โลก many").unwrap();
+        assert!(
+            DictionarySplit::from_file(file.path(), Script::Thai, Segmentation::MaxMatch).is_err()
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let pretok = DictionarySplit::new(
+            vec![("生命", 2), ("研究", 1)],
+            Script::Han,
+            Segmentation::MaxMatch,
+        );
+        let pretok_s = r#"{"type":"DictionarySplit","script":"Han","segmentation":"MaxMatch","words":{"生命":2,"研究":1}}"#;
+        assert_eq!(serde_json::to_string(&pretok).unwrap(), pretok_s);
+        let deserialized: DictionarySplit = serde_json::from_str(pretok_s).unwrap();
+        assert_eq!(deserialized.get_words(), pretok.get_words());
+        assert_eq!(
+            splits(&deserialized, "研究生命"),
+            vec![("研究".into(), (0, 6)), ("生命".into(), (6, 12))]
+        );
+    }
+}
@@ -1,111 +1,123 @@
 pub mod bert;
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 pub mod byte_level;
-#[cfg(not(feature = "bert"))]
+pub mod code_identifiers;
 pub mod delimiter;
-#[cfg(not(feature = "bert"))]
+pub mod dictionary;
 pub mod digits;
-#[cfg(not(feature = "bert"))]
 pub mod metaspace;
-#[cfg(not(feature = "bert"))]
 pub mod punctuation;
-#[cfg(not(feature = "bert"))]
 pub mod sequence;
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 pub mod split;
-#[cfg(not(feature = "bert"))]
 pub mod unicode_scripts;
-#[cfg(not(feature = "bert"))]
+pub mod unicode_words;
 pub mod whitespace;
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
+use crate::pre_tokenizers::{byte_level::ByteLevel, split::Split};
 use crate::pre_tokenizers::{
-    byte_level::ByteLevel,
+    code_identifiers::CodeIdentifiers,
     delimiter::CharDelimiterSplit,
+    dictionary::DictionarySplit,
     digits::Digits,
     metaspace::Metaspace,
     punctuation::Punctuation,
     sequence::Sequence,
-    split::Split,
     unicode_scripts::UnicodeScripts,
+    unicode_words::UnicodeWords,
     whitespace::{Whitespace, WhitespaceSplit},
 };
 use crate::{pre_tokenizers::bert::BertPreTokenizer, PreTokenizedString, PreTokenizer};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum PreTokenizerWrapper {
     BertPreTokenizer(BertPreTokenizer),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     ByteLevel(ByteLevel),
-    #[cfg(not(feature = "bert"))]
     Delimiter(CharDelimiterSplit),
-    #[cfg(not(feature = "bert"))]
     Metaspace(Metaspace),
-    #[cfg(not(feature = "bert"))]
     Whitespace(Whitespace),
-    #[cfg(not(feature = "bert"))]
     Sequence(Sequence),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     Split(Split),
-    #[cfg(not(feature = "bert"))]
     Punctuation(Punctuation),
-    #[cfg(not(feature = "bert"))]
     WhitespaceSplit(WhitespaceSplit),
-    #[cfg(not(feature = "bert"))]
     Digits(Digits),
-    #[cfg(not(feature = "bert"))]
     UnicodeScripts(UnicodeScripts),
+    UnicodeWords(UnicodeWords),
+    DictionarySplit(DictionarySplit),
+    CodeIdentifiers(CodeIdentifiers),
 }
 
+/// Find the features required by the given pre-tokenizer `type`, if it has been disabled
+#[allow(clippy::match_single_binding)]
+fn disabled_feature(ty: &str) -> Option<&'static str> {
+    match ty {
+        #[cfg(not(any(feature = "onig", feature = "fancy-regex")))]
+        "ByteLevel" | "Split" => Some("`onig` or `fancy-regex`"),
+        _ => None,
+    }
+}
+
+impl_serde_wrapper!(PreTokenizerWrapper, disabled_feature, {
+    "BertPreTokenizer" => BertPreTokenizer,
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "ByteLevel" => ByteLevel,
+    "CharDelimiterSplit" => Delimiter,
+    "Metaspace" => Metaspace,
+    "Whitespace" => Whitespace,
+    "Sequence" => Sequence,
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "Split" => Split,
+    "Punctuation" => Punctuation,
+    "WhitespaceSplit" => WhitespaceSplit,
+    "Digits" => Digits,
+    "UnicodeScripts" => UnicodeScripts,
+    "UnicodeWords" => UnicodeWords,
+    "DictionarySplit" => DictionarySplit,
+    "CodeIdentifiers" => CodeIdentifiers,
+});
+
 impl PreTokenizer for PreTokenizerWrapper {
     fn pre_tokenize(&self, normalized: &mut PreTokenizedString) -> crate::Result<()> {
         match self {
             PreTokenizerWrapper::BertPreTokenizer(bpt) => bpt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PreTokenizerWrapper::ByteLevel(bpt) => bpt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Delimiter(dpt) => dpt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Metaspace(mspt) => mspt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Whitespace(wspt) => wspt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Punctuation(tok) => tok.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Sequence(tok) => tok.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PreTokenizerWrapper::Split(tok) => tok.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::WhitespaceSplit(wspt) => wspt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::Digits(wspt) => wspt.pre_tokenize(normalized),
-            #[cfg(not(feature = "bert"))]
             PreTokenizerWrapper::UnicodeScripts(us) => us.pre_tokenize(normalized),
+            PreTokenizerWrapper::UnicodeWords(uw) => uw.pre_tokenize(normalized),
+            PreTokenizerWrapper::DictionarySplit(ds) => ds.pre_tokenize(normalized),
+            PreTokenizerWrapper::CodeIdentifiers(ci) => ci.pre_tokenize(normalized),
         }
     }
 }
 
 impl_enum_from!(BertPreTokenizer, PreTokenizerWrapper, BertPreTokenizer);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(ByteLevel, PreTokenizerWrapper, ByteLevel);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(CharDelimiterSplit, PreTokenizerWrapper, Delimiter);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Whitespace, PreTokenizerWrapper, Whitespace);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Punctuation, PreTokenizerWrapper, Punctuation);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Sequence, PreTokenizerWrapper, Sequence);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(Split, PreTokenizerWrapper, Split);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Metaspace, PreTokenizerWrapper, Metaspace);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(WhitespaceSplit, PreTokenizerWrapper, WhitespaceSplit);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(Digits, PreTokenizerWrapper, Digits);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(UnicodeScripts, PreTokenizerWrapper, UnicodeScripts);
+impl_enum_from!(UnicodeWords, PreTokenizerWrapper, UnicodeWords);
+impl_enum_from!(DictionarySplit, PreTokenizerWrapper, DictionarySplit);
+impl_enum_from!(CodeIdentifiers, PreTokenizerWrapper, CodeIdentifiers);
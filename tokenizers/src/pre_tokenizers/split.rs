@@ -1,4 +1,4 @@
-use onig::Regex;
+use crate::utils::SysRegex;
 use serde::{Deserialize, Serialize};
 
 use crate::tokenizer::{
@@ -35,7 +35,7 @@ struct SplitDeserializer {
 }
 
 impl std::convert::TryFrom<SplitDeserializer> for Split {
-    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Error = crate::Error;
 
     fn try_from(v: SplitDeserializer) -> Result<Self> {
         Split::new(v.pattern, v.behavior, v.invert)
@@ -47,7 +47,7 @@ impl std::convert::TryFrom<SplitDeserializer> for Split {
 pub struct Split {
     pattern: SplitPattern,
     #[serde(skip)]
-    regex: Regex,
+    regex: SysRegex,
     behavior: SplitDelimiterBehavior,
     invert: bool,
 }
@@ -74,8 +74,8 @@ impl Split {
     ) -> Result<Self> {
         let pattern: SplitPattern = pattern.into();
         let regex = match &pattern {
-            SplitPattern::String(s) => Regex::new(&regex::escape(s))?,
-            SplitPattern::Regex(r) => Regex::new(r)?,
+            SplitPattern::String(s) => SysRegex::new(&regex::escape(s))?,
+            SplitPattern::Regex(r) => SysRegex::new(r)?,
         };
 
         Ok(Self {
@@ -1,5 +1,5 @@
 mod pre_tokenizer;
-mod scripts;
+pub(crate) mod scripts;
 
 // Re-export the PreTokenizer
 pub use pre_tokenizer::UnicodeScripts;
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::tokenizer::{normalizer::Range, PreTokenizedString, PreTokenizer, Result};
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+/// Splits on the word boundaries of the Unicode Standard Annex #29. The words keep their
+/// apostrophes and the separators of their numbers, like `can't` or `3.14`, and each
+/// punctuation is on its own. If remove_whitespace is set to true, the segments that only
+/// contain whitespace are removed.
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub struct UnicodeWords {
+    pub remove_whitespace: bool,
+}
+
+impl UnicodeWords {
+    pub fn new(remove_whitespace: bool) -> Self {
+        Self { remove_whitespace }
+    }
+}
+
+impl Default for UnicodeWords {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl PreTokenizer for UnicodeWords {
+    fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> Result<()> {
+        pretokenized.split(|_, normalized| {
+            Ok(normalized
+                .get()
+                .split_word_bound_indices()
+                .filter(|(_, word)| !(self.remove_whitespace && word.trim().is_empty()))
+                .map(|(start, word)| {
+                    normalized
+                        .slice(Range::Normalized(start..start + word.len()))
+                        .expect("NormalizedString bad split")
+                })
+                .collect::<Vec<_>>())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OffsetReferential, OffsetType};
+
+    fn splits(pretok: &UnicodeWords, s: &str) -> Vec<(String, (usize, usize))> {
+        let mut pretokenized = PreTokenizedString::from(s);
+        pretok.pre_tokenize(&mut pretokenized).unwrap();
+        pretokenized
+            .get_splits(OffsetReferential::Original, OffsetType::Byte)
+            .into_iter()
+            .map(|(s, o, _)| (s.to_owned(), o))
+            .collect()
+    }
+
+    #[test]
+    fn words() {
+        let pretok = UnicodeWords::default();
+        assert_eq!(
+            splits(&pretok, "I can't pay $3.14, l'été\u{a0}!"),
+            vec![
+                ("I".into(), (0, 1)),
+                ("can't".into(), (2, 7)),
+                ("pay".into(), (8, 11)),
+                ("$".into(), (12, 13)),
+                ("3.14".into(), (13, 17)),
+                (",".into(), (17, 18)),
+                ("l'été".into(), (19, 26)),
+                ("!".into(), (28, 29)),
+            ]
+        );
+
+        // Each ideograph is its own word
+        assert_eq!(
+            splits(&pretok, "東京 ok"),
+            vec![
+                ("東".into(), (0, 3)),
+                ("京".into(), (3, 6)),
+                ("ok".into(), (7, 9))
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_whitespace() {
+        let pretok = UnicodeWords::new(false);
+        assert_eq!(
+            splits(&pretok, "Hey  you"),
+            vec![
+                ("Hey".into(), (0, 3)),
+                ("  ".into(), (3, 5)),
+                ("you".into(), (5, 8))
+            ]
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let pretok = UnicodeWords::new(false);
+        let pretok_s = r#"{"type":"UnicodeWords","remove_whitespace":false}"#;
+        assert_eq!(serde_json::to_string(&pretok).unwrap(), pretok_s);
+        let deserialized: UnicodeWords = serde_json::from_str(pretok_s).unwrap();
+        assert!(!deserialized.remove_whitespace);
+    }
+}
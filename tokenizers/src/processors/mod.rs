@@ -1,44 +1,60 @@
 pub mod bert;
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 pub mod roberta;
-#[cfg(not(feature = "bert"))]
 pub mod template;
 
 // Re-export these as processors
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 pub use super::pre_tokenizers::byte_level;
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
+use crate::{pre_tokenizers::byte_level::ByteLevel, processors::roberta::RobertaProcessing};
 use crate::{
-    pre_tokenizers::byte_level::ByteLevel,
-    processors::{roberta::RobertaProcessing, template::TemplateProcessing},
+    processors::{bert::BertProcessing, template::TemplateProcessing},
+    Encoding, PostProcessor, Result,
 };
-use crate::{processors::bert::BertProcessing, Encoding, PostProcessor, Result};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum PostProcessorWrapper {
     // Roberta must be before Bert for deserialization (serde does not validate tags)
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     Roberta(RobertaProcessing),
     Bert(BertProcessing),
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     ByteLevel(ByteLevel),
-    #[cfg(not(feature = "bert"))]
     Template(TemplateProcessing),
 }
 
+/// Find the features required by the given post-processor `type`, if it has been disabled
+#[allow(clippy::match_single_binding)]
+fn disabled_feature(ty: &str) -> Option<&'static str> {
+    match ty {
+        #[cfg(not(any(feature = "onig", feature = "fancy-regex")))]
+        "RobertaProcessing" | "ByteLevel" => Some("`onig` or `fancy-regex`"),
+        _ => None,
+    }
+}
+
+impl_serde_wrapper!(PostProcessorWrapper, disabled_feature, {
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "RobertaProcessing" => Roberta,
+    "BertProcessing" => Bert,
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
+    "ByteLevel" => ByteLevel,
+    "TemplateProcessing" => Template,
+});
+
 impl PostProcessor for PostProcessorWrapper {
     fn added_tokens(&self, is_pair: bool) -> usize {
         match self {
             PostProcessorWrapper::Bert(bert) => bert.added_tokens(is_pair),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PostProcessorWrapper::ByteLevel(bl) => bl.added_tokens(is_pair),
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PostProcessorWrapper::Roberta(roberta) => roberta.added_tokens(is_pair),
-            #[cfg(not(feature = "bert"))]
             PostProcessorWrapper::Template(template) => template.added_tokens(is_pair),
         }
     }
@@ -53,15 +69,14 @@ impl PostProcessor for PostProcessorWrapper {
             PostProcessorWrapper::Bert(bert) => {
                 bert.process(encoding, pair_encoding, add_special_tokens)
             }
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PostProcessorWrapper::ByteLevel(bl) => {
                 bl.process(encoding, pair_encoding, add_special_tokens)
             }
-            #[cfg(not(feature = "bert"))]
+            #[cfg(any(feature = "onig", feature = "fancy-regex"))]
             PostProcessorWrapper::Roberta(roberta) => {
                 roberta.process(encoding, pair_encoding, add_special_tokens)
             }
-            #[cfg(not(feature = "bert"))]
             PostProcessorWrapper::Template(template) => {
                 template.process(encoding, pair_encoding, add_special_tokens)
             }
@@ -70,11 +85,10 @@ impl PostProcessor for PostProcessorWrapper {
 }
 
 impl_enum_from!(BertProcessing, PostProcessorWrapper, Bert);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(ByteLevel, PostProcessorWrapper, ByteLevel);
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "onig", feature = "fancy-regex"))]
 impl_enum_from!(RobertaProcessing, PostProcessorWrapper, Roberta);
-#[cfg(not(feature = "bert"))]
 impl_enum_from!(TemplateProcessing, PostProcessorWrapper, Template);
 
 #[cfg(test)]
@@ -83,7 +97,7 @@ mod tests {
 
     #[test]
     fn deserialize_bert_roberta_correctly() {
-        #[cfg(not(feature = "bert"))]
+        #[cfg(any(feature = "onig", feature = "fancy-regex"))]
         {
             let roberta = RobertaProcessing::default();
             let roberta_r = r#"{
@@ -109,4 +123,26 @@ mod tests {
             PostProcessorWrapper::Bert(bert)
         );
     }
+
+    #[test]
+    fn deserialize_reports_variant_errors() {
+        let missing_cls = r#"{"type":"BertProcessing","sep":["[SEP]",102]}"#;
+        let err = serde_json::from_str::<PostProcessorWrapper>(missing_cls).unwrap_err();
+        assert_eq!(err.to_string(), "missing field `cls`");
+    }
+
+    #[test]
+    fn deserialize_disabled_processor() {
+        let byte_level = r#"{"type":"ByteLevel","add_prefix_space":true,"trim_offsets":true}"#;
+        let result = serde_json::from_str::<PostProcessorWrapper>(byte_level);
+        if cfg!(any(feature = "onig", feature = "fancy-regex")) {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "`ByteLevel` is not available, tokenizers must be compiled with the `onig` or \
+                 `fancy-regex` feature"
+            );
+        }
+    }
 }
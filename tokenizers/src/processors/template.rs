@@ -55,7 +55,7 @@
 //!
 //! [`TemplateProcessing`]: struct.TemplateProcessing.html
 //!
-use crate::{Encoding, PostProcessor, Result};
+use crate::{Encoding, Error, PostProcessor, Result};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -226,7 +226,9 @@ impl From<(u32, &str)> for SpecialToken {
 impl SpecialToken {
     pub fn new(id: String, ids: Vec<u32>, tokens: Vec<String>) -> Result<Self> {
         if ids.len() != tokens.len() {
-            Err("SpecialToken: ids and tokens must be of the same length".into())
+            Err(Error::configuration(
+                "SpecialToken: ids and tokens must be of the same length",
+            ))
         } else {
             Ok(Self { id, ids, tokens })
         }
@@ -494,14 +496,18 @@ impl TemplateProcessing {
                     id: Sequence::B, ..
                 } => pair
                     .as_ref()
-                    .ok_or("Template expected a pair sequence, but none provided")?
+                    .ok_or_else(|| {
+                        Error::configuration("Template expected a pair sequence, but none provided")
+                    })?
                     .len(),
                 Piece::SpecialToken { id, .. } => {
                     if add_special_tokens {
                         self.special_tokens
                             .0
                             .get(id)
-                            .ok_or_else(|| format!("Missing SpecialToken with id {}", id))?
+                            .ok_or_else(|| {
+                                Error::configuration(format!("Missing SpecialToken with id {}", id))
+                            })?
                             .ids
                             .len()
                     } else {
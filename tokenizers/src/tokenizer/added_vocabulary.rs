@@ -497,8 +497,9 @@ impl Serialize for AddedVocabulary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(not(feature = "bert"))]
-    use crate::{normalizers::utils::Lowercase, Trainer};
+    use crate::normalizers::utils::Lowercase;
+    #[cfg(feature = "training")]
+    use crate::Trainer;
     use crate::{normalizers::NormalizerWrapper, OffsetReferential, OffsetType, Result, Token};
     use std::path::{Path, PathBuf};
 
@@ -526,9 +527,9 @@ mod tests {
         }
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     struct TrainerMock;
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     impl Trainer for TrainerMock {
         type Model = ModelMock;
         fn should_show_progress(&self) -> bool {
@@ -548,7 +549,7 @@ mod tests {
     }
 
     impl Model for ModelMock {
-        #[cfg(not(feature = "bert"))]
+        #[cfg(feature = "training")]
         type Trainer = TrainerMock;
 
         fn tokenize(&self, _sequence: &str) -> Result<Vec<Token>> {
@@ -569,7 +570,7 @@ mod tests {
         fn save(&self, _folder: &Path, _name: Option<&str>) -> Result<Vec<PathBuf>> {
             unimplemented!()
         }
-        #[cfg(not(feature = "bert"))]
+        #[cfg(feature = "training")]
         fn get_trainer(&self) -> Self::Trainer {
             TrainerMock
         }
@@ -700,7 +701,6 @@ mod tests {
         );
     }
 
-    #[cfg(not(feature = "bert"))]
     #[test]
     fn options_use_cases() {
         // Is able to extract both normal and special tokens, with various options (lstrip, rstrip,
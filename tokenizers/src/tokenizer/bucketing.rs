@@ -0,0 +1,271 @@
+//! Encoding of large batches of inputs, grouped by length to minimize the padding.
+use crate::tokenizer::{
+    pad_encodings, Decoder, EncodeInput, Encoding, Error, Model, Normalizer, PaddingParams,
+    PostProcessor, PreTokenizer, Result, TokenizerImpl,
+};
+use crate::utils::parallelism::*;
+use std::cmp;
+
+/// How the inputs get grouped by length with `encode_batch_bucketed`
+#[derive(Debug, Clone, PartialEq)]
+pub enum BucketingStrategy {
+    /// Sort all the inputs by length, and cut the sorted list into batches. This gives the least
+    /// padding, with batches ordered from the shortest to the longest inputs.
+    Sorted,
+    /// Group the inputs into buckets of lengths up to each of the given boundaries, which must be
+    /// sorted. The inputs longer than the last boundary go in a last bucket. A batch never mixes
+    /// two buckets, and each bucket keeps the original order of its inputs.
+    Boundaries(Vec<usize>),
+}
+
+#[derive(Debug, Clone)]
+pub struct BucketingParams {
+    pub strategy: BucketingStrategy,
+    /// The maximum number of inputs in each batch
+    pub max_batch_size: usize,
+    /// The maximum number of tokens in each batch, counting the padding, if any. An input longer
+    /// than this gets a batch of its own.
+    pub max_tokens: Option<usize>,
+}
+
+impl Default for BucketingParams {
+    fn default() -> Self {
+        Self {
+            strategy: BucketingStrategy::Sorted,
+            max_batch_size: 32,
+            max_tokens: None,
+        }
+    }
+}
+
+/// A batch of encodings built by `encode_batch_bucketed`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodedBatch {
+    /// The index of each encoding in the original inputs
+    pub indices: Vec<usize>,
+    pub encodings: Vec<Encoding>,
+}
+
+impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
+where
+    M: Model + Send + Sync,
+    N: Normalizer + Send + Sync,
+    PT: PreTokenizer + Send + Sync,
+    PP: PostProcessor + Send + Sync,
+    D: Decoder + Send + Sync,
+{
+    /// Encode all the inputs in parallel, like `encode_batch`, and then group them into batches
+    /// of similar lengths, following the given `BucketingParams`. When padding is enabled, each
+    /// batch gets padded on its own, so `PaddingStrategy::BatchLongest` pads each input only up
+    /// to the longest one of its batch.
+    pub fn encode_batch_bucketed<'s, E>(
+        &self,
+        inputs: Vec<E>,
+        add_special_tokens: bool,
+        params: &BucketingParams,
+    ) -> Result<Vec<EncodedBatch>>
+    where
+        E: Into<EncodeInput<'s>> + Send,
+    {
+        if params.max_batch_size == 0 {
+            return Err(Error::configuration(
+                "`max_batch_size` must be greater than 0",
+            ));
+        }
+        if let BucketingStrategy::Boundaries(boundaries) = &params.strategy {
+            if boundaries.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(Error::configuration("The bucket boundaries must be sorted"));
+            }
+        }
+
+        self.parallelism.install(|| {
+            let encodings = inputs
+                .into_maybe_par_iter()
+                .map(|input| self.encode(input, add_special_tokens))
+                .collect::<Result<Vec<Encoding>>>()?;
+
+            let lengths = encodings.iter().map(|e| e.len()).collect::<Vec<_>>();
+            let mut encodings = encodings.into_iter().map(Some).collect::<Vec<_>>();
+            bucket_indices(&lengths, params, self.padding.as_ref())
+                .into_iter()
+                .map(|indices| {
+                    let mut encodings = indices
+                        .iter()
+                        .map(|i| encodings[*i].take().unwrap())
+                        .collect::<Vec<_>>();
+                    if let Some(padding) = &self.padding {
+                        pad_encodings(&mut encodings, padding)?;
+                    }
+                    Ok(EncodedBatch { indices, encodings })
+                })
+                .collect()
+        })
+    }
+}
+
+/// Group the indices of the inputs with the given `lengths` into batches
+fn bucket_indices(
+    lengths: &[usize],
+    params: &BucketingParams,
+    padding: Option<&PaddingParams>,
+) -> Vec<Vec<usize>> {
+    let bucket = |length: usize| match &params.strategy {
+        BucketingStrategy::Sorted => 0,
+        BucketingStrategy::Boundaries(boundaries) => boundaries
+            .iter()
+            .position(|b| length <= *b)
+            .unwrap_or(boundaries.len()),
+    };
+    // The number of tokens of each input of a batch, once padded
+    let padded_length = |longest: usize| match padding {
+        Some(padding) => cmp::max(longest, padding.pad_length(longest)),
+        None => longest,
+    };
+
+    // A stable sort, to keep the original order among the inputs of the same bucket
+    let mut order = (0..lengths.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| match params.strategy {
+        BucketingStrategy::Sorted => lengths[*i],
+        BucketingStrategy::Boundaries(_) => bucket(lengths[*i]),
+    });
+
+    let mut batches = vec![];
+    let mut batch: Vec<usize> = vec![];
+    let mut longest = 0;
+    for i in order {
+        let length = lengths[i];
+        if let Some(last) = batch.last() {
+            let tokens = (batch.len() + 1) * padded_length(longest.max(length));
+            if batch.len() == params.max_batch_size
+                || bucket(lengths[*last]) != bucket(length)
+                || matches!(params.max_tokens, Some(max) if tokens > max)
+            {
+                batches.push(std::mem::take(&mut batch));
+                longest = 0;
+            }
+        }
+        batch.push(i);
+        longest = longest.max(length);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+
+    batches
+}
+
+#[cfg(all(test, feature = "wordlevel"))]
+mod tests {
+    use super::*;
+    use crate::models::wordlevel::WordLevel;
+    use crate::pre_tokenizers::whitespace::Whitespace;
+    use crate::Tokenizer;
+
+    fn tokenizer() -> Tokenizer {
+        let vocab = (0..10).map(|i| (format!("w{}", i), i)).collect();
+        let model = WordLevel::builder().vocab(vocab).build().unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Whitespace);
+        tokenizer
+    }
+
+    // Inputs of lengths 3, 1, 4, 1, 5, 2
+    fn inputs() -> Vec<&'static str> {
+        vec![
+            "w1 w2 w3",
+            "w1",
+            "w1 w2 w3 w4",
+            "w2",
+            "w1 w2 w3 w4 w5",
+            "w1 w2",
+        ]
+    }
+
+    fn indices(batches: &[EncodedBatch]) -> Vec<Vec<usize>> {
+        batches.iter().map(|b| b.indices.clone()).collect()
+    }
+
+    #[test]
+    fn sorted() {
+        let mut tokenizer = tokenizer();
+        tokenizer.with_padding(Some(PaddingParams {
+            pad_id: 9,
+            ..Default::default()
+        }));
+        let params = BucketingParams {
+            max_batch_size: 2,
+            ..Default::default()
+        };
+        let batches = tokenizer
+            .encode_batch_bucketed(inputs(), false, &params)
+            .unwrap();
+
+        assert_eq!(indices(&batches), vec![vec![1, 3], vec![5, 0], vec![2, 4]]);
+        let ids = batches
+            .iter()
+            .map(|b| b.encodings.iter().map(|e| e.get_ids().to_vec()).collect())
+            .collect::<Vec<Vec<_>>>();
+        assert_eq!(
+            ids,
+            vec![
+                vec![vec![1], vec![2]],
+                vec![vec![1, 2, 9], vec![1, 2, 3]],
+                vec![vec![1, 2, 3, 4, 9], vec![1, 2, 3, 4, 5]],
+            ]
+        );
+    }
+
+    #[test]
+    fn max_tokens() {
+        let mut tokenizer = tokenizer();
+        tokenizer.with_padding(Some(PaddingParams {
+            pad_to_multiple_of: Some(2),
+            ..Default::default()
+        }));
+        let params = BucketingParams {
+            max_batch_size: 10,
+            max_tokens: Some(6),
+            ..Default::default()
+        };
+        let batches = tokenizer
+            .encode_batch_bucketed(inputs(), false, &params)
+            .unwrap();
+
+        // The input of length 5 gets padded to 6, and fills a whole batch
+        assert_eq!(
+            indices(&batches),
+            vec![vec![1, 3, 5], vec![0], vec![2], vec![4]]
+        );
+        for batch in &batches {
+            let tokens = batch.encodings.iter().map(|e| e.len()).sum::<usize>();
+            assert!(tokens <= 6);
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        let params = BucketingParams {
+            strategy: BucketingStrategy::Boundaries(vec![2, 4]),
+            max_batch_size: 2,
+            ..Default::default()
+        };
+        let batches = tokenizer()
+            .encode_batch_bucketed(inputs(), false, &params)
+            .unwrap();
+        assert_eq!(
+            indices(&batches),
+            vec![vec![1, 3], vec![5], vec![0, 2], vec![4]]
+        );
+        // No padding set
+        assert_eq!(batches[2].encodings[0].len(), 3);
+        assert_eq!(batches[2].encodings[1].len(), 4);
+
+        let params = BucketingParams {
+            strategy: BucketingStrategy::Boundaries(vec![4, 2]),
+            ..Default::default()
+        };
+        assert!(tokenizer()
+            .encode_batch_bucketed(inputs(), false, &params)
+            .is_err());
+    }
+}
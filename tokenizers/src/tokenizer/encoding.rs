@@ -1,7 +1,9 @@
 use crate::parallelism::*;
 use crate::tokenizer::{Offsets, Token};
 use crate::utils::padding::PaddingDirection;
+use crate::utils::truncation::TruncationDirection;
 use serde::{Deserialize, Serialize};
+use std::cmp;
 use std::collections::HashMap;
 use std::ops::Range;
 
@@ -179,7 +181,7 @@ impl Encoding {
         std::mem::replace(&mut self.overflowing, vec![])
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(any(feature = "onig", feature = "fancy-regex"))]
     pub(crate) fn process_tokens_with_offsets_mut<F>(&mut self, func: F)
     where
         F: FnMut((usize, (&String, &mut Offsets))),
@@ -191,6 +193,13 @@ impl Encoding {
             .for_each(func)
     }
 
+    /// Replace the token at the given index, keeping everything else
+    #[cfg(feature = "masking")]
+    pub(crate) fn set_token(&mut self, index: usize, id: u32, token: String) {
+        self.ids[index] = id;
+        self.tokens[index] = token;
+    }
+
     /// Returns the range to target to retrieve something (word_id, offsets, ..) related to the
     /// given sequence id
     fn sequence_range(&self, sequence_id: usize) -> Range<usize> {
@@ -381,6 +390,118 @@ impl Encoding {
         self.overflowing = overflowing;
     }
 
+    /// Truncate the current `Encoding`, keeping the tokens on the side given by `direction`.
+    ///
+    /// The overflowing parts cover the removed tokens, starting with the ones closest to the
+    /// tokens we kept. Each of them includes `stride` tokens of the part next to it, on the side
+    /// of the tokens we kept.
+    ///
+    /// Panic if `stride >= max_len`
+    pub fn truncate_with_direction(
+        &mut self,
+        max_len: usize,
+        stride: usize,
+        direction: TruncationDirection,
+    ) {
+        self.truncate_with_options(max_len, stride, direction, false)
+    }
+
+    /// Truncate the current `Encoding` like `truncate_with_direction`, but without splitting any
+    /// word: the cut moves back to the closest word boundary, using the word ids, and each
+    /// overflowing part starts and ends on word boundaries too. A word longer than `max_len`
+    /// still gets cut.
+    ///
+    /// Panic if `stride >= max_len`
+    pub fn truncate_at_word_boundaries(
+        &mut self,
+        max_len: usize,
+        stride: usize,
+        direction: TruncationDirection,
+    ) {
+        self.truncate_with_options(max_len, stride, direction, true)
+    }
+
+    fn truncate_with_options(
+        &mut self,
+        max_len: usize,
+        stride: usize,
+        direction: TruncationDirection,
+        word_boundaries: bool,
+    ) {
+        let len = self.len();
+        let (mut head, mut tail) = match direction {
+            TruncationDirection::Right => (max_len, 0),
+            TruncationDirection::Left => (0, max_len),
+            TruncationDirection::HeadTail(head) => (head.min(max_len), max_len - head.min(max_len)),
+        };
+        if max_len >= len || max_len == 0 || (tail == 0 && !word_boundaries) {
+            return self.truncate(max_len, stride);
+        }
+        assert!(stride < max_len);
+
+        let is_boundary = |i: usize| !word_boundaries || self.is_word_boundary(i);
+        let is_boundary_rev = |i: usize| !word_boundaries || self.is_word_boundary(len - i);
+        if head > 0 {
+            head = (1..=head).rev().find(|i| is_boundary(*i)).unwrap_or(head);
+        }
+        if tail > 0 {
+            tail = (1..=tail)
+                .rev()
+                .find(|i| is_boundary_rev(*i))
+                .unwrap_or(tail);
+        }
+
+        // The overflowing parts always start from the tokens we kept, so with `Left` we compute
+        // them from the end, and then reverse the ranges
+        let overflowing = if head == 0 {
+            overflowing_ranges(tail, len, max_len, stride, is_boundary_rev)
+                .into_iter()
+                .map(|r| len - r.end..len - r.start)
+                .map(|r| self.slice(&[r]))
+                .collect()
+        } else {
+            overflowing_ranges(head, len - tail, max_len, stride, is_boundary)
+                .into_iter()
+                .map(|r| self.slice(&[r]))
+                .collect()
+        };
+
+        *self = Encoding {
+            overflowing,
+            ..self.slice(&[0..head, len - tail..len])
+        };
+    }
+
+    /// Whether the token at `index` starts a new word. Tokens without a word are words on their
+    /// own, and both ends of the `Encoding` are word boundaries.
+    fn is_word_boundary(&self, index: usize) -> bool {
+        index == 0
+            || index >= self.len()
+            || self.words[index].is_none()
+            || self.words[index] != self.words[index - 1]
+    }
+
+    /// Build a new `Encoding` with the tokens in the given ranges, without any overflowing part
+    fn slice(&self, ranges: &[Range<usize>]) -> Encoding {
+        let mut encoding = Encoding::with_capacity(ranges.iter().map(|r| r.len()).sum());
+        for range in ranges {
+            encoding.ids.extend(&self.ids[range.clone()]);
+            encoding.type_ids.extend(&self.type_ids[range.clone()]);
+            encoding
+                .tokens
+                .extend_from_slice(&self.tokens[range.clone()]);
+            encoding.words.extend(&self.words[range.clone()]);
+            encoding.offsets.extend(&self.offsets[range.clone()]);
+            encoding
+                .special_tokens_mask
+                .extend(&self.special_tokens_mask[range.clone()]);
+            encoding
+                .attention_mask
+                .extend(&self.attention_mask[range.clone()]);
+        }
+        encoding
+    }
+
     /// Merge all Encodings together
     pub fn merge<I: IntoIterator<Item = Encoding>>(encodings: I, growing_offsets: bool) -> Self {
         let mut encoding = Encoding::default();
@@ -544,7 +665,256 @@ impl std::iter::FromIterator<(u32, String, (usize, usize), Option<u32>, u32)> fo
     }
 }
 
+/// The scheme used to tag the tokens covered by a labeled span
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaggingScheme {
+    /// `B-` for the beginning of a span, `I-` inside a span, and `O` outside
+    Bio,
+    /// Like `Bio`, with `L-` for the last token of a span, and `U-` for a span with a single one
+    Bilou,
+}
+
+/// A labeled span of an input sequence, as used for NER or QA for example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabeledSpan {
+    /// The offsets of the span. They must be in the same referential as the offsets of the
+    /// `Encoding`: in bytes with `encode`, or in chars with `encode_char_offsets`.
+    pub offsets: Offsets,
+    /// The label of the span, without any tag prefix (ie. `PER`, not `B-PER`)
+    pub label: String,
+    /// The sequence containing the span: 0 for the first one, 1 for the pair
+    pub sequence_id: usize,
+}
+
+impl LabeledSpan {
+    pub fn new<S: Into<String>>(offsets: Offsets, label: S, sequence_id: usize) -> Self {
+        Self {
+            offsets,
+            label: label.into(),
+            sequence_id,
+        }
+    }
+}
+
+/// How `Encoding::labels_from_spans` labels the tokens
+#[derive(Debug, Clone)]
+pub struct LabelAlignmentParams {
+    /// The scheme used to tag the tokens
+    pub scheme: TaggingScheme,
+    /// Only label the first token of each word, ignoring the others
+    pub first_subword_only: bool,
+    /// Ignore the special tokens (including padding), instead of labeling them `O`
+    pub ignore_special_tokens: bool,
+}
+
+impl Default for LabelAlignmentParams {
+    fn default() -> Self {
+        Self {
+            scheme: TaggingScheme::Bio,
+            first_subword_only: false,
+            ignore_special_tokens: true,
+        }
+    }
+}
+
+impl Encoding {
+    /// Convert some labeled spans of the input into one label per token (like `B-PER`, `I-PER`
+    /// or `O`), for this `Encoding` and each of its overflowing parts, in this order.
+    ///
+    /// Ignored tokens get `None`, which usually becomes the ignore index (`-100`) of the loss.
+    /// A span cut by a window keeps its `I-` tags in the next window, since its beginning is
+    /// not there. When spans overlap, the first one in `spans` wins.
+    pub fn labels_from_spans(
+        &self,
+        spans: &[LabeledSpan],
+        params: &LabelAlignmentParams,
+    ) -> Vec<Vec<Option<String>>> {
+        std::iter::once(self)
+            .chain(self.overflowing.iter())
+            .map(|encoding| encoding.window_labels_from_spans(spans, params))
+            .collect()
+    }
+
+    fn window_labels_from_spans(
+        &self,
+        spans: &[LabeledSpan],
+        params: &LabelAlignmentParams,
+    ) -> Vec<Option<String>> {
+        let sequence_ids = self.get_sequence_ids();
+        let mut labels = vec![None; self.len()];
+
+        let mut i = 0;
+        while i < self.len() {
+            if self.special_tokens_mask[i] == 1 {
+                if !params.ignore_special_tokens {
+                    labels[i] = Some("O".into());
+                }
+                i += 1;
+                continue;
+            }
+
+            // We label whole words if we only keep the first subword, to know where they end
+            let mut end = i + 1;
+            if params.first_subword_only && self.words[i].is_some() {
+                while end < self.len()
+                    && self.special_tokens_mask[end] == 0
+                    && self.words[end] == self.words[i]
+                    && sequence_ids[end] == sequence_ids[i]
+                {
+                    end += 1;
+                }
+            }
+            let (start_offset, end_offset) = (self.offsets[i].0, self.offsets[end - 1].1);
+
+            let label = spans
+                .iter()
+                .find(|span| {
+                    Some(span.sequence_id) == sequence_ids[i]
+                        && span.offsets.0 < end_offset
+                        && span.offsets.1 > start_offset
+                })
+                .map_or_else(
+                    || "O".into(),
+                    |span| {
+                        let begins = start_offset <= span.offsets.0;
+                        let ends = end_offset >= span.offsets.1;
+                        let prefix = match (params.scheme, begins, ends) {
+                            (TaggingScheme::Bio, true, _) => "B",
+                            (TaggingScheme::Bio, false, _) => "I",
+                            (TaggingScheme::Bilou, true, true) => "U",
+                            (TaggingScheme::Bilou, true, false) => "B",
+                            (TaggingScheme::Bilou, false, true) => "L",
+                            (TaggingScheme::Bilou, false, false) => "I",
+                        };
+                        format!("{}-{}", prefix, span.label)
+                    },
+                );
+
+            labels[i] = Some(label);
+            i = end;
+        }
+
+        labels
+    }
+
+    /// Convert one label per token, like the ones predicted by a model, back into labeled spans
+    /// of the input. Both `Bio` and `Bilou` tags are understood.
+    ///
+    /// Ignored tokens (`None`) extend the span of the word they belong to, so labels produced
+    /// with `first_subword_only` give spans covering whole words.
+    pub fn spans_from_labels<S: AsRef<str>>(&self, labels: &[Option<S>]) -> Vec<LabeledSpan> {
+        let sequence_ids = self.get_sequence_ids();
+        let mut spans = vec![];
+        // The span being built, with the word of its last token, and whether it got its last
+        // tag already, in which case only the rest of this word can extend it
+        let mut current: Option<(LabeledSpan, Option<u32>, bool)> = None;
+
+        for (i, label) in labels.iter().enumerate().take(self.len()) {
+            let is_special = self.special_tokens_mask[i] == 1;
+            let tag = match label {
+                Some(label) if !is_special => label.as_ref(),
+                _ => {
+                    match current.as_mut() {
+                        Some((span, word, _))
+                            if !is_special
+                                && word.is_some()
+                                && *word == self.words[i]
+                                && Some(span.sequence_id) == sequence_ids[i] =>
+                        {
+                            span.offsets.1 = self.offsets[i].1;
+                        }
+                        _ => spans.extend(current.take().map(|(span, ..)| span)),
+                    }
+                    continue;
+                }
+            };
+
+            let (prefix, name) = match (tag.get(..2), tag.get(2..)) {
+                (Some(prefix @ "B-"), Some(name))
+                | (Some(prefix @ "I-"), Some(name))
+                | (Some(prefix @ "L-"), Some(name))
+                | (Some(prefix @ "U-"), Some(name)) => (prefix, name),
+                _ => {
+                    spans.extend(current.take().map(|(span, ..)| span));
+                    continue;
+                }
+            };
+
+            match current.as_mut() {
+                Some((span, word, false))
+                    if (prefix == "I-" || prefix == "L-")
+                        && span.label == name
+                        && Some(span.sequence_id) == sequence_ids[i] =>
+                {
+                    span.offsets.1 = self.offsets[i].1;
+                    *word = self.words[i];
+                }
+                _ => {
+                    spans.extend(current.take().map(|(span, ..)| span));
+                    current = sequence_ids[i].map(|sequence_id| {
+                        (
+                            LabeledSpan::new(self.offsets[i], name, sequence_id),
+                            self.words[i],
+                            false,
+                        )
+                    });
+                }
+            }
+
+            if prefix == "L-" || prefix == "U-" {
+                if let Some((_, _, finished)) = current.as_mut() {
+                    *finished = true;
+                }
+            }
+        }
+        spans.extend(current.map(|(span, ..)| span));
+
+        spans
+    }
+}
+
 #[inline]
+/// The ranges of the overflowing parts covering the tokens in `from..to`, each with `max_len`
+/// tokens at most, including up to `stride` tokens before the new ones. The parts start and end
+/// on the boundaries given by `is_boundary`, unless a single word doesn't fit.
+fn overflowing_ranges<F>(
+    from: usize,
+    to: usize,
+    max_len: usize,
+    stride: usize,
+    is_boundary: F,
+) -> Vec<Range<usize>>
+where
+    F: Fn(usize) -> bool,
+{
+    let last_boundary = |range: Range<usize>| range.rev().find(|i| is_boundary(*i));
+
+    let mut ranges = vec![];
+    let mut next = from;
+    while next < to {
+        let mut start = (next.saturating_sub(stride)..next)
+            .find(|i| is_boundary(*i))
+            .unwrap_or(next);
+        let mut end = cmp::min(start + max_len, to);
+        if end < to {
+            end = match last_boundary(next + 1..end + 1) {
+                Some(end) => end,
+                None => {
+                    // The next word doesn't fit with the stride, so we skip it
+                    start = next;
+                    let end = cmp::min(next + max_len, to);
+                    last_boundary(next + 1..end + 1).unwrap_or(end)
+                }
+            };
+        }
+
+        ranges.push(start..end);
+        next = end;
+    }
+
+    ranges
+}
+
 fn get_current_part<T: Clone>(
     prev: &[T],
     current: &[T],
@@ -689,6 +1059,109 @@ mod tests {
         );
     }
 
+    fn ids_and_words(encoding: &Encoding) -> (Vec<u32>, Vec<Option<u32>>) {
+        (encoding.ids.clone(), encoding.words.clone())
+    }
+
+    #[test]
+    fn truncate_with_direction() {
+        let mut a = Encoding::from_tokens(
+            (0..8)
+                .map(|i| Token::new(i, format!("t{}", i), (2 * i as usize, 2 * i as usize + 1)))
+                .collect(),
+            0,
+        );
+        a.words = (0..8).map(Some).collect();
+
+        let mut left = a.clone();
+        left.truncate_with_direction(3, 1, TruncationDirection::Left);
+        assert_eq!(left.get_ids(), &[5, 6, 7]);
+        assert_eq!(left.get_offsets(), &[(10, 11), (12, 13), (14, 15)]);
+        let overflowing = left
+            .get_overflowing()
+            .iter()
+            .map(|o| o.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(overflowing, vec![vec![3, 4, 5], vec![1, 2, 3], vec![0, 1]]);
+
+        let mut head_tail = a.clone();
+        head_tail.truncate_with_direction(5, 1, TruncationDirection::HeadTail(2));
+        assert_eq!(
+            ids_and_words(&head_tail),
+            (
+                vec![0, 1, 5, 6, 7],
+                vec![Some(0), Some(1), Some(5), Some(6), Some(7)]
+            )
+        );
+        assert_eq!(head_tail.get_overflowing().len(), 1);
+        assert_eq!(
+            ids_and_words(&head_tail.get_overflowing()[0]),
+            (vec![1, 2, 3, 4], vec![Some(1), Some(2), Some(3), Some(4)])
+        );
+
+        // A head longer than the max length keeps only the start
+        let mut right = a.clone();
+        right.truncate_with_direction(3, 0, TruncationDirection::HeadTail(4));
+        let mut expected = a.clone();
+        expected.truncate(3, 0);
+        assert_eq!(right, expected);
+
+        // Nothing to do when the encoding is short enough
+        let mut short = a.clone();
+        short.truncate_with_direction(8, 0, TruncationDirection::Left);
+        assert_eq!(short, a);
+    }
+
+    fn overflowing_ids(encoding: &Encoding) -> Vec<Vec<u32>> {
+        encoding
+            .get_overflowing()
+            .iter()
+            .map(|o| o.get_ids().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn truncate_at_word_boundaries() {
+        let mut a = Encoding::from_tokens(
+            (0..8)
+                .map(|i| Token::new(i, format!("t{}", i), (i as usize, i as usize + 1)))
+                .collect(),
+            0,
+        );
+        a.words = vec![0, 0, 1, 1, 1, 2, 3, 3].into_iter().map(Some).collect();
+
+        let mut right = a.clone();
+        right.truncate_at_word_boundaries(4, 0, TruncationDirection::Right);
+        assert_eq!(right.get_ids(), &[0, 1]);
+        assert_eq!(overflowing_ids(&right), vec![vec![2, 3, 4, 5], vec![6, 7]]);
+
+        // The stride starts on a word boundary, or gets skipped when the next word doesn't fit
+        let mut stride = a.clone();
+        stride.truncate_at_word_boundaries(4, 2, TruncationDirection::Right);
+        assert_eq!(stride.get_ids(), &[0, 1]);
+        assert_eq!(
+            overflowing_ids(&stride),
+            vec![vec![2, 3, 4, 5], vec![5, 6, 7]]
+        );
+
+        let mut left = a.clone();
+        left.truncate_at_word_boundaries(4, 0, TruncationDirection::Left);
+        assert_eq!(left.get_ids(), &[5, 6, 7]);
+        assert_eq!(overflowing_ids(&left), vec![vec![2, 3, 4], vec![0, 1]]);
+
+        let mut head_tail = a.clone();
+        head_tail.truncate_at_word_boundaries(5, 0, TruncationDirection::HeadTail(3));
+        assert_eq!(head_tail.get_ids(), &[0, 1, 6, 7]);
+        assert_eq!(overflowing_ids(&head_tail), vec![vec![2, 3, 4, 5]]);
+
+        // A word longer than the max length still gets cut
+        let mut long = a.clone();
+        long.words = vec![Some(0); 8];
+        long.truncate_at_word_boundaries(3, 0, TruncationDirection::Right);
+        assert_eq!(long.get_ids(), &[0, 1, 2]);
+        assert_eq!(overflowing_ids(&long), vec![vec![3, 4, 5], vec![6, 7]]);
+    }
+
     #[test]
     fn mappings() {
         let encoding = Encoding {
@@ -780,4 +1253,203 @@ mod tests {
         assert_eq!(encoding.char_to_word(2, 1), Some(0));
         assert_eq!(encoding.char_to_word(9, 1), Some(2));
     }
+
+    fn john_lives_in_new_york() -> Encoding {
+        Encoding {
+            ids: vec![0; 9],
+            type_ids: vec![0; 9],
+            tokens: vec![
+                "[CLS]".into(),
+                "Jo".into(),
+                "hn".into(),
+                "lives".into(),
+                "in".into(),
+                "New".into(),
+                "Yo".into(),
+                "rk".into(),
+                "[SEP]".into(),
+            ],
+            words: vec![
+                None,
+                Some(0),
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(4),
+                None,
+            ],
+            offsets: vec![
+                (0, 0),
+                (0, 2),
+                (2, 4),
+                (5, 10),
+                (11, 13),
+                (14, 17),
+                (18, 20),
+                (20, 22),
+                (0, 0),
+            ],
+            special_tokens_mask: vec![1, 0, 0, 0, 0, 0, 0, 0, 1],
+            attention_mask: vec![1; 9],
+            ..Default::default()
+        }
+    }
+
+    fn labels(labels: &[Option<&str>]) -> Vec<Option<String>> {
+        labels.iter().map(|l| l.map(String::from)).collect()
+    }
+
+    #[test]
+    fn labels_from_spans() {
+        let encoding = john_lives_in_new_york();
+        let spans = vec![
+            LabeledSpan::new((0, 4), "PER", 0),
+            LabeledSpan::new((14, 22), "LOC", 0),
+        ];
+
+        assert_eq!(
+            encoding.labels_from_spans(&spans, &LabelAlignmentParams::default()),
+            vec![labels(&[
+                None,
+                Some("B-PER"),
+                Some("I-PER"),
+                Some("O"),
+                Some("O"),
+                Some("B-LOC"),
+                Some("I-LOC"),
+                Some("I-LOC"),
+                None
+            ])]
+        );
+
+        assert_eq!(
+            encoding.labels_from_spans(
+                &spans,
+                &LabelAlignmentParams {
+                    scheme: TaggingScheme::Bilou,
+                    first_subword_only: true,
+                    ignore_special_tokens: false,
+                }
+            ),
+            vec![labels(&[
+                Some("O"),
+                Some("U-PER"),
+                None,
+                Some("O"),
+                Some("O"),
+                Some("B-LOC"),
+                Some("L-LOC"),
+                None,
+                Some("O")
+            ])]
+        );
+    }
+
+    #[test]
+    fn labels_from_spans_overflowing() {
+        let mut encoding = john_lives_in_new_york();
+        encoding.truncate(7, 0);
+
+        let spans = vec![LabeledSpan::new((14, 22), "LOC", 0)];
+        let params = LabelAlignmentParams {
+            scheme: TaggingScheme::Bilou,
+            ..Default::default()
+        };
+        assert_eq!(
+            encoding.labels_from_spans(&spans, &params),
+            vec![
+                labels(&[
+                    None,
+                    Some("O"),
+                    Some("O"),
+                    Some("O"),
+                    Some("O"),
+                    Some("B-LOC"),
+                    Some("I-LOC")
+                ]),
+                labels(&[Some("L-LOC"), None])
+            ]
+        );
+    }
+
+    #[test]
+    fn labels_from_spans_pair() {
+        let encoding = Encoding {
+            ids: vec![0; 4],
+            words: vec![Some(0), Some(1), Some(0), Some(1)],
+            offsets: vec![(0, 3), (4, 7), (0, 3), (4, 7)],
+            special_tokens_mask: vec![0; 4],
+            sequence_ranges: HashMap::from_iter(vec![(0, 0..2), (1, 2..4)]),
+            ..Default::default()
+        };
+        let spans = vec![LabeledSpan::new((0, 3), "ANS", 1)];
+
+        let token_labels = encoding.labels_from_spans(&spans, &Default::default());
+        assert_eq!(
+            token_labels,
+            vec![labels(&[Some("O"), Some("O"), Some("B-ANS"), Some("O")])]
+        );
+        assert_eq!(encoding.spans_from_labels(&token_labels[0]), spans);
+    }
+
+    #[test]
+    fn spans_from_labels() {
+        let encoding = john_lives_in_new_york();
+        let expected = vec![
+            LabeledSpan::new((0, 4), "PER", 0),
+            LabeledSpan::new((14, 22), "LOC", 0),
+        ];
+
+        let bio = labels(&[
+            None,
+            Some("B-PER"),
+            Some("I-PER"),
+            Some("O"),
+            Some("O"),
+            Some("B-LOC"),
+            Some("I-LOC"),
+            Some("I-LOC"),
+            None,
+        ]);
+        assert_eq!(encoding.spans_from_labels(&bio), expected);
+
+        // Ignored subwords extend the span of their word, even after the last tag
+        let bilou = labels(&[
+            Some("O"),
+            Some("U-PER"),
+            None,
+            Some("O"),
+            Some("O"),
+            Some("B-LOC"),
+            Some("L-LOC"),
+            None,
+            Some("O"),
+        ]);
+        assert_eq!(encoding.spans_from_labels(&bilou), expected);
+
+        // A new tag always starts a new span after a last one, and `I-` without `B-` starts one
+        let unusual = labels(&[
+            None,
+            Some("U-PER"),
+            Some("I-PER"),
+            Some("O"),
+            Some("I-LOC"),
+            Some("B-LOC"),
+            Some("I-MISC"),
+            None,
+            None,
+        ]);
+        assert_eq!(
+            encoding.spans_from_labels(&unusual),
+            vec![
+                LabeledSpan::new((0, 2), "PER", 0),
+                LabeledSpan::new((2, 4), "PER", 0),
+                LabeledSpan::new((11, 13), "LOC", 0),
+                LabeledSpan::new((14, 17), "LOC", 0),
+                LabeledSpan::new((18, 22), "MISC", 0),
+            ]
+        );
+    }
 }
@@ -0,0 +1,152 @@
+use crate::utils::truncation::TruncationError;
+use std::fmt;
+
+#[cfg(feature = "bpe")]
+use crate::models::bpe;
+#[cfg(feature = "unigram")]
+use crate::models::unigram::UnigramError;
+#[cfg(feature = "wordlevel")]
+use crate::models::wordlevel;
+#[cfg(feature = "wordpiece")]
+use crate::models::wordpiece;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Errors that can be encountered while using the tokenizers, or any of their parts.
+///
+/// Each variant represents a category of errors, and holds the error that caused it, which is
+/// also available with `std::error::Error::source`. New categories may be added in the future.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error encountered while reading or writing files
+    Io(std::io::Error),
+    /// An error forwarded from Serde, while serializing or deserializing JSON
+    Serialization(serde_json::Error),
+    /// An invalid regular expression
+    Regex(BoxError),
+    /// An error from the `BPE` model
+    #[cfg(feature = "bpe")]
+    Bpe(bpe::Error),
+    /// An error from the `WordPiece` model
+    #[cfg(feature = "wordpiece")]
+    WordPiece(wordpiece::Error),
+    /// An error from the `WordLevel` model
+    #[cfg(feature = "wordlevel")]
+    WordLevel(wordlevel::Error),
+    /// An error from the `Unigram` model
+    #[cfg(feature = "unigram")]
+    Unigram(UnigramError),
+    /// The encodings could not be truncated with the current parameters
+    Truncation(TruncationError),
+    /// Invalid parameters or configuration, like incompatible options, a trainer used with
+    /// another model, or a template using missing special tokens
+    Configuration(BoxError),
+    /// Any other error, like an error coming from a custom component
+    Other(BoxError),
+}
+
+impl Error {
+    /// Build an `Error::Other` from anything that can be converted into a boxed error, like
+    /// a `String`, or any type implementing `std::error::Error`.
+    pub fn other<E: Into<BoxError>>(error: E) -> Self {
+        Error::Other(error.into())
+    }
+
+    /// Build an `Error::Configuration`, from anything that `Error::other` accepts
+    pub fn configuration<E: Into<BoxError>>(error: E) -> Self {
+        Error::Configuration(error.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => e.fmt(f),
+            Error::Serialization(e) => e.fmt(f),
+            Error::Regex(e) => e.fmt(f),
+            #[cfg(feature = "bpe")]
+            Error::Bpe(e) => e.fmt(f),
+            #[cfg(feature = "wordpiece")]
+            Error::WordPiece(e) => e.fmt(f),
+            #[cfg(feature = "wordlevel")]
+            Error::WordLevel(e) => e.fmt(f),
+            #[cfg(feature = "unigram")]
+            Error::Unigram(e) => e.fmt(f),
+            Error::Truncation(e) => e.fmt(f),
+            Error::Configuration(e) => e.fmt(f),
+            Error::Other(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Serialization(e) => Some(e),
+            Error::Regex(e) => Some(e.as_ref()),
+            #[cfg(feature = "bpe")]
+            Error::Bpe(e) => Some(e),
+            #[cfg(feature = "wordpiece")]
+            Error::WordPiece(e) => Some(e),
+            #[cfg(feature = "wordlevel")]
+            Error::WordLevel(e) => Some(e),
+            #[cfg(feature = "unigram")]
+            Error::Unigram(e) => Some(e),
+            Error::Truncation(e) => Some(e),
+            Error::Configuration(e) => Some(e.as_ref()),
+            Error::Other(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+impl_enum_from!(std::io::Error, Error, Io);
+impl_enum_from!(serde_json::Error, Error, Serialization);
+#[cfg(feature = "bpe")]
+impl_enum_from!(bpe::Error, Error, Bpe);
+#[cfg(feature = "wordpiece")]
+impl_enum_from!(wordpiece::Error, Error, WordPiece);
+#[cfg(feature = "wordlevel")]
+impl_enum_from!(wordlevel::Error, Error, WordLevel);
+#[cfg(feature = "unigram")]
+impl_enum_from!(UnigramError, Error, Unigram);
+impl_enum_from!(TruncationError, Error, Truncation);
+
+impl From<regex::Error> for Error {
+    fn from(e: regex::Error) -> Self {
+        Error::Regex(Box::new(e))
+    }
+}
+
+#[cfg(feature = "onig")]
+impl From<onig::Error> for Error {
+    fn from(e: onig::Error) -> Self {
+        Error::Regex(Box::new(e))
+    }
+}
+
+#[cfg(feature = "fancy-regex")]
+impl From<fancy_regex::Error> for Error {
+    fn from(e: fancy_regex::Error) -> Self {
+        Error::Regex(Box::new(e))
+    }
+}
+
+impl From<BoxError> for Error {
+    fn from(e: BoxError) -> Self {
+        Error::Other(e)
+    }
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Self {
+        Error::Other(e.into())
+    }
+}
+
+impl From<&str> for Error {
+    fn from(e: &str) -> Self {
+        Error::Other(e.into())
+    }
+}
@@ -0,0 +1,461 @@
+use crate::tokenizer::{
+    normalizer::Range, Decoder, EncodeInput, Encoding, InputSequence, Model, Normalizer,
+    OffsetReferential, OffsetType, Offsets, PostProcessor, PreTokenizedString, PreTokenizer,
+    Result, Token, TokenizerImpl,
+};
+
+/// The full trace of the work done by a `Tokenizer` to encode an input, as returned by
+/// `TokenizerImpl::explain`. All the offsets are byte offsets relative to the original input
+/// of the sequence they belong to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    /// The trace of each sequence, in order. When the input is pre-tokenized, each of its words
+    /// is a sequence of its own.
+    pub sequences: Vec<SequenceTrace>,
+    /// What the truncation did, if it is enabled
+    pub truncation: Option<TruncationTrace>,
+    /// The tokens added by the `PostProcessor`, along with their index in the final `Encoding`
+    pub special_tokens: Vec<(usize, String)>,
+    /// The number of padding tokens added at the end of the pipeline
+    pub padding: usize,
+    /// The final `Encoding`, exactly as returned by `encode`
+    pub encoding: Encoding,
+}
+
+/// The trace of a single sequence going through the `Normalizer`, the `PreTokenizer` and
+/// the `Model`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceTrace {
+    /// The original input
+    pub original: String,
+    /// The type id attributed to this sequence: 0 for the first one, 1 for the pair
+    pub type_id: u32,
+    /// The index of the word, if this sequence is part of a pre-tokenized input
+    pub word_idx: Option<u32>,
+    /// The input, split around the added tokens. Every part that is not an added token has
+    /// already been normalized.
+    pub added_tokens: Vec<SplitTrace>,
+    /// The normalized string, as seen by the `PreTokenizer`
+    pub normalized: NormalizedTrace,
+    /// The pre-tokens, each with the tokens produced by the `Model`
+    pub pre_tokens: Vec<SplitTrace>,
+}
+
+/// A part of the input, produced by one of the splitting steps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitTrace {
+    /// The content of the split, after normalization
+    pub value: String,
+    /// The offsets of the split in the original input
+    pub offsets: Offsets,
+    /// Whether this split is an added token, in which case it never reaches the `Model`
+    pub is_added_token: bool,
+    /// The tokens of this split, with offsets relative to the original input. Before the
+    /// `Model` runs, only added tokens have one.
+    pub tokens: Vec<Token>,
+}
+
+/// The normalized version of a sequence, with its alignments to the original input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedTrace {
+    /// The normalized string
+    pub normalized: String,
+    /// For each char of the normalized string, its offsets in `normalized` and the offsets of
+    /// the part of the original input it comes from.
+    pub alignments: Vec<(Offsets, Offsets)>,
+}
+
+/// What the truncation did to the encoded sequences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruncationTrace {
+    /// The maximum length actually used, after making room for the special tokens
+    pub max_length: usize,
+    /// The number of tokens of the sequence, and of its pair, before truncation
+    pub lengths_before: (usize, Option<usize>),
+    /// The number of tokens of the sequence, and of its pair, after truncation
+    pub lengths_after: (usize, Option<usize>),
+    /// The number of overflowing encodings produced
+    pub overflowing: usize,
+}
+
+impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
+where
+    M: Model,
+    N: Normalizer,
+    PT: PreTokenizer,
+    PP: PostProcessor,
+    D: Decoder,
+{
+    /// Encode the given input just like `encode` does, while keeping a trace of what each step
+    /// of the pipeline did. This is especially useful to understand where an unexpected token
+    /// comes from:
+    ///
+    /// ```
+    /// # #[cfg(feature = "wordlevel")] {
+    /// # use tokenizers::Tokenizer;
+    /// # use tokenizers::models::wordlevel::WordLevel;
+    /// # use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    /// let vocab = [("hello".into(), 0), ("world".into(), 1), ("[UNK]".into(), 2)];
+    /// let model = WordLevel::builder()
+    ///     .vocab(vocab.iter().cloned().collect())
+    ///     .unk_token("[UNK]".into())
+    ///     .build()
+    ///     .unwrap();
+    /// let mut tokenizer = Tokenizer::new(model);
+    /// tokenizer.with_pre_tokenizer(Whitespace::default());
+    ///
+    /// let explanation = tokenizer.explain("hello world", false).unwrap();
+    /// let pre_tokens = &explanation.sequences[0].pre_tokens;
+    /// assert_eq!(pre_tokens[1].value, "world");
+    /// assert_eq!(pre_tokens[1].offsets, (6, 11));
+    /// assert_eq!(explanation.encoding.get_ids(), &[0, 1]);
+    /// # }
+    /// ```
+    pub fn explain<'s, E>(&self, input: E, add_special_tokens: bool) -> Result<Explanation>
+    where
+        E: Into<EncodeInput<'s>>,
+    {
+        let (sequence, pair) = match input.into() {
+            EncodeInput::Single(s1) => (s1, None),
+            EncodeInput::Dual(s1, s2) => (s1, Some(s2)),
+        };
+
+        let mut sequences = vec![];
+        let encoding = self.explain_single_sequence(sequence, 0, &mut sequences)?;
+        let pair_encoding = pair
+            .map(|sequence| self.explain_single_sequence(sequence, 1, &mut sequences))
+            .transpose()?;
+
+        // Truncation
+        let lengths_before = (encoding.len(), pair_encoding.as_ref().map(|e| e.len()));
+        let truncation_params = self.truncation_params(pair_encoding.is_some(), add_special_tokens);
+        let (encoding, pair_encoding) =
+            self.do_truncate(encoding, pair_encoding, add_special_tokens)?;
+        let truncation = truncation_params.map(|params| TruncationTrace {
+            max_length: params.max_length,
+            lengths_before,
+            lengths_after: (encoding.len(), pair_encoding.as_ref().map(|e| e.len())),
+            overflowing: encoding.get_overflowing().len(),
+        });
+
+        // Post-processing
+        let encoding = self.do_post_process(encoding, pair_encoding, add_special_tokens)?;
+        let special_tokens = encoding
+            .get_special_tokens_mask()
+            .iter()
+            .zip(encoding.get_tokens())
+            .enumerate()
+            .filter(|(_, (mask, _))| **mask == 1)
+            .map(|(i, (_, token))| (i, token.clone()))
+            .collect();
+
+        // Padding
+        let length = encoding.len();
+        let encoding = self.do_pad(encoding)?;
+
+        Ok(Explanation {
+            sequences,
+            truncation,
+            special_tokens,
+            padding: encoding.len() - length,
+            encoding,
+        })
+    }
+
+    /// Encode a single sequence, just like `encode_single_sequence`, adding the trace of each
+    /// of its subsequences to `traces`
+    fn explain_single_sequence(
+        &self,
+        sequence: InputSequence,
+        type_id: u32,
+        traces: &mut Vec<SequenceTrace>,
+    ) -> Result<Encoding> {
+        let (subseqs, is_pre_tokenized): (Vec<&str>, bool) = match &sequence {
+            InputSequence::PreTokenized(seq) => (seq.to_vec(), true),
+            InputSequence::PreTokenizedOwned(seq) => {
+                (seq.iter().map(|s| s.as_str()).collect(), true)
+            }
+            InputSequence::PreTokenizedCow(seq) => (seq.iter().map(|s| s.as_ref()).collect(), true),
+            InputSequence::Raw(seq) => (vec![seq.as_ref()], false),
+        };
+
+        subseqs
+            .into_iter()
+            .enumerate()
+            .map(|(i, subseq)| {
+                let word_idx = if is_pre_tokenized {
+                    Some(i as u32)
+                } else {
+                    None
+                };
+
+                // 1. Extract the added tokens and normalize
+                let normalized = self
+                    .added_vocabulary
+                    .extract_and_normalize(self.normalizer.as_ref(), subseq);
+                let added_tokens = split_traces(&normalized, None);
+                let normalized_trace = normalized_trace(&normalized);
+
+                // 2. Pre-tokenize
+                let mut pre_tokenized = self.do_pre_tokenize(normalized)?;
+                let is_added_token = pre_tokenized
+                    .get_splits(OffsetReferential::Original, OffsetType::Byte)
+                    .into_iter()
+                    .map(|(_, _, tokens)| tokens.is_some())
+                    .collect::<Vec<_>>();
+
+                // 3. Tokenize
+                pre_tokenized.tokenize(|normalized| self.model.tokenize(normalized.get()))?;
+                let encoding =
+                    pre_tokenized
+                        .clone()
+                        .into_encoding(word_idx, type_id, OffsetType::Byte)?;
+                let mut pre_tokens = split_traces(&pre_tokenized, Some(&encoding));
+                for (trace, is_added_token) in pre_tokens.iter_mut().zip(is_added_token) {
+                    trace.is_added_token = is_added_token;
+                }
+
+                traces.push(SequenceTrace {
+                    original: subseq.to_owned(),
+                    type_id,
+                    word_idx,
+                    added_tokens,
+                    normalized: normalized_trace,
+                    pre_tokens,
+                });
+
+                Ok(encoding)
+            })
+            .collect()
+    }
+}
+
+/// Build the trace of each split of the given `PreTokenizedString`. If its `Encoding` is
+/// provided, the tokens are read from it to get offsets relative to the original input.
+fn split_traces(pretokenized: &PreTokenizedString, encoding: Option<&Encoding>) -> Vec<SplitTrace> {
+    let mut token_idx = 0;
+    pretokenized
+        .get_splits(OffsetReferential::Original, OffsetType::Byte)
+        .into_iter()
+        .map(|(value, offsets, tokens)| {
+            let n_tokens = tokens.as_ref().map_or(0, |tokens| tokens.len());
+            let tokens = match encoding {
+                Some(encoding) => {
+                    let range = token_idx..token_idx + n_tokens;
+                    token_idx += n_tokens;
+                    range
+                        .map(|i| {
+                            Token::new(
+                                encoding.get_ids()[i],
+                                encoding.get_tokens()[i].clone(),
+                                encoding.get_offsets()[i],
+                            )
+                        })
+                        .collect()
+                }
+                // Only added tokens are already tokenized, and they cover their whole split
+                None => tokens
+                    .iter()
+                    .flatten()
+                    .map(|token| Token::new(token.id, token.value.clone(), offsets))
+                    .collect(),
+            };
+
+            SplitTrace {
+                value: value.to_owned(),
+                offsets,
+                is_added_token: encoding.is_none() && n_tokens > 0,
+                tokens,
+            }
+        })
+        .collect()
+}
+
+/// Build the normalized string of the given `PreTokenizedString`, with the alignments of each
+/// of its chars to the original input
+fn normalized_trace(pretokenized: &PreTokenizedString) -> NormalizedTrace {
+    let mut normalized = String::new();
+    let mut alignments = vec![];
+
+    for split in pretokenized.normalized_splits() {
+        let shift = split.offsets_original().0;
+        for (start, c) in split.get().char_indices() {
+            let end = start + c.len_utf8();
+            let original = split
+                .convert_offsets(Range::Normalized(start..end))
+                .map_or((shift, shift), |range| {
+                    (shift + range.start, shift + range.end)
+                });
+            alignments.push(((normalized.len() + start, normalized.len() + end), original));
+        }
+        normalized.push_str(split.get());
+    }
+
+    NormalizedTrace {
+        normalized,
+        alignments,
+    }
+}
+
+#[cfg(all(test, feature = "wordlevel"))]
+mod tests {
+    use super::*;
+    use crate::models::wordlevel::WordLevel;
+    use crate::normalizers::utils::Lowercase;
+    use crate::pre_tokenizers::whitespace::Whitespace;
+    use crate::processors::bert::BertProcessing;
+    use crate::{AddedToken, PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+
+    fn tokenizer() -> Tokenizer {
+        let vocab = [
+            ("hello", 0),
+            ("world", 1),
+            ("[UNK]", 2),
+            ("[MASK]", 3),
+            ("[CLS]", 4),
+            ("[SEP]", 5),
+            ("[PAD]", 6),
+        ]
+        .iter()
+        .map(|(token, id)| (token.to_string(), *id))
+        .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer
+            .with_normalizer(Lowercase)
+            .with_pre_tokenizer(Whitespace)
+            .with_post_processor(BertProcessing::new(
+                ("[SEP]".into(), 5),
+                ("[CLS]".into(), 4),
+            ));
+        tokenizer.add_special_tokens(&[AddedToken::from("[MASK]", true)]);
+        tokenizer
+    }
+
+    #[test]
+    fn explain_each_step() {
+        let tokenizer = tokenizer();
+        let explanation = tokenizer.explain("Hello [MASK] WORLD", true).unwrap();
+        assert_eq!(explanation.sequences.len(), 1);
+        let trace = &explanation.sequences[0];
+        assert_eq!(trace.original, "Hello [MASK] WORLD");
+
+        let added_tokens = trace
+            .added_tokens
+            .iter()
+            .map(|split| (split.value.as_str(), split.offsets, split.is_added_token))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            added_tokens,
+            vec![
+                ("hello ", (0, 6), false),
+                ("[MASK]", (6, 12), true),
+                (" world", (12, 18), false)
+            ]
+        );
+        assert_eq!(
+            trace.added_tokens[1].tokens,
+            vec![Token::new(3, "[MASK]".into(), (6, 12))]
+        );
+
+        assert_eq!(trace.normalized.normalized, "hello [MASK] world");
+        assert_eq!(trace.normalized.alignments[0], ((0, 1), (0, 1)));
+        assert_eq!(trace.normalized.alignments[17], ((17, 18), (17, 18)));
+
+        let pre_tokens = trace
+            .pre_tokens
+            .iter()
+            .map(|split| (split.value.as_str(), split.offsets, split.is_added_token))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pre_tokens,
+            vec![
+                ("hello", (0, 5), false),
+                ("[MASK]", (6, 12), true),
+                ("world", (13, 18), false)
+            ]
+        );
+        assert_eq!(
+            trace.pre_tokens[2].tokens,
+            vec![Token::new(1, "world".into(), (13, 18))]
+        );
+
+        assert_eq!(explanation.truncation, None);
+        assert_eq!(
+            explanation.special_tokens,
+            vec![(0, "[CLS]".into()), (4, "[SEP]".into())]
+        );
+        assert_eq!(explanation.padding, 0);
+        assert_eq!(
+            explanation.encoding,
+            tokenizer.encode("Hello [MASK] WORLD", true).unwrap()
+        );
+    }
+
+    #[test]
+    fn explain_truncation_and_padding() {
+        let mut tokenizer = tokenizer();
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: 5,
+                ..Default::default()
+            }))
+            .with_padding(Some(PaddingParams {
+                strategy: PaddingStrategy::Fixed(7),
+                pad_id: 6,
+                ..Default::default()
+            }));
+
+        let explanation = tokenizer.explain("hello world foo bar", true).unwrap();
+        assert_eq!(
+            explanation.truncation,
+            Some(TruncationTrace {
+                max_length: 3,
+                lengths_before: (4, None),
+                lengths_after: (3, None),
+                overflowing: 1,
+            })
+        );
+        assert_eq!(explanation.padding, 2);
+        assert_eq!(
+            explanation.encoding.get_tokens(),
+            &["[CLS]", "hello", "world", "foo", "[SEP]", "[PAD]", "[PAD]"]
+        );
+        assert_eq!(
+            explanation.encoding,
+            tokenizer.encode("hello world foo bar", true).unwrap()
+        );
+    }
+
+    #[test]
+    fn explain_pre_tokenized_pair() {
+        let tokenizer = tokenizer();
+        let explanation = tokenizer
+            .explain(("Hello", &["WORLD", "foo"][..]), false)
+            .unwrap();
+
+        let sequences = explanation
+            .sequences
+            .iter()
+            .map(|trace| (trace.original.as_str(), trace.type_id, trace.word_idx))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sequences,
+            vec![
+                ("Hello", 0, None),
+                ("WORLD", 1, Some(0)),
+                ("foo", 1, Some(1))
+            ]
+        );
+        assert_eq!(
+            explanation.sequences[2].pre_tokens[0].tokens,
+            vec![Token::new(2, "foo".into(), (0, 3))]
+        );
+        assert!(explanation.special_tokens.is_empty());
+    }
+}
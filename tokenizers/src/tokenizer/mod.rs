@@ -9,7 +9,7 @@
 //!   - [`PostProcessor`](trait.PostProcessor.html): Takes care of the processing after tokenization (like truncating, padding,
 //!   ...).
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 use std::io::BufReader;
 use std::{
     collections::HashMap,
@@ -24,18 +24,22 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::utils::parallelism::*;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 use crate::utils::{
     iter::ResultShunt,
     progress::{ProgressBar, ProgressStyle},
 };
 
 mod added_vocabulary;
+mod bucketing;
 mod encoding;
+mod error;
+mod explain;
 pub mod normalizer;
 pub mod pattern;
 pub mod pre_tokenizer;
 mod serialization;
+mod shards;
 
 // Re-export wrappers
 pub use crate::decoders::DecoderWrapper;
@@ -45,14 +49,22 @@ pub use crate::pre_tokenizers::PreTokenizerWrapper;
 pub use crate::processors::PostProcessorWrapper;
 // And some other types
 pub use crate::utils::iter::LinesWithEnding;
+#[cfg(feature = "masking")]
+pub use crate::utils::masking::{MaskingParams, WholeWordMasking};
+pub use crate::utils::packing::{pack_encodings, PackedBlock, PackedBlocks, PackingParams};
 pub use crate::utils::padding::{pad_encodings, PaddingDirection, PaddingParams, PaddingStrategy};
-pub use crate::utils::truncation::{truncate_encodings, TruncationParams, TruncationStrategy};
+pub use crate::utils::truncation::{
+    truncate_encodings, TruncationDirection, TruncationParams, TruncationStrategy,
+};
 pub use added_vocabulary::*;
+pub use bucketing::{BucketingParams, BucketingStrategy, EncodedBatch};
 pub use encoding::*;
+pub use error::Error;
+pub use explain::{Explanation, NormalizedTrace, SequenceTrace, SplitTrace, TruncationTrace};
 pub use normalizer::{NormalizedString, OffsetReferential, SplitDelimiterBehavior};
 pub use pre_tokenizer::*;
+pub use shards::{IdType, Shard, ShardsManifest, ShardsParams};
 
-pub type Error = Box<dyn std::error::Error + Send + Sync>;
 pub type Result<T> = std::result::Result<T, Error>;
 pub type Offsets = (usize, usize);
 
@@ -72,7 +84,7 @@ pub trait PreTokenizer {
 
 /// Represents a model used during Tokenization (like BPE or Word or Unigram).
 pub trait Model {
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     type Trainer: Trainer + Sync;
     /// Tokenize the given sequence into multiple underlying `Token`. The `offsets` on the `Token`
     /// are expected to be relative to the given sequence.
@@ -88,7 +100,7 @@ pub trait Model {
     /// Save the current `Model` in the given folder, using the given `prefix` for the various
     /// files that need to be saved.
     fn save(&self, folder: &Path, prefix: Option<&str>) -> Result<Vec<PathBuf>>;
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     /// Get an instance of a Trainer capable of training this Model
     fn get_trainer(&self) -> <Self as Model>::Trainer;
 }
@@ -129,7 +141,7 @@ pub trait Decoder {
     fn decode(&self, tokens: Vec<String>) -> Result<String>;
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 /// A `Trainer` has the responsibility to train a model. We feed it with lines/sentences
 /// and then it can train the given `Model`.
 pub trait Trainer {
@@ -270,6 +282,7 @@ pub struct TokenizerBuilder<M, N, PT, PP, D> {
 
     truncation: Option<TruncationParams>,
     padding: Option<PaddingParams>,
+    parallelism: Parallelism,
 }
 
 impl<M, N, PT, PP, D> Default for TokenizerBuilder<M, N, PT, PP, D>
@@ -304,6 +317,7 @@ where
             added_vocabulary: AddedVocabulary::new(),
             truncation: None,
             padding: None,
+            parallelism: Parallelism::default(),
         }
     }
 
@@ -313,7 +327,7 @@ where
     pub fn build(self) -> Result<TokenizerImpl<M, N, PT, PP, D>> {
         let model = self
             .model
-            .ok_or_else(|| Box::new(BuilderError("Model missing.".into())))?;
+            .ok_or_else(|| Error::configuration(BuilderError("Model missing.".into())))?;
         Ok(TokenizerImpl {
             normalizer: self.normalizer,
             pre_tokenizer: self.pre_tokenizer,
@@ -324,6 +338,7 @@ where
             added_vocabulary: self.added_vocabulary,
             truncation: self.truncation,
             padding: self.padding,
+            parallelism: self.parallelism,
         })
     }
 
@@ -368,6 +383,12 @@ where
         self.padding = padding;
         self
     }
+
+    /// Set the parallelism settings.
+    pub fn with_parallelism(mut self, parallelism: Parallelism) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -406,7 +427,7 @@ impl Tokenizer {
 }
 
 impl std::str::FromStr for Tokenizer {
-    type Err = Box<dyn std::error::Error + Send + Sync>;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
         Ok(serde_json::from_str(s)?)
@@ -431,6 +452,7 @@ where
             added_vocabulary: t.added_vocabulary,
             padding: t.padding,
             truncation: t.truncation,
+            parallelism: t.parallelism,
         })
     }
 }
@@ -471,6 +493,9 @@ pub struct TokenizerImpl<M, N, PT, PP, D> {
     // General processing parameters
     truncation: Option<TruncationParams>,
     padding: Option<PaddingParams>,
+
+    // Runtime settings, not serialized
+    parallelism: Parallelism,
 }
 
 impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
@@ -494,6 +519,8 @@ where
 
             truncation: None,
             padding: None,
+
+            parallelism: Parallelism::default(),
         }
     }
 
@@ -584,6 +611,17 @@ where
         self.padding.as_mut()
     }
 
+    /// Set the parallelism settings used by the batch methods and training
+    pub fn with_parallelism(&mut self, parallelism: Parallelism) -> &mut Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Get the currently set parallelism settings
+    pub fn get_parallelism(&self) -> &Parallelism {
+        &self.parallelism
+    }
+
     /// Get the vocabulary
     pub fn get_vocab(&self, with_added_tokens: bool) -> HashMap<String, u32> {
         let mut final_vocab = self.model.get_vocab();
@@ -671,7 +709,7 @@ where
     /// sequences. Also, a sequence can be a string, or already pre-tokenized input directly:
     ///
     /// ```
-    /// # #[cfg(not(feature = "bert"))] {
+    /// # #[cfg(feature = "bpe")] {
     /// # use tokenizers::Tokenizer;
     /// # use tokenizers::models::bpe::BPE;
     /// # let mut tokenizer = Tokenizer::new(BPE::default());
@@ -690,7 +728,6 @@ where
     /// // or even both types together:
     /// tokenizer.encode(("A complete sequence", &["And", "a", "tokenized"][..]), false);
     /// # }
-    /// # #[cfg(feature = "bert")] fn main() {}
     /// ```
     pub fn encode<'s, E>(&self, input: E, add_special_tokens: bool) -> Result<Encoding>
     where
@@ -717,7 +754,7 @@ where
     /// a sequence can be a string, or already pre-tokenized input directly:
     ///
     /// ```
-    /// # #[cfg(not(feature = "bert"))] {
+    /// # #[cfg(feature = "bpe")] {
     /// # use tokenizers::Tokenizer;
     /// # use tokenizers::models::bpe::BPE;
     /// # let mut tokenizer = Tokenizer::new(BPE::default());
@@ -736,7 +773,6 @@ where
     /// // or even both types together:
     /// tokenizer.encode(("A complete sequence", &["And", "a", "tokenized"][..]), false);
     /// # }
-    /// # #[cfg(feature = "bert")] fn main() {}
     /// ```
     pub fn encode_char_offsets<'s, E>(&self, input: E, add_special_tokens: bool) -> Result<Encoding>
     where
@@ -798,7 +834,7 @@ where
     }
 }
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
 where
     N: Normalizer,
@@ -865,45 +901,74 @@ where
         add_special_tokens: bool,
     ) -> Result<Encoding> {
         // 1. First we truncate if needed
-        let (encoding, pair_encoding) = {
-            if let Some(trunc) = &self.truncation {
-                let n_added_tokens = if let Some(processor) = &self.post_processor {
-                    processor.added_tokens(pair_encoding.is_some())
-                } else {
-                    0
-                };
+        let (encoding, pair_encoding) =
+            self.do_truncate(encoding, pair_encoding, add_special_tokens)?;
 
-                if add_special_tokens && n_added_tokens > 0 {
-                    let params = TruncationParams {
-                        max_length: trunc.max_length - n_added_tokens,
-                        ..*trunc
-                    };
-                    truncate_encodings(encoding, pair_encoding, &params)?
-                } else {
-                    truncate_encodings(encoding, pair_encoding, &trunc)?
-                }
-            } else {
-                (encoding, pair_encoding)
+        // 2. Then We post process
+        let final_encoding = self.do_post_process(encoding, pair_encoding, add_special_tokens)?;
+
+        // 3. Then we pad if needed
+        self.do_pad(final_encoding)
+    }
+
+    /// The truncation parameters to use, taking into account the room needed for the special
+    /// tokens added by the PostProcessor
+    fn truncation_params(
+        &self,
+        is_pair: bool,
+        add_special_tokens: bool,
+    ) -> Option<TruncationParams> {
+        self.truncation.as_ref().map(|trunc| {
+            let n_added_tokens = match &self.post_processor {
+                Some(processor) if add_special_tokens => processor.added_tokens(is_pair),
+                _ => 0,
+            };
+
+            TruncationParams {
+                max_length: trunc.max_length - n_added_tokens,
+                ..trunc.clone()
             }
-        };
+        })
+    }
 
-        // 2. Then We post process
-        let final_encoding = if let Some(processor) = &self.post_processor {
-            processor.process(encoding, pair_encoding, add_special_tokens)?
+    /// Truncation logic, handling the case where there is no truncation set
+    fn do_truncate(
+        &self,
+        encoding: Encoding,
+        pair_encoding: Option<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<(Encoding, Option<Encoding>)> {
+        match self.truncation_params(pair_encoding.is_some(), add_special_tokens) {
+            Some(params) => truncate_encodings(encoding, pair_encoding, &params),
+            None => Ok((encoding, pair_encoding)),
+        }
+    }
+
+    /// Process the encodings with the PostProcessor, or the default processing if none is set
+    fn do_post_process(
+        &self,
+        encoding: Encoding,
+        pair_encoding: Option<Encoding>,
+        add_special_tokens: bool,
+    ) -> Result<Encoding> {
+        if let Some(processor) = &self.post_processor {
+            processor.process(encoding, pair_encoding, add_special_tokens)
         } else {
-            PostProcessor::default_process(encoding, pair_encoding, add_special_tokens)?
-        };
+            PostProcessor::default_process(encoding, pair_encoding, add_special_tokens)
+        }
+    }
 
-        // 3. Then we pad if needed
-        let [final_encoding] = if let Some(params) = &self.padding {
-            let mut arr = [final_encoding];
+    /// Padding logic, handling the case where there is no padding set
+    fn do_pad(&self, encoding: Encoding) -> Result<Encoding> {
+        let [encoding] = if let Some(params) = &self.padding {
+            let mut arr = [encoding];
             pad_encodings(&mut arr, params)?;
             arr
         } else {
-            [final_encoding]
+            [encoding]
         };
 
-        Ok(final_encoding)
+        Ok(encoding)
     }
 }
 
@@ -924,17 +989,19 @@ where
     where
         E: Into<EncodeInput<'s>> + Send,
     {
-        let mut encodings = inputs
-            .into_maybe_par_iter()
-            .map(|input| self.encode(input, add_special_tokens))
-            .collect::<Result<Vec<Encoding>>>()?;
-
-        if let Some(params) = &self.padding {
-            // We do the padding here to make sure we handle the batch padding
-            pad_encodings(&mut encodings, &params)?;
-        }
+        self.parallelism.install(|| {
+            let mut encodings = inputs
+                .into_maybe_par_iter()
+                .map(|input| self.encode(input, add_special_tokens))
+                .collect::<Result<Vec<Encoding>>>()?;
+
+            if let Some(params) = &self.padding {
+                // We do the padding here to make sure we handle the batch padding
+                pad_encodings(&mut encodings, &params)?;
+            }
 
-        Ok(encodings)
+            Ok(encodings)
+        })
     }
 
     /// Encode all the sentences in parallel, using multiple threads.
@@ -947,17 +1014,19 @@ where
     where
         E: Into<EncodeInput<'s>> + Send,
     {
-        let mut encodings = inputs
-            .into_maybe_par_iter()
-            .map(|input| self.encode_char_offsets(input, add_special_tokens))
-            .collect::<Result<Vec<Encoding>>>()?;
-
-        if let Some(params) = &self.padding {
-            // We do the padding here to make sure we handle the batch padding
-            pad_encodings(&mut encodings, &params)?;
-        }
+        self.parallelism.install(|| {
+            let mut encodings = inputs
+                .into_maybe_par_iter()
+                .map(|input| self.encode_char_offsets(input, add_special_tokens))
+                .collect::<Result<Vec<Encoding>>>()?;
+
+            if let Some(params) = &self.padding {
+                // We do the padding here to make sure we handle the batch padding
+                pad_encodings(&mut encodings, &params)?;
+            }
 
-        Ok(encodings)
+            Ok(encodings)
+        })
     }
 
     /// Decode all sentences in parallel
@@ -969,15 +1038,27 @@ where
     where
         M: Send + Sync,
     {
-        sentences
-            .into_maybe_par_iter()
-            .map(|sentence| self.decode(sentence, skip_special_tokens))
-            .collect()
+        self.parallelism.install(|| {
+            sentences
+                .into_maybe_par_iter()
+                .map(|sentence| self.decode(sentence, skip_special_tokens))
+                .collect()
+        })
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     /// Train our Model from files
     pub fn train_from_files<T>(&mut self, trainer: &mut T, files: Vec<String>) -> Result<&mut Self>
+    where
+        T: Trainer<Model = M> + Send + Sync,
+    {
+        let parallelism = self.parallelism.clone();
+        parallelism.install(|| self.do_train_from_files(trainer, files))?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "training")]
+    fn do_train_from_files<T>(&mut self, trainer: &mut T, files: Vec<String>) -> Result<()>
     where
         T: Trainer<Model = M> + Sync,
     {
@@ -1044,13 +1125,24 @@ where
 
                 Ok(())
             },
-        )??;
-        Ok(self)
+        )?
     }
 
-    #[cfg(not(feature = "bert"))]
+    #[cfg(feature = "training")]
     /// Train our Model, using the given Trainer and iterator
     pub fn train<T, I, S>(&mut self, trainer: &mut T, sequences: I) -> Result<&mut Self>
+    where
+        T: Trainer<Model = M> + Send + Sync,
+        I: Iterator<Item = S> + Send,
+        S: AsRef<str> + Send,
+    {
+        let parallelism = self.parallelism.clone();
+        parallelism.install(|| self.do_train(trainer, sequences))?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "training")]
+    fn do_train<T, I, S>(&mut self, trainer: &mut T, sequences: I) -> Result<()>
     where
         T: Trainer<Model = M> + Sync,
         I: Iterator<Item = S> + Send,
@@ -1100,7 +1192,7 @@ where
         let special_tokens = trainer.train(&mut self.model)?;
         self.add_special_tokens(&special_tokens);
 
-        Ok(self)
+        Ok(())
     }
 }
 
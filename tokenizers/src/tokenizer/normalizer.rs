@@ -1,4 +1,6 @@
 use crate::pattern::Pattern;
+use crate::utils::case_folding::case_fold;
+use crate::utils::special_casing::{locale_language, special_lowercase};
 use crate::{Offsets, Result};
 use std::ops::{Bound, RangeBounds};
 use unicode_normalization_alignments::UnicodeNormalization;
@@ -124,7 +126,7 @@ pub struct NormalizedString {
 }
 
 impl NormalizedString {
-    #[cfg(all(test, not(feature = "bert")))]
+    #[cfg(test)]
     pub(crate) fn new(
         original: String,
         normalized: String,
@@ -371,12 +373,12 @@ impl NormalizedString {
 
                 let idx = offset as usize;
                 let align = if changes.is_positive() {
-                    if idx < 1 {
-                        (0, 0)
-                    } else {
-                        // This is a newly inserted character, so it shares the same alignment
-                        // than the previous one
-                        self.alignments[idx - 1]
+                    // This is a newly inserted character, so it shares the same alignment
+                    // than the previous one, even if that one removed some following chars
+                    match alignments.last() {
+                        Some(align) => *align,
+                        None if idx < 1 => (0, 0),
+                        None => self.alignments[idx - 1],
                     }
                 } else {
                     self.alignments[idx]
@@ -553,6 +555,47 @@ impl NormalizedString {
         self
     }
 
+    /// Lowercase, applying the conditional mappings of the Unicode SpecialCasing for the language
+    /// of the given `locale` (like `tr`, `az` or `lt`), and the final sigma in any language
+    pub fn lowercase_with_locale(&mut self, locale: &str) -> &mut Self {
+        let language = locale_language(locale);
+        let chars = self.normalized.chars().collect::<Vec<_>>();
+        let mut new_chars: Vec<(char, isize)> = vec![];
+        let mut initial_offset = 0;
+        for (index, c) in chars.iter().enumerate() {
+            let special = special_lowercase(&chars, index, &language);
+            match special {
+                // The removed dot above follows the `I`, or a combining mark, each mapped to one char
+                Some("") => match new_chars.last_mut() {
+                    Some((_, change)) => *change -= 1,
+                    None => initial_offset += 1,
+                },
+                Some(lowercase) => lowercase.chars().enumerate().for_each(|(index, c)| {
+                    new_chars.push((c, if index > 0 { 1 } else { 0 }));
+                }),
+                None => c.to_lowercase().enumerate().for_each(|(index, c)| {
+                    new_chars.push((c, if index > 0 { 1 } else { 0 }));
+                }),
+            }
+        }
+        self.transform(new_chars, initial_offset);
+        self
+    }
+
+    /// Apply the full Unicode case folding, which maps for example both `ß` and `ẞ` to `ss`, and
+    /// the final sigma to `σ`
+    pub fn casefold(&mut self) -> &mut Self {
+        let mut new_chars: Vec<(char, isize)> = vec![];
+        self.for_each(|c| match case_fold(c) {
+            Some(folded) => folded.chars().enumerate().for_each(|(index, c)| {
+                new_chars.push((c, if index > 0 { 1 } else { 0 }));
+            }),
+            None => new_chars.push((c, 0)),
+        });
+        self.transform(new_chars, 0);
+        self
+    }
+
     /// Uppercase
     pub fn uppercase(&mut self) -> &mut Self {
         let mut new_chars: Vec<(char, isize)> = vec![];
@@ -595,6 +638,42 @@ impl NormalizedString {
         Ok(())
     }
 
+    /// Replace each of the given normalized ranges with its content, in a single pass. Each char
+    /// of a content is aligned with its whole replaced range, so that any part of the content
+    /// maps back to all of it. The ranges must be sorted and must not overlap.
+    /// Returns `None`, leaving the string untouched, if any of the ranges is not valid.
+    pub fn replace_ranges<'a, I>(&mut self, replacements: I) -> Option<()>
+    where
+        I: IntoIterator<Item = (std::ops::Range<usize>, &'a str)>,
+    {
+        let mut normalized = String::with_capacity(self.normalized.len());
+        let mut alignments = Vec::with_capacity(self.alignments.len());
+        let mut last = 0;
+        for (range, content) in replacements {
+            if range.start < last || range.end < range.start {
+                return None;
+            }
+            normalized.push_str(self.normalized.get(last..range.start)?);
+            alignments.extend_from_slice(&self.alignments[last..range.start]);
+            self.normalized.get(range.clone())?;
+
+            let align = match expand_alignments(&self.alignments[range.clone()]) {
+                Some(original) => (original.start, original.end),
+                // Like any newly inserted char, it shares the alignment of the previous one
+                None => alignments.last().copied().unwrap_or((0, 0)),
+            };
+            normalized.push_str(content);
+            alignments.resize(alignments.len() + content.len(), align);
+            last = range.end;
+        }
+        normalized.push_str(&self.normalized[last..]);
+        alignments.extend_from_slice(&self.alignments[last..]);
+
+        self.normalized = normalized;
+        self.alignments = alignments;
+        Some(())
+    }
+
     /// Clear the normalized part of the string
     pub fn clear(&mut self) -> usize {
         let len = self.len();
@@ -1077,6 +1156,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn added_after_removal() {
+        // The added `h` shares the alignment of the `s`, not the one of the removed `'`
+        let mut n = NormalizedString::from("as'b");
+        n.transform(vec![('a', 0), ('s', -1), ('h', 1), ('b', 0)], 0);
+        assert_eq!(n.get(), "ashb");
+        assert_eq!(&n.alignments, &[(0, 1), (1, 2), (1, 2), (3, 4)]);
+    }
+
     #[test]
     fn range_conversion() {
         let mut n = NormalizedString::from("    __Hello__   ");
@@ -1394,6 +1482,29 @@ mod tests {
         assert_eq!(s.get(), "_Hello_friend_");
     }
 
+    #[test]
+    fn replace_ranges() {
+        let mut s = NormalizedString::from("caf&#233; &amp; ok");
+        s.replace_ranges(vec![(3..9, "é"), (10..15, "&")]).unwrap();
+        assert_eq!(s.get(), "café & ok");
+        assert_eq!(
+            s.get_range_original(Range::Normalized(0..5)),
+            Some("caf&#233;")
+        );
+        // Any part of the new content maps back to the whole replaced range
+        assert_eq!(
+            s.get_range_original(Range::Normalized(3..5)),
+            Some("&#233;")
+        );
+        assert_eq!(s.get_range_original(Range::Normalized(6..7)), Some("&amp;"));
+        assert_eq!(s.get_range_original(Range::Normalized(7..10)), Some(" ok"));
+
+        // Invalid ranges leave the string untouched
+        assert!(s.replace_ranges(vec![(4..5, "e")]).is_none());
+        assert!(s.replace_ranges(vec![(6..7, "+"), (0..1, "C")]).is_none());
+        assert_eq!(s.get(), "café & ok");
+    }
+
     #[test]
     fn split() {
         use SplitDelimiterBehavior::*;
@@ -221,6 +221,11 @@ impl PreTokenizedString {
             })
             .collect()
     }
+
+    /// Returns an iterator over the `NormalizedString` of each split
+    pub(crate) fn normalized_splits(&self) -> impl Iterator<Item = &NormalizedString> {
+        self.splits.iter().map(|split| &split.normalized)
+    }
 }
 
 impl From<NormalizedString> for PreTokenizedString {
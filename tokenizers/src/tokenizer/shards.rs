@@ -0,0 +1,508 @@
+//! Encoding of a whole corpus into binary shards of token ids, ready to be memory-mapped.
+//!
+//! The output directory contains:
+//!   - `manifest.json`: The parameters, the list of shards, and where to resume from.
+//!   - `NNNNN.bin`: The ids of all the tokens of the shard, as little-endian `u16` or `u32`.
+//!   - `NNNNN.idx`: The offsets of the documents in the shard, as little-endian `u64`, counted in
+//!     tokens: `documents + 1` values, starting with 0, such that the document `i` covers the
+//!     tokens `idx[i]..idx[i + 1]`.
+use crate::tokenizer::{
+    Decoder, Encoding, Error, LinesWithEnding, Model, Normalizer, PostProcessor, PreTokenizer,
+    Result, TokenizerImpl,
+};
+use crate::utils::parallelism::*;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const MANIFEST: &str = "manifest.json";
+
+/// The type used to store each id in the shards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdType {
+    U16,
+    U32,
+}
+
+impl IdType {
+    fn write<W: Write>(self, writer: &mut W, id: u32) -> Result<()> {
+        match self {
+            IdType::U16 => {
+                if id > u32::from(u16::MAX) {
+                    return Err(Error::configuration(format!(
+                        "Id {} does not fit in a u16, use u32 instead",
+                        id
+                    )));
+                }
+                writer.write_all(&(id as u16).to_le_bytes())?;
+            }
+            IdType::U32 => writer.write_all(&id.to_le_bytes())?,
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for IdType {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "u16" => Ok(IdType::U16),
+            "u32" => Ok(IdType::U32),
+            _ => Err(Error::configuration(format!(
+                "Unknown id type {}, expected u16 or u32",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShardsParams {
+    /// The minimum number of tokens in each shard. A shard is complete as soon as it reaches
+    /// this size, since documents are never split across shards.
+    pub shard_size: usize,
+    pub id_type: IdType,
+    pub add_special_tokens: bool,
+    /// The number of documents encoded in parallel at once
+    pub batch_size: usize,
+}
+
+impl Default for ShardsParams {
+    fn default() -> Self {
+        Self {
+            shard_size: 100_000_000,
+            id_type: IdType::U16,
+            add_special_tokens: true,
+            batch_size: 10_000,
+        }
+    }
+}
+
+/// A shard written on disk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shard {
+    /// The name of the shard, without extension
+    pub name: String,
+    pub documents: usize,
+    pub tokens: usize,
+}
+
+/// The content of `manifest.json`, describing the shards written so far
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardsManifest {
+    pub id_type: IdType,
+    pub add_special_tokens: bool,
+    /// The input files, in order
+    pub files: Vec<String>,
+    /// The shards, in order
+    pub shards: Vec<Shard>,
+    /// The index of the input file to resume from
+    pub next_file: usize,
+    /// The offset in bytes, in this input file, to resume from
+    pub next_offset: u64,
+    /// Whether all the input files have been processed
+    pub complete: bool,
+}
+
+impl ShardsManifest {
+    fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(MANIFEST);
+        if path.exists() {
+            Ok(Some(serde_json::from_str(&fs::read_to_string(path)?)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Atomically replace the manifest on disk
+    fn save(&self, dir: &Path) -> Result<()> {
+        let tmp = dir.join(format!("{}.tmp", MANIFEST));
+        fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(tmp, dir.join(MANIFEST))?;
+        Ok(())
+    }
+}
+
+/// The shard being written
+struct ShardWriter {
+    name: String,
+    ids: BufWriter<File>,
+    index: BufWriter<File>,
+    documents: usize,
+    tokens: usize,
+}
+
+impl ShardWriter {
+    fn create(dir: &Path, n: usize) -> Result<Self> {
+        let name = format!("{:05}", n);
+        let mut index = BufWriter::new(File::create(dir.join(format!("{}.idx", name)))?);
+        index.write_all(&0u64.to_le_bytes())?;
+
+        Ok(Self {
+            ids: BufWriter::new(File::create(dir.join(format!("{}.bin", name)))?),
+            name,
+            index,
+            documents: 0,
+            tokens: 0,
+        })
+    }
+
+    fn write(&mut self, encoding: &Encoding, id_type: IdType) -> Result<()> {
+        for id in encoding.get_ids() {
+            id_type.write(&mut self.ids, *id)?;
+        }
+        self.documents += 1;
+        self.tokens += encoding.len();
+        self.index.write_all(&(self.tokens as u64).to_le_bytes())?;
+        Ok(())
+    }
+
+    /// Make sure everything is on disk, and return the description of this shard
+    fn finish(self) -> Result<Shard> {
+        for writer in &mut [self.ids, self.index] {
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+        }
+
+        Ok(Shard {
+            name: self.name,
+            documents: self.documents,
+            tokens: self.tokens,
+        })
+    }
+}
+
+impl<M, N, PT, PP, D> TokenizerImpl<M, N, PT, PP, D>
+where
+    M: Model + Send + Sync,
+    N: Normalizer + Send + Sync,
+    PT: PreTokenizer + Send + Sync,
+    PP: PostProcessor + Send + Sync,
+    D: Decoder + Send + Sync,
+{
+    /// Encode all the given files into binary shards of token ids, written in `output_dir`.
+    /// Each line of the input files is a document, and documents keep the order of the input.
+    ///
+    /// The documents are encoded in parallel, in batches of `batch_size`. The truncation and
+    /// padding of the tokenizer apply to each document, so they should usually be disabled.
+    ///
+    /// The manifest gets updated each time a shard is complete. If `output_dir` already
+    /// contains one, the encoding resumes right after the last complete shard, as long as the
+    /// files and parameters are the same.
+    pub fn encode_to_shards<P: AsRef<Path>>(
+        &self,
+        files: Vec<String>,
+        output_dir: P,
+        params: &ShardsParams,
+    ) -> Result<ShardsManifest> {
+        let dir = output_dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut manifest = match ShardsManifest::load(dir)? {
+            Some(manifest) => {
+                if manifest.files != files
+                    || manifest.id_type != params.id_type
+                    || manifest.add_special_tokens != params.add_special_tokens
+                {
+                    return Err(Error::configuration(format!(
+                        "{} contains shards for different files or parameters",
+                        dir.display()
+                    )));
+                }
+                manifest
+            }
+            None => ShardsManifest {
+                id_type: params.id_type,
+                add_special_tokens: params.add_special_tokens,
+                files,
+                shards: vec![],
+                next_file: 0,
+                next_offset: 0,
+                complete: false,
+            },
+        };
+        if manifest.complete {
+            return Ok(manifest);
+        }
+        remove_partial_shards(dir, manifest.shards.len())?;
+
+        let mut shard: Option<ShardWriter> = None;
+        let mut batch = Vec::with_capacity(params.batch_size);
+        for file_idx in manifest.next_file..manifest.files.len() {
+            let mut file = File::open(&manifest.files[file_idx])?;
+            let mut offset = 0;
+            if file_idx == manifest.next_file {
+                offset = manifest.next_offset;
+                file.seek(SeekFrom::Start(offset))?;
+            }
+
+            for line in BufReader::new(file).lines_with_ending() {
+                let line = line?;
+                offset += line.len() as u64;
+                batch.push((line, file_idx, offset));
+
+                if batch.len() >= params.batch_size.max(1) {
+                    self.write_batch(&mut batch, dir, params, &mut manifest, &mut shard)?;
+                }
+            }
+        }
+        self.write_batch(&mut batch, dir, params, &mut manifest, &mut shard)?;
+
+        if let Some(shard) = shard {
+            manifest.shards.push(shard.finish()?);
+        }
+        if let Some(last) = manifest.files.len().checked_sub(1) {
+            manifest.next_file = last;
+            manifest.next_offset = fs::metadata(&manifest.files[last])?.len();
+        }
+        manifest.complete = true;
+        manifest.save(dir)?;
+
+        Ok(manifest)
+    }
+
+    /// Encode the given batch of documents, and write them to the shards, emptying the batch
+    fn write_batch(
+        &self,
+        batch: &mut Vec<(String, usize, u64)>,
+        dir: &Path,
+        params: &ShardsParams,
+        manifest: &mut ShardsManifest,
+        shard: &mut Option<ShardWriter>,
+    ) -> Result<()> {
+        let encodings = self.parallelism.install(|| {
+            batch
+                .maybe_par_iter()
+                .map(|(line, _, _)| {
+                    let document = line.trim_end_matches(&['\n', '\r'][..]);
+                    self.encode(document, params.add_special_tokens)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        for (encoding, (_, file_idx, offset)) in encodings.iter().zip(batch.drain(..)) {
+            let writer = match shard {
+                Some(writer) => writer,
+                None => shard.get_or_insert(ShardWriter::create(dir, manifest.shards.len())?),
+            };
+            writer.write(encoding, params.id_type)?;
+
+            if writer.tokens >= params.shard_size {
+                manifest.shards.push(shard.take().unwrap().finish()?);
+                manifest.next_file = file_idx;
+                manifest.next_offset = offset;
+                manifest.save(dir)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove the files of any shard left incomplete by a previous run
+fn remove_partial_shards(dir: &Path, n_complete: usize) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path: PathBuf = entry?.path();
+        let is_shard = matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("bin") | Some("idx")
+        );
+        let n = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<usize>().ok());
+        if let (true, Some(n)) = (is_shard, n) {
+            if n >= n_complete {
+                fs::remove_file(path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "wordlevel"))]
+mod tests {
+    use super::*;
+    use crate::models::wordlevel::WordLevel;
+    use crate::pre_tokenizers::whitespace::Whitespace;
+    use crate::Tokenizer;
+
+    fn tokenizer() -> Tokenizer {
+        let vocab = (0..10)
+            .map(|i| (format!("w{}", i), i))
+            .chain(std::iter::once(("[UNK]".into(), 10)))
+            .collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("[UNK]".into())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Whitespace);
+        tokenizer
+    }
+
+    fn read_u64(path: PathBuf) -> Vec<u64> {
+        fs::read(path)
+            .unwrap()
+            .chunks(8)
+            .map(|c| {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(c);
+                u64::from_le_bytes(bytes)
+            })
+            .collect()
+    }
+
+    fn read_u16(path: PathBuf) -> Vec<u16> {
+        fs::read(path)
+            .unwrap()
+            .chunks(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect()
+    }
+
+    fn write_inputs(dir: &Path) -> Vec<String> {
+        let inputs = vec![
+            ("a.txt", "w1 w2 w3\nw4\r\n\nw5 w6 w7 w8\n"),
+            ("b.txt", "w9 w0 x\nw1"),
+        ];
+        inputs
+            .into_iter()
+            .map(|(name, content)| {
+                let path = dir.join(name);
+                fs::write(&path, content).unwrap();
+                path.to_str().unwrap().to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_to_shards() {
+        let inputs = tempfile::tempdir().unwrap();
+        let files = write_inputs(inputs.path());
+        let output = tempfile::tempdir().unwrap();
+        let params = ShardsParams {
+            shard_size: 4,
+            batch_size: 2,
+            ..Default::default()
+        };
+
+        let manifest = tokenizer()
+            .encode_to_shards(files.clone(), output.path(), &params)
+            .unwrap();
+        assert!(manifest.complete);
+        let shards = manifest
+            .shards
+            .iter()
+            .map(|s| (s.name.as_str(), s.documents, s.tokens))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shards,
+            vec![("00000", 2, 4), ("00001", 2, 4), ("00002", 2, 4)]
+        );
+
+        let read_shard = |name: &str| {
+            (
+                read_u16(output.path().join(format!("{}.bin", name))),
+                read_u64(output.path().join(format!("{}.idx", name))),
+            )
+        };
+        assert_eq!(read_shard("00000"), (vec![1, 2, 3, 4], vec![0, 3, 4]));
+        // The empty line is kept as an empty document
+        assert_eq!(read_shard("00001"), (vec![5, 6, 7, 8], vec![0, 0, 4]));
+        assert_eq!(read_shard("00002"), (vec![9, 0, 10, 1], vec![0, 3, 4]));
+
+        // Running again on a complete output does nothing
+        assert_eq!(
+            tokenizer()
+                .encode_to_shards(files, output.path(), &params)
+                .unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    fn resume() {
+        let inputs = tempfile::tempdir().unwrap();
+        let files = write_inputs(inputs.path());
+        let params = ShardsParams {
+            shard_size: 4,
+            batch_size: 3,
+            ..Default::default()
+        };
+
+        let expected = tempfile::tempdir().unwrap();
+        let expected_manifest = tokenizer()
+            .encode_to_shards(files.clone(), expected.path(), &params)
+            .unwrap();
+
+        // Simulate an interruption after the first shard, in the middle of the second one
+        let output = tempfile::tempdir().unwrap();
+        let mut manifest = expected_manifest.clone();
+        manifest.shards.truncate(1);
+        manifest.next_file = 0;
+        manifest.next_offset = 13;
+        manifest.complete = false;
+        manifest.save(output.path()).unwrap();
+        for ext in &["bin", "idx"] {
+            let name = format!("00000.{}", ext);
+            fs::copy(expected.path().join(&name), output.path().join(&name)).unwrap();
+            fs::write(output.path().join(format!("00001.{}", ext)), b"partial").unwrap();
+        }
+
+        let manifest = tokenizer()
+            .encode_to_shards(files.clone(), output.path(), &params)
+            .unwrap();
+        assert_eq!(manifest, expected_manifest);
+        for shard in &manifest.shards {
+            for ext in &["bin", "idx"] {
+                let name = format!("{}.{}", shard.name, ext);
+                assert_eq!(
+                    fs::read(output.path().join(&name)).unwrap(),
+                    fs::read(expected.path().join(&name)).unwrap()
+                );
+            }
+        }
+
+        // Different parameters can't be resumed
+        let params = ShardsParams {
+            id_type: IdType::U32,
+            ..params
+        };
+        assert!(tokenizer()
+            .encode_to_shards(files, output.path(), &params)
+            .is_err());
+    }
+
+    #[test]
+    fn ids_too_big() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "w1").unwrap();
+        let files = vec![file.path().to_str().unwrap().to_owned()];
+        let output = tempfile::tempdir().unwrap();
+
+        let mut vocab = std::collections::HashMap::new();
+        vocab.insert("w1".into(), 70_000);
+        let tokenizer = Tokenizer::new(WordLevel::builder().vocab(vocab).build().unwrap());
+        assert!(tokenizer
+            .encode_to_shards(files.clone(), output.path(), &Default::default())
+            .is_err());
+
+        let params = ShardsParams {
+            id_type: IdType::U32,
+            ..Default::default()
+        };
+        let output = tempfile::tempdir().unwrap();
+        tokenizer
+            .encode_to_shards(files, output.path(), &params)
+            .unwrap();
+        assert_eq!(
+            fs::read(output.path().join("00000.bin")).unwrap(),
+            70_000u32.to_le_bytes()
+        );
+    }
+}
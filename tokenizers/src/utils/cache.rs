@@ -58,6 +58,7 @@ where
     }
 
     /// Clear the cache.
+    #[cfg(feature = "bpe")]
     pub(crate) fn clear(&self) {
         self.map.write().unwrap().clear();
     }
@@ -0,0 +1,130 @@
+use crate::tokenizer::pattern::Pattern;
+use crate::{Offsets, Result};
+
+/// A regex backed by the pure-Rust [fancy-regex](https://crates.io/crates/fancy-regex) library.
+/// It supports the look-arounds we need, while not requiring any C library.
+#[derive(Debug)]
+pub struct SysRegex {
+    regex: ::fancy_regex::Regex,
+}
+
+impl SysRegex {
+    pub fn new(regex_str: &str) -> Result<Self> {
+        Ok(Self {
+            regex: ::fancy_regex::Regex::new(regex_str)?,
+        })
+    }
+
+    /// Returns an iterator over the `(start, end)` byte offsets of each match in `inside`.
+    ///
+    /// The iteration stops on the first error (if the backtracking limit is reached for example).
+    /// Use it as a `Pattern` to get these errors back.
+    pub fn find_iter<'r, 't>(&'r self, inside: &'t str) -> Matches<'r, 't> {
+        Matches(self.regex.find_iter(inside))
+    }
+}
+
+pub struct Matches<'r, 't>(::fancy_regex::Matches<'r, 't>);
+
+impl<'r, 't> Iterator for Matches<'r, 't> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0.next() {
+            Some(Ok(m)) => Some((m.start(), m.end())),
+            None | Some(Err(_)) => None,
+        }
+    }
+}
+
+impl Pattern for &SysRegex {
+    fn find_matches(&self, inside: &str) -> Result<Vec<(Offsets, bool)>> {
+        if inside.is_empty() {
+            return Ok(vec![((0, 0), false)]);
+        }
+
+        let mut prev = 0;
+        let mut splits = Vec::with_capacity(inside.len());
+        for m in self.regex.find_iter(inside) {
+            let m = m?;
+            if prev != m.start() {
+                splits.push(((prev, m.start()), false));
+            }
+            splits.push(((m.start(), m.end()), true));
+            prev = m.end();
+        }
+        if prev != inside.len() {
+            splits.push(((prev, inside.len()), false))
+        }
+        Ok(splits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GPT2: &str =
+        r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+";
+
+    fn matches(regex: &SysRegex, inside: &str) -> Vec<(usize, usize)> {
+        regex.find_iter(inside).collect()
+    }
+
+    #[test]
+    fn gpt2_pattern() {
+        let re = SysRegex::new(GPT2).unwrap();
+        let input = "Hello my friend,  how's it going?";
+        let words = matches(&re, input)
+            .into_iter()
+            .map(|(start, end)| &input[start..end])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            words,
+            vec!["Hello", " my", " friend", ",", " ", " how", "'s", " it", " going", "?"]
+        );
+    }
+
+    #[test]
+    fn pattern_covers_input() {
+        let re = SysRegex::new(r"(?<=a)b").unwrap();
+        assert_eq!(
+            (&re).find_matches("abcb").unwrap(),
+            vec![((0, 1), false), ((1, 2), true), ((2, 4), false)]
+        );
+        assert_eq!((&re).find_matches("").unwrap(), vec![((0, 0), false)]);
+    }
+
+    #[cfg(feature = "onig")]
+    #[test]
+    fn same_as_onig() {
+        let patterns = [
+            GPT2,
+            r"\s+(?!\S)",
+            r"(?<=\d)(?=(\d{3})+\b)",
+            r"(?<![A-Z])[A-Z]",
+            r"\p{P}+",
+        ];
+        let inputs = [
+            "Hello my friend, how is your day going?",
+            "   leading and trailing spaces   ",
+            "I'm sure they'll say we've got 1234 reasons",
+            "Tabs\tand\nnew\r\nlines  \n\n",
+            "𝔾𝕠𝕠𝕕 𝕞𝕠𝕣𝕟𝕚𝕟𝕘 ça va? 日本語のテキスト ١٢٣",
+            "",
+        ];
+        for pattern in patterns.iter() {
+            let fancy = SysRegex::new(pattern).unwrap();
+            let onig = crate::utils::onig::SysRegex::new(pattern).unwrap();
+            for input in inputs.iter() {
+                assert_eq!(
+                    matches(&fancy, input),
+                    onig.find_iter(input).collect::<Vec<_>>(),
+                    "pattern {:?} on {:?}",
+                    pattern,
+                    input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use crate::tokenizer::{Encoding, Error, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+
+/// The parameters of `WholeWordMasking`
+#[derive(Debug, Clone)]
+pub struct MaskingParams {
+    /// The probability for each word to be selected for masking
+    pub probability: f64,
+    /// Among the tokens of the selected words, the proportion replaced by the mask token
+    pub mask_ratio: f64,
+    /// Among the tokens of the selected words, the proportion replaced by a random token. All
+    /// the others are left unchanged.
+    pub random_ratio: f64,
+    /// The mask token, whose id is looked up in the vocabulary
+    pub mask_token: String,
+    /// The ids that are never used as random replacement, like the ids of the special tokens.
+    /// The mask token is always left out.
+    pub excluded_ids: HashSet<u32>,
+    /// The seed of the random number generator
+    pub seed: u64,
+}
+
+impl Default for MaskingParams {
+    fn default() -> Self {
+        Self {
+            probability: 0.15,
+            mask_ratio: 0.8,
+            random_ratio: 0.1,
+            mask_token: String::from("[MASK]"),
+            excluded_ids: HashSet::new(),
+            seed: 42,
+        }
+    }
+}
+
+/// Prepares the inputs of a masked language model, using whole word masking: when a word is
+/// selected, all of its tokens get masked together.
+///
+/// The random number generator is seeded once, and then keeps its state from one batch to the
+/// next, so the same sequence of batches always gets masked the same way.
+#[derive(Debug, Clone)]
+pub struct WholeWordMasking {
+    params: MaskingParams,
+    mask_id: u32,
+    /// The tokens that can be used as random replacement, sorted by id
+    vocab: Vec<(u32, String)>,
+    rng: StdRng,
+}
+
+impl WholeWordMasking {
+    /// Build a new `WholeWordMasking` with the given parameters. The id of the mask token is
+    /// looked up in `vocab`, and the random replacement tokens are drawn from it, except for the
+    /// mask token and the `excluded_ids`. It usually comes from `get_vocab(true)` on the
+    /// `Tokenizer`, with the ids of the special tokens excluded.
+    pub fn new(params: MaskingParams, vocab: &HashMap<String, u32>) -> Result<Self> {
+        let is_ratio = |r: f64| (0.0..=1.0).contains(&r);
+        if !is_ratio(params.probability)
+            || !is_ratio(params.mask_ratio)
+            || !is_ratio(params.random_ratio)
+            || params.mask_ratio + params.random_ratio > 1.0
+        {
+            return Err(Error::configuration(format!(
+                "Invalid masking ratios: probability {}, mask {}, random {}",
+                params.probability, params.mask_ratio, params.random_ratio
+            )));
+        }
+        let mask_id = *vocab.get(&params.mask_token).ok_or_else(|| {
+            Error::configuration(format!(
+                "The mask token {} is not part of the vocabulary",
+                params.mask_token
+            ))
+        })?;
+
+        let mut vocab = vocab
+            .iter()
+            .filter(|(_, id)| **id != mask_id && !params.excluded_ids.contains(*id))
+            .map(|(token, id)| (*id, token.to_owned()))
+            .collect::<Vec<_>>();
+        vocab.sort_unstable();
+        if vocab.is_empty() && params.random_ratio > 0.0 {
+            return Err(Error::configuration(
+                "A vocabulary is needed to replace tokens with random ones",
+            ));
+        }
+
+        Ok(Self {
+            rng: StdRng::seed_from_u64(params.seed),
+            params,
+            mask_id,
+            vocab,
+        })
+    }
+
+    pub fn get_params(&self) -> &MaskingParams {
+        &self.params
+    }
+
+    pub fn get_mask_id(&self) -> u32 {
+        self.mask_id
+    }
+
+    /// Mask whole words in each of the given encodings, in place, and return the labels: the
+    /// original id of each selected token, and `None` for all the others. Special tokens
+    /// (including padding) are never selected.
+    ///
+    /// Each word is selected with `probability`. Then, each of its tokens is replaced by the
+    /// mask token, replaced by a random token, or left unchanged, following `mask_ratio` and
+    /// `random_ratio` (usually 80%, 10% and 10%).
+    pub fn mask(&mut self, encodings: &mut [Encoding]) -> Vec<Vec<Option<u32>>> {
+        encodings
+            .iter_mut()
+            .map(|encoding| self.mask_encoding(encoding))
+            .collect()
+    }
+
+    fn mask_encoding(&mut self, encoding: &mut Encoding) -> Vec<Option<u32>> {
+        let mut labels = vec![None; encoding.len()];
+        let sequence_ids = encoding.get_sequence_ids();
+
+        let mut start = 0;
+        while start < encoding.len() {
+            let is_maskable = |i: usize| {
+                encoding.get_special_tokens_mask()[i] == 0 && encoding.get_attention_mask()[i] == 1
+            };
+            if !is_maskable(start) {
+                start += 1;
+                continue;
+            }
+
+            // Find the end of the word. Tokens without a word are words on their own.
+            let word = encoding.get_word_ids()[start];
+            let mut end = start + 1;
+            while word.is_some()
+                && end < encoding.len()
+                && is_maskable(end)
+                && encoding.get_word_ids()[end] == word
+                && sequence_ids[end] == sequence_ids[start]
+            {
+                end += 1;
+            }
+
+            if self.rng.gen::<f64>() < self.params.probability {
+                for (i, label) in labels.iter_mut().enumerate().take(end).skip(start) {
+                    *label = Some(encoding.get_ids()[i]);
+
+                    let p = self.rng.gen::<f64>();
+                    if p < self.params.mask_ratio {
+                        encoding.set_token(i, self.mask_id, self.params.mask_token.clone());
+                    } else if p < self.params.mask_ratio + self.params.random_ratio {
+                        let (id, token) = &self.vocab[self.rng.gen_range(0, self.vocab.len())];
+                        encoding.set_token(i, *id, token.clone());
+                    }
+                }
+            }
+
+            start = end;
+        }
+
+        labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::padding::PaddingDirection;
+
+    fn vocab() -> HashMap<String, u32> {
+        let mut vocab = (0..10)
+            .map(|i| (format!("t{}", i), i))
+            .collect::<HashMap<_, _>>();
+        vocab.insert(String::from("[CLS]"), 100);
+        vocab.insert(String::from("[SEP]"), 101);
+        vocab.insert(String::from("[MASK]"), 103);
+        vocab.insert(String::from("[PAD]"), 200);
+        vocab
+    }
+
+    fn special_ids() -> HashSet<u32> {
+        vec![100, 101, 200].into_iter().collect()
+    }
+
+    // [CLS] w0 w0 w1 w2 w2 w2 w3 [SEP] [PAD] [PAD]
+    fn encoding() -> Encoding {
+        let ids = vec![100, 1, 2, 3, 4, 5, 6, 7, 101];
+        let mut tokens = vec![String::from("[CLS]")];
+        tokens.extend((1..8).map(|i| format!("t{}", i)));
+        tokens.push(String::from("[SEP]"));
+        let words = vec![
+            None,
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(3),
+            None,
+        ];
+        let mut encoding = Encoding::new(
+            ids,
+            vec![0; 9],
+            tokens,
+            words,
+            vec![(0, 0); 9],
+            vec![1, 0, 0, 0, 0, 0, 0, 0, 1],
+            vec![1; 9],
+            vec![],
+            HashMap::new(),
+        );
+        encoding.pad(11, 200, 0, "[PAD]", PaddingDirection::Right);
+        encoding
+    }
+
+    #[test]
+    fn invalid_params() {
+        let params = |mask_ratio, random_ratio| MaskingParams {
+            mask_ratio,
+            random_ratio,
+            ..Default::default()
+        };
+        assert!(WholeWordMasking::new(params(0.8, 0.1), &vocab()).is_ok());
+        assert!(WholeWordMasking::new(params(0.8, 0.3), &vocab()).is_err());
+        assert!(WholeWordMasking::new(params(1.5, 0.0), &vocab()).is_err());
+
+        // The mask token must be in the vocab, and it is the only token left here
+        let mask_only = vec![(String::from("[MASK]"), 103)].into_iter().collect();
+        assert!(WholeWordMasking::new(params(0.8, 0.1), &HashMap::new()).is_err());
+        assert!(WholeWordMasking::new(params(1.0, 0.0), &HashMap::new()).is_err());
+        assert!(WholeWordMasking::new(params(0.8, 0.1), &mask_only).is_err());
+        let masking = WholeWordMasking::new(params(1.0, 0.0), &mask_only).unwrap();
+        assert_eq!(masking.get_mask_id(), 103);
+    }
+
+    #[test]
+    fn masks_whole_words_only() {
+        let words = [(1, 3), (3, 4), (4, 7), (7, 8)];
+        let mut masking = WholeWordMasking::new(
+            MaskingParams {
+                probability: 0.5,
+                mask_ratio: 1.0,
+                random_ratio: 0.0,
+                ..Default::default()
+            },
+            &vocab(),
+        )
+        .unwrap();
+
+        let mut encodings = vec![encoding(); 20];
+        let labels = masking.mask(&mut encodings);
+        assert!(labels.iter().flatten().any(|l| l.is_some()));
+        assert!(labels.iter().flatten().any(|l| l.is_none()));
+
+        let original = encoding();
+        for (encoding, labels) in encodings.iter().zip(labels) {
+            for &(start, end) in &words {
+                let masked = labels[start].is_some();
+                for (i, label) in labels.iter().enumerate().take(end).skip(start) {
+                    assert_eq!(label.is_some(), masked);
+                    if masked {
+                        assert_eq!(*label, Some(original.get_ids()[i]));
+                        assert_eq!(encoding.get_ids()[i], 103);
+                        assert_eq!(encoding.get_tokens()[i], "[MASK]");
+                    } else {
+                        assert_eq!(encoding.get_ids()[i], original.get_ids()[i]);
+                    }
+                }
+            }
+
+            // Special tokens and padding are never touched
+            for &i in &[0, 8, 9, 10] {
+                assert_eq!(labels[i], None);
+                assert_eq!(encoding.get_ids()[i], original.get_ids()[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn replacements() {
+        let params = |mask_ratio, random_ratio| MaskingParams {
+            probability: 1.0,
+            mask_ratio,
+            random_ratio,
+            excluded_ids: special_ids(),
+            ..Default::default()
+        };
+        let original = encoding();
+        let expected_labels = vec![
+            None,
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            None,
+            None,
+            None,
+        ];
+
+        // Unchanged
+        let mut encodings = vec![encoding()];
+        let labels = WholeWordMasking::new(params(0.0, 0.0), &vocab())
+            .unwrap()
+            .mask(&mut encodings);
+        assert_eq!(labels, vec![expected_labels.clone()]);
+        assert_eq!(encodings[0], original);
+
+        // Random tokens from the vocab, never the special or mask tokens
+        let mut encodings = vec![encoding(); 10];
+        let labels = WholeWordMasking::new(params(0.0, 1.0), &vocab())
+            .unwrap()
+            .mask(&mut encodings);
+        assert_eq!(labels, vec![expected_labels; 10]);
+        for encoding in &encodings {
+            for i in 1..8 {
+                let id = encoding.get_ids()[i];
+                assert!(id < 10);
+                assert_eq!(encoding.get_tokens()[i], format!("t{}", id));
+            }
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        let params = MaskingParams {
+            probability: 0.5,
+            ..Default::default()
+        };
+        let mut masking = WholeWordMasking::new(params.clone(), &vocab()).unwrap();
+        let mut first = vec![encoding(); 10];
+        let first_labels = masking.mask(&mut first);
+        let mut second = vec![encoding(); 10];
+        let second_labels = masking.mask(&mut second);
+        // The state of the RNG is kept from one batch to the next
+        assert_ne!(first_labels, second_labels);
+
+        // And the same seed always gives the same results
+        let mut masking = WholeWordMasking::new(params, &vocab()).unwrap();
+        let mut again = vec![encoding(); 10];
+        assert_eq!(masking.mask(&mut again), first_labels);
+        assert_eq!(again, first);
+    }
+}
@@ -1,12 +1,26 @@
-#[cfg(not(feature = "bert"))]
+#[cfg(any(feature = "bpe", feature = "unigram"))]
 pub mod cache;
+pub(crate) mod case_folding;
+#[cfg(feature = "fancy-regex")]
+pub mod fancy;
 pub mod iter;
+#[cfg(feature = "masking")]
+pub mod masking;
+#[cfg(feature = "onig")]
+pub mod onig;
+pub mod packing;
 pub mod padding;
 pub mod parallelism;
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "training")]
 pub mod progress;
+pub(crate) mod special_casing;
 pub mod truncation;
 
+#[cfg(feature = "onig")]
+pub use self::onig::SysRegex;
+#[cfg(all(feature = "fancy-regex", not(feature = "onig")))]
+pub use fancy::SysRegex;
+
 use serde::{Serialize, Serializer};
 use std::collections::{BTreeMap, HashMap};
 
@@ -76,3 +90,54 @@ macro_rules! impl_serde_unit_struct (
         }
     }
 );
+
+/// Implements `Serialize` and `Deserialize` for one of the `*Wrapper` enums. The enum must
+/// derive them with `#[serde(remote = "Self")]`, and each of its variants is listed with the
+/// `type` it deserializes from.
+///
+/// The `type` is read once, and used to deserialize the right variant directly. Since some
+/// components can be disabled at compile time, the given function is used to find the features
+/// required by a `type`, already formatted like ``"`onig` or `fancy-regex`"``, letting us return
+/// a clear error instead of the generic "data did not match any variant" one. Without a known
+/// `type`, like in some older files, each variant is tried in order.
+macro_rules! impl_serde_wrapper (
+    ($wrapper:ty, $disabled_feature:path, {
+        $($(#[$meta:meta])* $ty:literal => $variant:ident),* $(,)?
+    }) => {
+        impl serde::Serialize for $wrapper {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                <$wrapper>::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $wrapper {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error;
+
+                let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+                let ty = value.get("type").and_then(|ty| ty.as_str());
+                if let Some((ty, features)) = ty.and_then(|ty| $disabled_feature(ty).map(|f| (ty, f))) {
+                    return Err(D::Error::custom(format!(
+                        "`{}` is not available, tokenizers must be compiled with the {} feature",
+                        ty, features
+                    )));
+                }
+                match ty {
+                    $(
+                        $(#[$meta])*
+                        Some($ty) => serde_json::from_value(value)
+                            .map(Self::$variant)
+                            .map_err(D::Error::custom),
+                    )*
+                    _ => <$wrapper>::deserialize(value).map_err(D::Error::custom),
+                }
+            }
+        }
+    }
+);
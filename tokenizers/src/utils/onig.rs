@@ -0,0 +1,27 @@
+use crate::tokenizer::pattern::Pattern;
+use crate::{Offsets, Result};
+
+/// A regex backed by the [onig](https://crates.io/crates/onig) library
+#[derive(Debug)]
+pub struct SysRegex {
+    regex: ::onig::Regex,
+}
+
+impl SysRegex {
+    pub fn new(regex_str: &str) -> Result<Self> {
+        Ok(Self {
+            regex: ::onig::Regex::new(regex_str)?,
+        })
+    }
+
+    /// Returns an iterator over the `(start, end)` byte offsets of each match in `inside`
+    pub fn find_iter<'r, 't>(&'r self, inside: &'t str) -> ::onig::FindMatches<'r, 't> {
+        self.regex.find_iter(inside)
+    }
+}
+
+impl Pattern for &SysRegex {
+    fn find_matches(&self, inside: &str) -> Result<Vec<(Offsets, bool)>> {
+        (&self.regex).find_matches(inside)
+    }
+}
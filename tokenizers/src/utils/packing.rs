@@ -0,0 +1,309 @@
+use crate::tokenizer::{Encoding, Error, Result};
+use crate::utils::padding::PaddingDirection;
+use std::collections::HashMap;
+
+/// The parameters used to pack encodings with `pack_encodings`
+#[derive(Debug, Clone)]
+pub struct PackingParams {
+    /// The length of each block
+    pub max_length: usize,
+    /// The separator token added after each document, as `(token, id)`, if any
+    pub separator: Option<(String, u32)>,
+    /// Whether a document can be split across blocks, so that all the blocks are full. If not,
+    /// a document that doesn't fit starts a new block, and the documents longer than a whole
+    /// block get truncated.
+    pub split_documents: bool,
+    pub pad_id: u32,
+    pub pad_type_id: u32,
+    pub pad_token: String,
+}
+
+impl Default for PackingParams {
+    fn default() -> Self {
+        Self {
+            max_length: 512,
+            separator: None,
+            split_documents: true,
+            pad_id: 0,
+            pad_type_id: 0,
+            pad_token: String::from("[PAD]"),
+        }
+    }
+}
+
+/// A block of `max_length` tokens, made of several documents packed together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedBlock {
+    /// The packed documents, padded to `max_length`. Use `segment_ids` to find the documents,
+    /// since its sequence ids only make sense for each document on its own.
+    pub encoding: Encoding,
+    /// The position of each token in its document, starting at 0 for each document. The part
+    /// of a document continuing from the previous block starts at 0 too. Padding gets 0.
+    pub position_ids: Vec<u32>,
+    /// The index of the document containing each token in this block, or `None` for padding.
+    /// Two tokens can attend to each other only if they have the same segment id.
+    pub segment_ids: Vec<Option<u32>>,
+}
+
+/// Pack a stream of encodings, usually one per document, into blocks of exactly `max_length`
+/// tokens. The blocks are built lazily, as the returned iterator gets consumed.
+///
+/// The overflowing parts of the encodings are ignored.
+pub fn pack_encodings<I>(encodings: I, params: &PackingParams) -> Result<PackedBlocks<I::IntoIter>>
+where
+    I: IntoIterator<Item = Encoding>,
+{
+    let separator_len = if params.separator.is_some() { 1 } else { 0 };
+    if params.max_length <= separator_len {
+        return Err(Error::configuration(format!(
+            "`max_length` must be greater than {} to pack encodings",
+            separator_len
+        )));
+    }
+
+    Ok(PackedBlocks {
+        encodings: encodings.into_iter(),
+        params: params.clone(),
+        pending: None,
+    })
+}
+
+/// The iterator over the blocks built by `pack_encodings`
+pub struct PackedBlocks<I> {
+    encodings: I,
+    params: PackingParams,
+    /// The (rest of the) document that didn't fit in the previous block
+    pending: Option<Encoding>,
+}
+
+impl<I> PackedBlocks<I>
+where
+    I: Iterator<Item = Encoding>,
+{
+    /// Returns the next non-empty document, with its separator
+    fn next_document(&mut self) -> Option<Encoding> {
+        if let Some(document) = self.pending.take() {
+            return Some(document);
+        }
+
+        let mut document = self.encodings.by_ref().find(|e| !e.is_empty())?;
+        document.take_overflowing();
+
+        if let Some((token, id)) = &self.params.separator {
+            if !self.params.split_documents && document.len() >= self.params.max_length {
+                truncate(&mut document, self.params.max_length - 1);
+            }
+            let separator = Encoding::new(
+                vec![*id],
+                vec![document.get_type_ids().last().copied().unwrap_or(0)],
+                vec![token.clone()],
+                vec![None],
+                vec![(0, 0)],
+                vec![1],
+                vec![1],
+                vec![],
+                HashMap::new(),
+            );
+            document.merge_with(separator, false);
+        }
+
+        Some(document)
+    }
+}
+
+impl<I> Iterator for PackedBlocks<I>
+where
+    I: Iterator<Item = Encoding>,
+{
+    type Item = PackedBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let max_length = self.params.max_length;
+        let mut documents = vec![];
+        let mut position_ids = Vec::with_capacity(max_length);
+        let mut segment_ids = Vec::with_capacity(max_length);
+        let mut length = 0;
+
+        while length < max_length {
+            let mut document = match self.next_document() {
+                Some(document) => document,
+                None => break,
+            };
+
+            let remaining = max_length - length;
+            if document.len() > remaining {
+                if self.params.split_documents {
+                    self.pending = Some(truncate(&mut document, remaining));
+                } else if length == 0 {
+                    truncate(&mut document, remaining);
+                } else {
+                    self.pending = Some(document);
+                    break;
+                }
+            }
+
+            position_ids.extend(0..document.len() as u32);
+            segment_ids.resize(length + document.len(), Some(documents.len() as u32));
+            length += document.len();
+            documents.push(document);
+        }
+
+        if documents.is_empty() {
+            return None;
+        }
+
+        let mut encoding = Encoding::merge(documents, false);
+        encoding.pad(
+            max_length,
+            self.params.pad_id,
+            self.params.pad_type_id,
+            &self.params.pad_token,
+            PaddingDirection::Right,
+        );
+        position_ids.resize(max_length, 0);
+        segment_ids.resize(max_length, None);
+
+        Some(PackedBlock {
+            encoding,
+            position_ids,
+            segment_ids,
+        })
+    }
+}
+
+/// Truncate the given encoding to `length` tokens, returning the rest
+fn truncate(encoding: &mut Encoding, length: usize) -> Encoding {
+    encoding.truncate(length, 0);
+    Encoding::merge(encoding.take_overflowing(), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Token;
+
+    fn document(id: u32, len: usize) -> Encoding {
+        Encoding::from_tokens(
+            (0..len)
+                .map(|i| Token::new(id, format!("d{}", id), (i, i + 1)))
+                .collect(),
+            0,
+        )
+    }
+
+    fn documents() -> Vec<Encoding> {
+        vec![document(1, 3), document(2, 4), document(3, 2)]
+    }
+
+    #[test]
+    fn split_documents() {
+        let params = PackingParams {
+            max_length: 5,
+            separator: Some(("[SEP]".into(), 9)),
+            ..Default::default()
+        };
+        let blocks = pack_encodings(documents(), &params)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        let ids = blocks
+            .iter()
+            .map(|b| b.encoding.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                vec![1, 1, 1, 9, 2],
+                vec![2, 2, 2, 9, 3],
+                vec![3, 9, 0, 0, 0]
+            ]
+        );
+        assert_eq!(blocks[0].position_ids, vec![0, 1, 2, 3, 0]);
+        assert_eq!(
+            blocks[0].segment_ids,
+            vec![Some(0), Some(0), Some(0), Some(0), Some(1)]
+        );
+        assert_eq!(blocks[1].position_ids, vec![0, 1, 2, 3, 0]);
+        assert_eq!(blocks[2].position_ids, vec![0, 1, 0, 0, 0]);
+        assert_eq!(
+            blocks[2].segment_ids,
+            vec![Some(0), Some(0), None, None, None]
+        );
+
+        assert_eq!(
+            blocks[0].encoding.get_special_tokens_mask(),
+            &[0, 0, 0, 1, 0]
+        );
+        assert_eq!(blocks[2].encoding.get_attention_mask(), &[1, 1, 0, 0, 0]);
+        assert_eq!(
+            blocks[1].encoding.get_offsets()[..3],
+            [(1, 2), (2, 3), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn keep_documents_whole() {
+        let params = PackingParams {
+            max_length: 5,
+            split_documents: false,
+            pad_id: 0,
+            ..Default::default()
+        };
+        let mut encodings = documents();
+        encodings.push(Encoding::default());
+        encodings.push(document(4, 7));
+        let blocks = pack_encodings(encodings, &params)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        let ids = blocks
+            .iter()
+            .map(|b| b.encoding.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ids,
+            vec![
+                vec![1, 1, 1, 0, 0],
+                vec![2, 2, 2, 2, 0],
+                vec![3, 3, 0, 0, 0],
+                vec![4, 4, 4, 4, 4]
+            ]
+        );
+        assert_eq!(blocks[1].position_ids, vec![0, 1, 2, 3, 0]);
+        assert_eq!(
+            blocks[1].segment_ids,
+            vec![Some(0), Some(0), Some(0), Some(0), None]
+        );
+    }
+
+    #[test]
+    fn truncated_documents_keep_separator() {
+        let params = PackingParams {
+            max_length: 4,
+            separator: Some(("[SEP]".into(), 9)),
+            split_documents: false,
+            ..Default::default()
+        };
+        let blocks = pack_encodings(vec![document(1, 6), document(2, 1)], &params)
+            .unwrap()
+            .map(|b| b.encoding.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![vec![1, 1, 1, 9], vec![2, 9, 0, 0]]);
+    }
+
+    #[test]
+    fn invalid_max_length() {
+        let params = PackingParams {
+            max_length: 1,
+            separator: Some(("[SEP]".into(), 9)),
+            ..Default::default()
+        };
+        assert!(pack_encodings(documents(), &params).is_err());
+        assert_eq!(
+            pack_encodings(vec![], &PackingParams::default())
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::parallelism::*;
 use crate::tokenizer::{Encoding, Result};
 use serde::{Deserialize, Serialize};
+use std::cmp;
 
 /// The various possible padding directions.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -23,6 +24,12 @@ pub struct PaddingParams {
     pub strategy: PaddingStrategy,
     pub direction: PaddingDirection,
     pub pad_to_multiple_of: Option<usize>,
+    /// The minimum length to pad to, whatever the strategy
+    #[serde(default)]
+    pub min_pad_length: Option<usize>,
+    /// The maximum length to pad to. The encodings already longer than this are left as is.
+    #[serde(default)]
+    pub max_pad_length: Option<usize>,
     pub pad_id: u32,
     pub pad_type_id: u32,
     pub pad_token: String,
@@ -34,6 +41,8 @@ impl Default for PaddingParams {
             strategy: PaddingStrategy::BatchLongest,
             direction: PaddingDirection::Right,
             pad_to_multiple_of: None,
+            min_pad_length: None,
+            max_pad_length: None,
             pad_id: 0,
             pad_type_id: 0,
             pad_token: String::from("[PAD]"),
@@ -41,10 +50,42 @@ impl Default for PaddingParams {
     }
 }
 
+impl PaddingParams {
+    /// The length to which we pad a batch, given the length of its longest `Encoding`
+    pub fn pad_length(&self, longest: usize) -> usize {
+        let min_length = self.min_pad_length.unwrap_or(0);
+        let longest = cmp::max(longest, min_length);
+        let mut pad_length = match &self.strategy {
+            PaddingStrategy::Fixed(size) => cmp::max(*size, min_length),
+            PaddingStrategy::BatchLongest => longest,
+            PaddingStrategy::Bucketed(lengths) => lengths
+                .iter()
+                .copied()
+                .filter(|l| *l >= longest)
+                .min()
+                .unwrap_or(longest),
+        };
+
+        if let Some(multiple) = self.pad_to_multiple_of {
+            if multiple > 0 && pad_length % multiple > 0 {
+                pad_length += multiple - pad_length % multiple;
+            }
+        }
+
+        match self.max_pad_length {
+            Some(max_length) => cmp::min(pad_length, max_length),
+            None => pad_length,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PaddingStrategy {
     BatchLongest,
     Fixed(usize),
+    /// Pad to the smallest of the given lengths that fits the longest `Encoding` of the batch,
+    /// or to the longest `Encoding` if none does.
+    Bucketed(Vec<usize>),
 }
 
 pub fn pad_encodings(encodings: &mut [Encoding], params: &PaddingParams) -> Result<()> {
@@ -52,20 +93,15 @@ pub fn pad_encodings(encodings: &mut [Encoding], params: &PaddingParams) -> Resu
         return Ok(());
     }
 
-    let mut pad_length = match params.strategy {
-        PaddingStrategy::Fixed(size) => size,
-        PaddingStrategy::BatchLongest => encodings
+    let longest = match params.strategy {
+        PaddingStrategy::Fixed(_) => 0,
+        _ => encodings
             .maybe_par_iter()
             .map(|e| e.get_ids().len())
             .max()
             .unwrap(),
     };
-
-    if let Some(multiple) = params.pad_to_multiple_of {
-        if multiple > 0 && pad_length % multiple > 0 {
-            pad_length += multiple - pad_length % multiple;
-        }
-    }
+    let pad_length = params.pad_length(longest);
 
     encodings.maybe_par_iter_mut().for_each(|encoding| {
         encoding.pad(
@@ -121,6 +157,8 @@ mod tests {
             strategy: PaddingStrategy::Fixed(7),
             direction: PaddingDirection::Right,
             pad_to_multiple_of: Some(8),
+            min_pad_length: None,
+            max_pad_length: None,
             pad_id: 0,
             pad_type_id: 0,
             pad_token: String::from("[PAD]"),
@@ -139,4 +177,81 @@ mod tests {
         params.pad_to_multiple_of = Some(0);
         pad_encodings(&mut encodings, &params).unwrap();
     }
+
+    #[test]
+    fn pad_length() {
+        let params = |strategy| PaddingParams {
+            strategy,
+            ..Default::default()
+        };
+
+        let batch_longest = params(PaddingStrategy::BatchLongest);
+        assert_eq!(batch_longest.pad_length(10), 10);
+        let min_max = PaddingParams {
+            min_pad_length: Some(8),
+            max_pad_length: Some(16),
+            ..batch_longest
+        };
+        assert_eq!(min_max.pad_length(3), 8);
+        assert_eq!(min_max.pad_length(10), 10);
+        assert_eq!(min_max.pad_length(20), 16);
+
+        let bucketed = params(PaddingStrategy::Bucketed(vec![128, 64, 256]));
+        assert_eq!(bucketed.pad_length(10), 64);
+        assert_eq!(bucketed.pad_length(64), 64);
+        assert_eq!(bucketed.pad_length(65), 128);
+        assert_eq!(bucketed.pad_length(300), 300);
+        let bucketed = PaddingParams {
+            min_pad_length: Some(100),
+            pad_to_multiple_of: Some(100),
+            ..bucketed
+        };
+        assert_eq!(bucketed.pad_length(10), 200);
+        assert_eq!(bucketed.pad_length(257), 300);
+
+        let fixed = PaddingParams {
+            min_pad_length: Some(8),
+            ..params(PaddingStrategy::Fixed(4))
+        };
+        assert_eq!(fixed.pad_length(2), 8);
+    }
+
+    #[test]
+    fn pad_bucketed() {
+        let mut encodings = vec![
+            Encoding::from_tokens(vec![crate::Token::new(1, "a".into(), (0, 1)); 5], 0),
+            Encoding::from_tokens(vec![crate::Token::new(2, "b".into(), (0, 1)); 3], 0),
+        ];
+        let params = PaddingParams {
+            strategy: PaddingStrategy::Bucketed(vec![4, 8]),
+            max_pad_length: Some(6),
+            ..Default::default()
+        };
+        pad_encodings(&mut encodings, &params).unwrap();
+        assert!(encodings.iter().all(|e| e.len() == 6));
+        assert_eq!(encodings[1].get_attention_mask(), &[1, 1, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn serialization() {
+        let params = PaddingParams {
+            strategy: PaddingStrategy::Bucketed(vec![64, 128]),
+            max_pad_length: Some(128),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&params).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"strategy":{"Bucketed":[64,128]},"direction":"Right","pad_to_multiple_of":null,"min_pad_length":null,"max_pad_length":128,"pad_id":0,"pad_type_id":0,"pad_token":"[PAD]"}"#
+        );
+        let deserialized: PaddingParams = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.pad_length(10), 64);
+
+        // The new options are optional
+        let deserialized: PaddingParams = serde_json::from_str(
+            r#"{"strategy":"BatchLongest","direction":"Right","pad_to_multiple_of":null,"pad_id":0,"pad_type_id":0,"pad_token":"[PAD]"}"#,
+        )
+        .unwrap();
+        assert_eq!(deserialized.max_pad_length, None);
+    }
 }
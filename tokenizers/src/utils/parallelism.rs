@@ -2,21 +2,97 @@
 //! This module defines helpers to allow optional Rayon usage.
 //!
 
-#[cfg(not(feature = "bert"))]
+#[cfg(feature = "parallel")]
 pub use parallel::*;
-#[cfg(feature = "bert")]
+#[cfg(not(feature = "parallel"))]
 pub use sequential::*;
 
-#[cfg(not(feature = "bert"))]
+use std::cell::Cell;
+
+thread_local! {
+    /// Set by `Parallelism::install` to override the `TOKENIZERS_PARALLELISM` environment
+    /// variable for everything running on the current thread.
+    static PARALLELISM_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Restores the previous override when dropped, even if the wrapped operation panics
+struct OverrideGuard(Option<bool>);
+
+impl OverrideGuard {
+    fn set(value: bool) -> Self {
+        Self(PARALLELISM_OVERRIDE.with(|o| o.replace(Some(value))))
+    }
+}
+
+impl Drop for OverrideGuard {
+    fn drop(&mut self) {
+        PARALLELISM_OVERRIDE.with(|o| o.set(self.0));
+    }
+}
+
+fn with_override<OP, R>(value: bool, op: OP) -> R
+where
+    OP: FnOnce() -> R,
+{
+    let _guard = OverrideGuard::set(value);
+    op()
+}
+
+/// Describes how a `Tokenizer` runs its batch operations (encoding, decoding and training).
+///
+/// This lets each tokenizer living in the same process use its own settings, instead of relying
+/// only on the process-wide `TOKENIZERS_PARALLELISM` environment variable.
+#[derive(Debug, Clone, Default)]
+pub enum Parallelism {
+    /// Follow the `TOKENIZERS_PARALLELISM` environment variable, using rayon's global thread
+    /// pool when it is enabled. This is the default.
+    #[default]
+    Global,
+    /// Always run serially, on the calling thread.
+    Disabled,
+    /// Always run in parallel, on the given thread pool.
+    #[cfg(feature = "parallel")]
+    Pool(std::sync::Arc<rayon::ThreadPool>),
+}
+
+impl Parallelism {
+    /// Build a dedicated thread pool with the given number of threads.
+    #[cfg(feature = "parallel")]
+    pub fn with_num_threads(num_threads: usize) -> crate::Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(crate::Error::other)?;
+        Ok(Parallelism::Pool(std::sync::Arc::new(pool)))
+    }
+
+    /// Run the given operation with these settings. Any `MaybeParallel*` iterator used by `op`
+    /// on the current thread will follow them.
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        match self {
+            Parallelism::Global => op(),
+            Parallelism::Disabled => with_override(false, op),
+            #[cfg(feature = "parallel")]
+            Parallelism::Pool(pool) => pool.install(|| with_override(true, op)),
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
 mod parallel {
     use rayon::iter::IterBridge;
     use rayon::prelude::*;
     use rayon_cond::CondIterator;
 
+    use std::sync::atomic::{AtomicBool, Ordering};
+
     pub const ENV_VARIABLE: &str = "TOKENIZERS_PARALLELISM";
 
-    // Reading/Writing this variable should always happen on the main thread
-    static mut USED_PARALLELISM: bool = false;
+    static USED_PARALLELISM: AtomicBool = AtomicBool::new(false);
 
     /// Check if the TOKENIZERS_PARALLELISM env variable has been explicitly set
     pub fn is_parallelism_configured() -> bool {
@@ -25,7 +101,7 @@ mod parallel {
 
     /// Check if at some point we used a parallel iterator
     pub fn has_parallelism_been_used() -> bool {
-        unsafe { USED_PARALLELISM }
+        USED_PARALLELISM.load(Ordering::SeqCst)
     }
 
     /// Get the currently set value for `TOKENIZERS_PARALLELISM` env variable
@@ -44,9 +120,22 @@ mod parallel {
         std::env::set_var(ENV_VARIABLE, if val { "true" } else { "false" })
     }
 
+    /// Check whether the iterators should run in parallel on the current thread. Any override
+    /// installed with `Parallelism::install` takes precedence over `TOKENIZERS_PARALLELISM`.
+    fn should_use_parallelism() -> bool {
+        let parallelism = super::PARALLELISM_OVERRIDE
+            .with(|o| o.get())
+            .unwrap_or_else(get_parallelism);
+        if parallelism {
+            USED_PARALLELISM.store(true, Ordering::SeqCst);
+        }
+        parallelism
+    }
+
     /// Allows to convert into an iterator that can be executed either parallelly or serially.
     ///
-    /// The choice is made according to the currently set `TOKENIZERS_PARALLELISM` environment variable.
+    /// The choice is made according to the `Parallelism` installed on the current thread if any,
+    /// or else to the currently set `TOKENIZERS_PARALLELISM` environment variable.
     /// This variable can have one of the following values
     ///   - False => "" (empty value), "false", "f", "off", "no", "n", "0"
     ///   - True => Any other value
@@ -73,11 +162,7 @@ mod parallel {
         S: Iterator<Item = P::Item>,
     {
         fn into_maybe_par_iter(self) -> CondIterator<P, S> {
-            let parallelism = get_parallelism();
-            if parallelism {
-                unsafe { USED_PARALLELISM = true };
-            }
-            CondIterator::new(self, parallelism)
+            CondIterator::new(self, should_use_parallelism())
         }
 
         fn into_maybe_par_iter_cond(self, cond: bool) -> CondIterator<P, S> {
@@ -163,8 +248,7 @@ mod parallel {
         fn maybe_par_bridge(self) -> CondIterator<IterBridge<S>, S> {
             let iter = CondIterator::from_serial(self);
 
-            if get_parallelism() {
-                unsafe { USED_PARALLELISM = true };
+            if should_use_parallelism() {
                 CondIterator::from_parallel(iter.into_parallel().right().unwrap())
             } else {
                 iter
@@ -181,7 +265,7 @@ mod parallel {
     }
 }
 
-#[cfg(feature = "bert")]
+#[cfg(not(feature = "parallel"))]
 mod sequential {
     /// Allows to convert into an iterator that can be executed either parallelly or serially.
     ///
@@ -315,4 +399,28 @@ mod tests {
         assert_eq!(v.maybe_par_iter().sum::<u32>(), 42);
         assert_eq!(v.into_maybe_par_iter().sum::<u32>(), 42);
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallelism_install() {
+        let v = vec![1u32, 2, 3, 4, 5, 6];
+        let thread_indices = |v: &[u32]| {
+            v.maybe_par_iter()
+                .map(|_| rayon::current_thread_index())
+                .collect::<Vec<_>>()
+        };
+
+        // Disabled runs everything on the calling thread
+        let indices = Parallelism::Disabled.install(|| thread_indices(&v));
+        assert!(indices.iter().all(|i| i.is_none()));
+
+        // A dedicated pool runs on its own threads
+        let pool = Parallelism::with_num_threads(2).unwrap();
+        assert_eq!(pool.install(rayon::current_num_threads), 2);
+        let indices = pool.install(|| thread_indices(&v));
+        assert!(indices.iter().all(|i| matches!(i, Some(0) | Some(1))));
+
+        // The override does not outlive the call
+        assert!(PARALLELISM_OVERRIDE.with(|o| o.get()).is_none());
+    }
 }
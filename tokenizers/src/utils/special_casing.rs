@@ -0,0 +1,128 @@
+//! The conditional lowercase mappings of `SpecialCasing.txt`, which depend on the context of each
+//! character, and for some of them on the language of the text.
+use unicode_categories::UnicodeCategories;
+use unicode_normalization_alignments::char::canonical_combining_class;
+
+/// Return the language of the given locale, like `tr` for `tr-TR`
+pub(crate) fn locale_language(locale: &str) -> String {
+    locale
+        .split(&['-', '_'][..])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Return the lowercase mapping of the character at `index` in `chars`, if one of the
+/// conditional mappings applies to it for the given `language`. The `Final_Sigma` mapping
+/// applies whatever the language.
+pub(crate) fn special_lowercase(
+    chars: &[char],
+    index: usize,
+    language: &str,
+) -> Option<&'static str> {
+    let c = chars[index];
+
+    match language {
+        "lt" => match c {
+            'I' if more_above(chars, index) => return Some("i\u{307}"),
+            'J' if more_above(chars, index) => return Some("j\u{307}"),
+            '\u{12E}' if more_above(chars, index) => return Some("\u{12F}\u{307}"),
+            '\u{CC}' => return Some("i\u{307}\u{300}"),
+            '\u{CD}' => return Some("i\u{307}\u{301}"),
+            '\u{128}' => return Some("i\u{307}\u{303}"),
+            _ => {}
+        },
+        "tr" | "az" => match c {
+            '\u{130}' => return Some("i"),
+            '\u{307}' if after_i(chars, index) => return Some(""),
+            'I' if !before_dot(chars, index) => return Some("\u{131}"),
+            _ => {}
+        },
+        _ => {}
+    }
+
+    if c == '\u{3A3}' && final_sigma(chars, index) {
+        return Some("\u{3C2}");
+    }
+    None
+}
+
+/// The character is preceded by a cased letter, and not followed by one, ignoring the case
+/// ignorable characters in between
+fn final_sigma(chars: &[char], index: usize) -> bool {
+    let before = chars[..index]
+        .iter()
+        .rev()
+        .find(|c| !is_case_ignorable(**c));
+    let after = chars[index + 1..].iter().find(|c| !is_case_ignorable(**c));
+    matches!(before, Some(c) if is_cased(*c)) && !matches!(after, Some(c) if is_cased(*c))
+}
+
+/// The character follows an uppercase `I`, with no combining class 0 or 230 in between
+fn after_i(chars: &[char], index: usize) -> bool {
+    for c in chars[..index].iter().rev() {
+        if *c == 'I' {
+            return true;
+        }
+        if matches!(canonical_combining_class(*c), 0 | 230) {
+            return false;
+        }
+    }
+    false
+}
+
+/// The character is followed by a combining class 230 (above), with no combining class 0 in
+/// between
+fn more_above(chars: &[char], index: usize) -> bool {
+    for c in &chars[index + 1..] {
+        match canonical_combining_class(*c) {
+            230 => return true,
+            0 => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The character is followed by a combining dot above, with no combining class 0 or 230 in
+/// between
+fn before_dot(chars: &[char], index: usize) -> bool {
+    for c in &chars[index + 1..] {
+        if *c == '\u{307}' {
+            return true;
+        }
+        if matches!(canonical_combining_class(*c), 0 | 230) {
+            return false;
+        }
+    }
+    false
+}
+
+fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase() || c.is_letter_titlecase()
+}
+
+fn is_case_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '.'
+            | ':'
+            | '\u{B7}'
+            | '\u{387}'
+            | '\u{5F4}'
+            | '\u{2018}'
+            | '\u{2019}'
+            | '\u{2024}'
+            | '\u{2027}'
+            | '\u{FE13}'
+            | '\u{FE52}'
+            | '\u{FE55}'
+            | '\u{FF07}'
+            | '\u{FF0E}'
+            | '\u{FF1A}'
+    ) || c.is_mark_nonspacing()
+        || c.is_mark_enclosing()
+        || c.is_other_format()
+        || c.is_letter_modifier()
+        || c.is_symbol_modifier()
+}
@@ -8,6 +8,21 @@ pub struct TruncationParams {
     pub max_length: usize,
     pub strategy: TruncationStrategy,
     pub stride: usize,
+    /// The side of each sequence where the tokens get removed
+    #[serde(default)]
+    pub direction: TruncationDirection,
+    /// Whether to cut only between words, using the word ids, in the truncated sequences and
+    /// their overflowing parts. A word longer than the whole length still gets cut.
+    #[serde(default)]
+    pub word_boundaries: bool,
+    /// The maximum length of the first sequence. The rest of it gets dropped, so that it is
+    /// repeated as is in each overflowing part of a pair.
+    #[serde(default)]
+    pub max_first_length: Option<usize>,
+    /// The maximum length of the second sequence, whose overflowing parts use `stride`. With
+    /// `OnlySecond`, it gets the rest of `max_length` after the first sequence, up to this length.
+    #[serde(default)]
+    pub max_second_length: Option<usize>,
 }
 
 impl Default for TruncationParams {
@@ -16,6 +31,10 @@ impl Default for TruncationParams {
             max_length: 512,
             strategy: TruncationStrategy::LongestFirst,
             stride: 0,
+            direction: TruncationDirection::Right,
+            word_boundaries: false,
+            max_first_length: None,
+            max_second_length: None,
         }
     }
 }
@@ -57,6 +76,29 @@ pub enum TruncationStrategy {
     OnlySecond,
 }
 
+/// Which tokens of a sequence we keep when truncating it
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TruncationDirection {
+    /// Keep the start of the sequence
+    #[default]
+    Right,
+    /// Keep the end of the sequence
+    Left,
+    /// Keep the given number of tokens at the start of the sequence, and fill the rest of the
+    /// length with its end. The overflowing parts then cover the middle of the sequence.
+    HeadTail(usize),
+}
+
+impl std::convert::AsRef<str> for TruncationDirection {
+    fn as_ref(&self) -> &str {
+        match self {
+            TruncationDirection::Right => "right",
+            TruncationDirection::Left => "left",
+            TruncationDirection::HeadTail(_) => "head_tail",
+        }
+    }
+}
+
 impl std::convert::AsRef<str> for TruncationStrategy {
     fn as_ref(&self) -> &str {
         match self {
@@ -67,6 +109,14 @@ impl std::convert::AsRef<str> for TruncationStrategy {
     }
 }
 
+fn truncate(encoding: &mut Encoding, max_len: usize, params: &TruncationParams) {
+    if params.word_boundaries {
+        encoding.truncate_at_word_boundaries(max_len, params.stride, params.direction);
+    } else {
+        encoding.truncate_with_direction(max_len, params.stride, params.direction);
+    }
+}
+
 pub fn truncate_encodings(
     mut encoding: Encoding,
     mut pair_encoding: Option<Encoding>,
@@ -80,14 +130,29 @@ pub fn truncate_encodings(
         return Ok((encoding, pair_encoding));
     }
 
-    let total_length = encoding.get_ids().len()
-        + pair_encoding
-            .as_ref()
-            .map(|e| e.get_ids().len())
-            .unwrap_or(0);
+    if let Some(max_first_length) = params.max_first_length {
+        if encoding.len() > max_first_length {
+            let params = TruncationParams {
+                stride: 0,
+                ..params.clone()
+            };
+            truncate(&mut encoding, max_first_length, &params);
+            encoding.take_overflowing();
+        }
+    }
+
+    let first_length = encoding.len();
+    let second_length = pair_encoding
+        .as_ref()
+        .map_or(0, |e| match params.max_second_length {
+            Some(max_second_length) => cmp::min(e.len(), max_second_length),
+            None => e.len(),
+        });
+    let total_length = first_length + second_length;
     let to_remove = if total_length > params.max_length {
         total_length - params.max_length
     } else {
+        truncate_second(pair_encoding.as_mut(), second_length, params);
         return Ok((encoding, pair_encoding));
     };
 
@@ -107,8 +172,8 @@ pub fn truncate_encodings(
                 //   n1 = max_length / 2
                 //   n2 = n1 + max_length % 2
 
-                let mut n1 = encoding.get_ids().len();
-                let mut n2 = other_encoding.get_ids().len();
+                let mut n1 = first_length;
+                let mut n2 = second_length;
                 let mut swap = false;
 
                 // Ensure n1 is the length of the shortest input
@@ -135,32 +200,41 @@ pub fn truncate_encodings(
                 if swap {
                     mem::swap(&mut n1, &mut n2);
                 }
-                encoding.truncate(n1, params.stride);
-                other_encoding.truncate(n2, params.stride);
+                truncate(&mut encoding, n1, params);
+                truncate(other_encoding, cmp::min(n2, second_length), params);
             } else {
-                encoding.truncate(total_length - to_remove, params.stride);
+                truncate(&mut encoding, total_length - to_remove, params);
             }
         }
         TruncationStrategy::OnlyFirst | TruncationStrategy::OnlySecond => {
-            let target = if params.strategy == TruncationStrategy::OnlyFirst {
-                Ok(&mut encoding)
+            let (target, target_len) = if params.strategy == TruncationStrategy::OnlyFirst {
+                Ok((&mut encoding, first_length))
             } else if let Some(encoding) = pair_encoding.as_mut() {
-                Ok(encoding)
+                Ok((encoding, second_length))
             } else {
-                Err(Box::new(TruncationError::SecondSequenceNotProvided))
+                Err(TruncationError::SecondSequenceNotProvided)
             }?;
 
-            let target_len = target.get_ids().len();
             if target_len > to_remove {
-                target.truncate(target_len - to_remove, params.stride);
+                truncate(target, target_len - to_remove, params);
             } else {
-                return Err(Box::new(TruncationError::SequenceTooShort));
+                return Err(TruncationError::SequenceTooShort.into());
             }
         }
     }
+    truncate_second(pair_encoding.as_mut(), second_length, params);
     Ok((encoding, pair_encoding))
 }
 
+/// Truncate the second sequence to `max_second_length` if it is still longer
+fn truncate_second(encoding: Option<&mut Encoding>, length: usize, params: &TruncationParams) {
+    if let Some(encoding) = encoding {
+        if encoding.len() > length {
+            truncate(encoding, length, params);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,7 +336,7 @@ mod tests {
         n1: usize,
         n2: usize,
     ) {
-        match truncate_encodings(encoding1, Some(encoding2), &params) {
+        match truncate_encodings(encoding1, Some(encoding2), params) {
             Ok((e1, Some(e2))) => {
                 assert!(e1.get_ids().len() == n1);
                 assert!(e2.get_ids().len() == n2);
@@ -277,6 +351,10 @@ mod tests {
             max_length: 7,
             strategy: TruncationStrategy::LongestFirst,
             stride: 0,
+            direction: TruncationDirection::Right,
+            word_boundaries: false,
+            max_first_length: None,
+            max_second_length: None,
         };
 
         truncate_and_assert(get_empty(), get_empty(), &params, 0, 0);
@@ -306,10 +384,91 @@ mod tests {
             max_length: 0,
             strategy: TruncationStrategy::LongestFirst,
             stride: 0,
+            direction: TruncationDirection::Right,
+            word_boundaries: false,
+            max_first_length: None,
+            max_second_length: None,
         };
 
         truncate_and_assert(get_empty(), get_short(), &params, 0, 0);
         truncate_and_assert(get_medium(), get_medium(), &params, 0, 0);
         truncate_and_assert(get_long(), get_long(), &params, 0, 0);
     }
+
+    #[test]
+    fn truncate_encodings_direction() {
+        let params = TruncationParams {
+            max_length: 7,
+            direction: TruncationDirection::Left,
+            ..Default::default()
+        };
+        let (e1, e2) = truncate_encodings(get_medium(), Some(get_long()), &params).unwrap();
+        assert_eq!(e1.get_ids(), &[4, 5, 6]);
+        assert_eq!(e2.unwrap().get_ids(), &[11, 12, 13, 14]);
+
+        let params = TruncationParams {
+            max_length: 5,
+            strategy: TruncationStrategy::OnlySecond,
+            direction: TruncationDirection::HeadTail(1),
+            ..Default::default()
+        };
+        let (e1, e2) = truncate_encodings(get_short(), Some(get_long()), &params).unwrap();
+        assert_eq!(e1.get_ids(), &[1, 2]);
+        let e2 = e2.unwrap();
+        assert_eq!(e2.get_ids(), &[7, 13, 14]);
+        assert_eq!(e2.get_offsets(), &[(0, 1), (6, 7), (6, 8)]);
+        assert_eq!(e2.get_overflowing()[0].get_ids(), &[8, 9, 10]);
+    }
+
+    #[test]
+    fn truncate_encodings_max_sequence_lengths() {
+        let params = TruncationParams {
+            max_length: 5,
+            strategy: TruncationStrategy::OnlySecond,
+            stride: 1,
+            max_first_length: Some(2),
+            ..Default::default()
+        };
+        let (e1, e2) = truncate_encodings(get_medium(), Some(get_long()), &params).unwrap();
+        assert_eq!(e1.get_ids(), &[3, 4]);
+        assert!(e1.get_overflowing().is_empty());
+        let e2 = e2.unwrap();
+        assert_eq!(e2.get_ids(), &[7, 8, 9]);
+
+        // The first sequence is repeated in each overflowing part of the pair
+        let pair = Encoding::merge(vec![e1, e2], false);
+        let overflowing = pair
+            .get_overflowing()
+            .iter()
+            .map(|o| o.get_ids().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overflowing,
+            vec![
+                vec![3, 4, 9, 10, 11],
+                vec![3, 4, 11, 12, 13],
+                vec![3, 4, 13, 14]
+            ]
+        );
+
+        // The second sequence is capped even when the pair fits in `max_length`
+        let params = TruncationParams {
+            max_length: 20,
+            max_second_length: Some(5),
+            ..Default::default()
+        };
+        let (e1, e2) = truncate_encodings(get_medium(), Some(get_long()), &params).unwrap();
+        assert_eq!(e1.get_ids().len(), 4);
+        let e2 = e2.unwrap();
+        assert_eq!(e2.get_ids(), &[7, 8, 9, 10, 11]);
+        assert_eq!(e2.get_overflowing()[0].get_ids(), &[12, 13, 14]);
+
+        // And `max_length` still applies on top of it
+        let params = TruncationParams {
+            max_length: 6,
+            max_second_length: Some(5),
+            ..params
+        };
+        truncate_and_assert(get_medium(), get_long(), &params, 3, 3);
+    }
 }
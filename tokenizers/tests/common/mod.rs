@@ -1,4 +1,4 @@
-#![cfg(not(feature = "bert"))]
+#![cfg(feature = "default")]
 
 use tokenizers::decoders::wordpiece::WordPiece as WordPieceDecoder;
 use tokenizers::models::bpe::BPE;
@@ -1,4 +1,4 @@
-#![cfg(not(feature = "bert"))]
+#![cfg(feature = "default")]
 
 use tokenizers::models::bpe::{BpeTrainerBuilder, BPE};
 use tokenizers::normalizers::{Sequence, Strip, NFC};
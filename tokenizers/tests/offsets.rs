@@ -1,4 +1,4 @@
-#![cfg(not(feature = "bert"))]
+#![cfg(feature = "default")]
 
 mod common;
 
@@ -1,4 +1,4 @@
-#![cfg(not(feature = "bert"))]
+#![cfg(feature = "default")]
 
 use tokenizers::models::bpe::BPE;
 use tokenizers::{DecoderWrapper, NormalizerWrapper, PostProcessorWrapper, PreTokenizerWrapper};
@@ -1,4 +1,4 @@
-#![cfg(not(feature = "bert"))]
+#![cfg(feature = "default")]
 
 #[cfg(not(debug_assertions))]
 use assert_approx_eq::assert_approx_eq;